@@ -0,0 +1,63 @@
+/// Compare the speed of the naive element-by-element bit-matrix product to that of `BitMatrix::dot_matrix`.
+/// Run in release mode for realistic timings.
+///
+/// SPDX-FileCopyrightText:  2025 Nessan Fitzmaurice <nzznfitz+gh@icloud.com>
+/// SPDX-License-Identifier: MIT
+use gf2::*;
+mod naive;
+
+use std::io::Write;
+use utilities_rs::Stopwatch;
+
+fn main() {
+    type Mat = BitMatrix<u64>;
+
+    // Number of trials to run & how often to print progress.
+    let n_trials = 20;
+    let n_tick = n_trials / 20;
+
+    // Randomly generate two bit-matrices that we will multiply.
+    let n = 500;
+    let lhs = Mat::random(n, n);
+    let rhs = Mat::random(n, n);
+
+    // Check that the two implementations agree.
+    let prd_naive = naive::dot_matrix(&lhs, &rhs);
+    let prd_fast = lhs.dot_matrix(&rhs);
+    assert_eq!(prd_naive, prd_fast, "Mismatch between naive and optimized products!");
+
+    // Set up a stopwatch.
+    let sw = Stopwatch::default();
+
+    // Do the products using the optimized implementation.
+    print!("Running {n_trials} trials of the optimized {n} x {n} product ");
+    let mut dt_optimized = sw.elapsed();
+    for n in 0..n_trials {
+        let _ = lhs.dot_matrix(&rhs);
+        if n % n_tick == 0 {
+            print!(".");
+            std::io::stdout().flush().unwrap();
+        }
+    }
+    println!(" done!");
+    dt_optimized = sw.elapsed() - dt_optimized;
+
+    // Do the products using the naive implementation.
+    print!("Running {n_trials} trials of the naive {n} x {n} product ");
+    let mut dt_naive = sw.elapsed();
+    for n in 0..n_trials {
+        let _ = naive::dot_matrix(&lhs, &rhs);
+        if n % n_tick == 0 {
+            print!(".");
+            std::io::stdout().flush().unwrap();
+        }
+    }
+    println!(" done!");
+    dt_naive = sw.elapsed() - dt_naive;
+
+    // Print the timing results.
+    println!();
+    println!("Optimized product: {}", Stopwatch::format_seconds(dt_optimized));
+    println!("Naive product:     {}", Stopwatch::format_seconds(dt_naive));
+    println!("Speed-up factor:   {:.0}x", dt_naive / dt_optimized);
+}
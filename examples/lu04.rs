@@ -0,0 +1,69 @@
+#![allow(non_snake_case)]
+/// Compare the speed of naive bit-by-bit LU substitutions to that of `BitLU::X` which works on whole rows at a
+/// time. Run in release mode for realistic timings.
+///
+/// SPDX-FileCopyrightText:  2025 Nessan Fitzmaurice <nzznfitz+gh@icloud.com>
+/// SPDX-License-Identifier: MIT
+use gf2::*;
+mod naive;
+
+use std::io::Write;
+use utilities_rs::Stopwatch;
+
+fn main() {
+    type Mat = BitMatrix<u64>;
+
+    // Number of trials to run & how often to print progress.
+    let n_trials = 20;
+    let n_tick = n_trials / 20;
+
+    // Keep generating random square bit-matrices until we get an invertible one.
+    let n = 250;
+    let mut A = Mat::random(n, n);
+    while A.rank() < n {
+        A = Mat::random(n, n);
+    }
+    let LU = BitLU::new(&A);
+    let B = Mat::random(n, n);
+
+    // Check that the two implementations agree.
+    let X_naive = naive::lu_solve(&LU, &B).unwrap();
+    let X_fast = LU.X(&B).unwrap();
+    assert_eq!(X_naive, X_fast, "Mismatch between naive and optimized solutions!");
+    assert_eq!(&A * &X_fast, B, "Oops! A.X != B");
+
+    // Set up a stopwatch.
+    let sw = Stopwatch::default();
+
+    // Do the solves using the optimized implementation.
+    print!("Running {n_trials} trials of the optimized solve for {n} x {n} systems ");
+    let mut dt_optimized = sw.elapsed();
+    for n in 0..n_trials {
+        let _ = LU.X(&B);
+        if n % n_tick == 0 {
+            print!(".");
+            std::io::stdout().flush().unwrap();
+        }
+    }
+    println!(" done!");
+    dt_optimized = sw.elapsed() - dt_optimized;
+
+    // Do the solves using the naive implementation.
+    print!("Running {n_trials} trials of the naive solve for {n} x {n} systems ");
+    let mut dt_naive = sw.elapsed();
+    for n in 0..n_trials {
+        let _ = naive::lu_solve(&LU, &B);
+        if n % n_tick == 0 {
+            print!(".");
+            std::io::stdout().flush().unwrap();
+        }
+    }
+    println!(" done!");
+    dt_naive = sw.elapsed() - dt_naive;
+
+    // Print the timing results.
+    println!();
+    println!("Optimized solve: {}", Stopwatch::format_seconds(dt_optimized));
+    println!("Naive solve:     {}", Stopwatch::format_seconds(dt_naive));
+    println!("Speed-up factor: {:.0}x", dt_naive / dt_optimized);
+}
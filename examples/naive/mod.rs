@@ -331,3 +331,47 @@ pub fn reduce_x_to_power_n<Word: Unsigned>(poly: &BitPolynomial<Word>, n: usize)
     // Done
     BitPolynomial::from_coefficients(result)
 }
+
+/// Returns the bit-matrix product `lhs * rhs` computed element by element as dot products of rows and columns.
+pub fn dot_matrix<Word: Unsigned>(lhs: &BitMatrix<Word>, rhs: &BitMatrix<Word>) -> BitMatrix<Word> {
+    let mut result = BitMatrix::zeros(lhs.rows(), rhs.cols());
+    for j in 0..rhs.cols() {
+        let rhs_col = rhs.col(j);
+        for i in 0..lhs.rows() {
+            if lhs.row(i).dot(&rhs_col) {
+                result.set(i, j, true);
+            }
+        }
+    }
+    result
+}
+
+/// Solves `A.X = B` from the LU decomposition of `A` by bit-by-bit substitutions, one column of `X` at a time.
+#[allow(non_snake_case)]
+pub fn lu_solve<Word: Unsigned>(lu: &BitLU<Word>, B: &BitMatrix<Word>) -> Option<BitMatrix<Word>> {
+    if lu.is_singular() {
+        return None;
+    }
+    let L = lu.L();
+    let U = lu.U();
+    let mut X = B.clone();
+    lu.permute_matrix(&mut X);
+    let n = X.rows();
+    for c in 0..X.cols() {
+        for i in 0..n {
+            for j in 0..i {
+                if L[i][j] && X[j][c] {
+                    X.flip(i, c);
+                }
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                if U[i][j] && X[j][c] {
+                    X.flip(i, c);
+                }
+            }
+        }
+    }
+    Some(X)
+}
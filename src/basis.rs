@@ -0,0 +1,392 @@
+//! [`BitBasis`] is an incrementally built basis for a subspace of bit-vectors --- an _XOR basis_.
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitStore,
+    BitVector,
+    Unsigned,
+};
+
+// Standard library imports.
+use std::fmt;
+
+#[doc = include_str!("../docs/basis.md")]
+#[derive(Clone)]
+pub struct BitBasis<Word: Unsigned = usize> {
+    // The number of elements in each of the bit-vectors.
+    len: usize,
+
+    // The basis vectors in reduced echelon form sorted by decreasing pivot, where a pivot is the last set element.
+    rows: Vec<BitVector<Word>>,
+
+    // The pivot of each basis vector.
+    pivots: Vec<usize>,
+
+    // Element `j` of `combinations[i]` is set if accepted vector `j` is one of the summands of `rows[i]`.
+    // These all have `len` elements as there can never be more than `len` accepted vectors.
+    combinations: Vec<BitVector<Word>>,
+}
+
+/// Constructors for XOR bases.
+impl<Word: Unsigned> BitBasis<Word> {
+    /// Returns an empty basis for subspaces of bit-vectors with `len` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let basis: BitBasis = BitBasis::new(8);
+    /// assert_eq!(basis.vector_len(), 8);
+    /// assert_eq!(basis.rank(), 0);
+    /// ```
+    #[must_use]
+    pub fn new(len: usize) -> Self { Self { len, rows: Vec::new(), pivots: Vec::new(), combinations: Vec::new() } }
+
+    /// Returns a basis for the span of the rows of a bit-matrix.
+    ///
+    /// The rows are inserted in order, so the accepted vectors are the rows that are independent of the rows above
+    /// them.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("1100 0110 1010").unwrap();
+    /// let basis = BitBasis::from_matrix(&m);
+    /// assert_eq!(basis.rank(), 2);
+    /// assert_eq!(basis.rank(), m.rank());
+    /// ```
+    #[must_use]
+    pub fn from_matrix(m: &BitMatrix<Word>) -> Self {
+        let mut result = Self::new(m.cols());
+        for row in m.row_iter() {
+            result.insert(row);
+        }
+        result
+    }
+}
+
+/// Methods to query an XOR basis.
+impl<Word: Unsigned> BitBasis<Word> {
+    /// Returns the number of elements in each of the bit-vectors that the basis spans.
+    #[must_use]
+    pub fn vector_len(&self) -> usize { self.len }
+
+    /// Returns the number of vectors in the basis which is the dimension of the subspace that it spans.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut basis: BitBasis = BitBasis::new(4);
+    /// basis.insert(&BitVector::from_string("1100").unwrap());
+    /// basis.insert(&BitVector::from_string("0110").unwrap());
+    /// basis.insert(&BitVector::from_string("1010").unwrap());
+    /// assert_eq!(basis.rank(), 2);
+    /// ```
+    #[must_use]
+    pub fn rank(&self) -> usize { self.rows.len() }
+
+    /// Returns `true` if the basis has no vectors so it spans just the zero bit-vector.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.rows.is_empty() }
+
+    /// Returns the basis vectors in reduced echelon form.
+    ///
+    /// The *pivot* of a basis vector is its last set element. The vectors are sorted by decreasing pivot and each pivot
+    /// is set in just one of the vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut basis: BitBasis = BitBasis::new(4);
+    /// basis.insert(&BitVector::from_string("1100").unwrap());
+    /// basis.insert(&BitVector::from_string("1110").unwrap());
+    /// assert_eq!(basis.vectors()[0].to_string(), "0010");
+    /// assert_eq!(basis.vectors()[1].to_string(), "1100");
+    /// assert_eq!(basis.pivots(), [2, 1]);
+    /// ```
+    #[must_use]
+    pub fn vectors(&self) -> &[BitVector<Word>] { &self.rows }
+
+    /// Returns the pivot of each basis vector which is the index of its last set element.
+    #[must_use]
+    pub fn pivots(&self) -> &[usize] { &self.pivots }
+
+    /// Returns the basis vectors as the rows of a new bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("1100 0110 1010").unwrap();
+    /// let basis = BitBasis::from_matrix(&m);
+    /// assert_eq!(basis.to_matrix().to_compact_binary_string(), "1010 1100");
+    /// ```
+    #[must_use]
+    pub fn to_matrix(&self) -> BitMatrix<Word> { BitMatrix::from_fn(self.rank(), self.len, |i, j| self.rows[i][j]) }
+
+    /// Returns `true` if the bit-store `v` is in the span of the basis.
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut basis: BitBasis = BitBasis::new(4);
+    /// basis.insert(&BitVector::from_string("1100").unwrap());
+    /// basis.insert(&BitVector::from_string("0110").unwrap());
+    /// assert!(basis.contains(&BitVector::from_string("1010").unwrap()));
+    /// assert!(!basis.contains(&BitVector::from_string("0001").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn contains<Src: BitStore<Word>>(&self, v: &Src) -> bool { self.residual(v).none() }
+
+    /// Returns the bit-store `v` reduced by the basis along with the accepted vectors that account for the difference.
+    ///
+    /// The vectors that [`BitBasis::insert`] accepted are numbered `0, 1, 2, ...` in the order they were inserted. The
+    /// method returns `(r, c)` where `r` is the smallest element of the coset `v + span` (see
+    /// [`BitBasis::min_xor_with`]) and `c` has one element per accepted vector such that `v` is `r` plus the sum of the
+    /// accepted vectors picked out by `c`. In particular, `v` is in the span exactly when `r` is zero and then `c`
+    /// shows how to write `v` as a sum of the accepted vectors.
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let u0: BitVector = BitVector::from_string("1100").unwrap();
+    /// let u1: BitVector = BitVector::from_string("0110").unwrap();
+    /// let mut basis = BitBasis::new(4);
+    /// basis.insert(&u0);
+    /// basis.insert(&u1);
+    /// let (r, c) = basis.reduce(&(&u0 ^ &u1));
+    /// assert!(r.none());
+    /// assert_eq!(c.to_string(), "11");
+    /// let (r, c) = basis.reduce(&BitVector::from_string("0101").unwrap());
+    /// assert_eq!(r.to_string(), "1001");
+    /// assert_eq!(c.to_string(), "10");
+    /// ```
+    #[must_use]
+    pub fn reduce<Src: BitStore<Word>>(&self, v: &Src) -> (BitVector<Word>, BitVector<Word>) {
+        let (r, mut c) = self.reduce_with_combination(v);
+        c.resize(self.rank());
+        (r, c)
+    }
+
+    /// Returns the largest element of the span of the basis.
+    ///
+    /// Bit-vectors are compared as the binary numbers `v[0] + 2 v[1] + 4 v[2] + ...`, which is the same order as the
+    /// unsigned integers that [`BitVector::from_unsigned`] takes.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut basis: BitBasis<u8> = BitBasis::new(8);
+    /// for x in [3_u8, 10, 14] {
+    ///     basis.insert(&BitVector::from_unsigned(x));
+    /// }
+    /// assert_eq!(basis.max_xor(), BitVector::from_unsigned(14_u8));
+    /// ```
+    #[must_use]
+    pub fn max_xor(&self) -> BitVector<Word> { self.max_xor_with(&BitVector::zeros(self.len)) }
+
+    /// Returns the smallest non-zero element of the span of the basis or `None` if the basis is empty.
+    ///
+    /// Bit-vectors are compared as binary numbers as in [`BitBasis::max_xor`].
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut basis: BitBasis<u8> = BitBasis::new(8);
+    /// assert_eq!(basis.min_xor(), None);
+    /// for x in [3_u8, 10, 14] {
+    ///     basis.insert(&BitVector::from_unsigned(x));
+    /// }
+    /// assert_eq!(basis.min_xor(), Some(BitVector::from_unsigned(3_u8)));
+    /// ```
+    #[must_use]
+    pub fn min_xor(&self) -> Option<BitVector<Word>> { self.rows.last().cloned() }
+
+    /// Returns the largest element of the coset `v + span`, i.e., the largest `v ^ s` for `s` in the span.
+    ///
+    /// Bit-vectors are compared as binary numbers as in [`BitBasis::max_xor`].
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut basis: BitBasis<u8> = BitBasis::new(8);
+    /// for x in [3_u8, 10, 14] {
+    ///     basis.insert(&BitVector::from_unsigned(x));
+    /// }
+    /// assert_eq!(basis.max_xor_with(&BitVector::from_unsigned(16_u8)), BitVector::from_unsigned(30_u8));
+    /// ```
+    #[must_use]
+    pub fn max_xor_with<Src: BitStore<Word>>(&self, v: &Src) -> BitVector<Word> {
+        // Every pivot element can be set independently of the others and the pivots are the most significant bits.
+        let mut result = self.residual(v);
+        for row in &self.rows {
+            result ^= row;
+        }
+        result
+    }
+
+    /// Returns the smallest element of the coset `v + span`, i.e., the smallest `v ^ s` for `s` in the span.
+    ///
+    /// Bit-vectors are compared as binary numbers as in [`BitBasis::max_xor`].
+    /// The result is zero exactly when `v` is in the span.
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut basis: BitBasis<u8> = BitBasis::new(8);
+    /// for x in [3_u8, 10, 14] {
+    ///     basis.insert(&BitVector::from_unsigned(x));
+    /// }
+    /// assert_eq!(basis.min_xor_with(&BitVector::from_unsigned(16_u8)), BitVector::from_unsigned(16_u8));
+    /// assert_eq!(basis.min_xor_with(&BitVector::from_unsigned(15_u8)), BitVector::from_unsigned(1_u8));
+    /// assert_eq!(basis.min_xor_with(&BitVector::from_unsigned(13_u8)), BitVector::from_unsigned(0_u8));
+    /// ```
+    #[must_use]
+    pub fn min_xor_with<Src: BitStore<Word>>(&self, v: &Src) -> BitVector<Word> { self.residual(v) }
+
+    /// Returns `v` with every pivot element cleared by adding basis vectors.
+    fn residual<Src: BitStore<Word>>(&self, v: &Src) -> BitVector<Word> {
+        assert_eq!(v.len(), self.len, "Bit-store has {} elements but the basis is for length {}", v.len(), self.len);
+        let mut result = BitVector::from_store(v);
+        for (row, &p) in self.rows.iter().zip(&self.pivots) {
+            if result[p] {
+                result ^= row;
+            }
+        }
+        result
+    }
+
+    /// Returns `v` reduced by the basis and the combination of accepted vectors used with `len` elements.
+    fn reduce_with_combination<Src: BitStore<Word>>(&self, v: &Src) -> (BitVector<Word>, BitVector<Word>) {
+        assert_eq!(v.len(), self.len, "Bit-store has {} elements but the basis is for length {}", v.len(), self.len);
+        let mut result = BitVector::from_store(v);
+        let mut combination = BitVector::zeros(self.len);
+        for ((row, &p), c) in self.rows.iter().zip(&self.pivots).zip(&self.combinations) {
+            if result[p] {
+                result ^= row;
+                combination ^= c;
+            }
+        }
+        (result, combination)
+    }
+}
+
+/// Methods to add vectors to an XOR basis.
+impl<Word: Unsigned> BitBasis<Word> {
+    /// Adds the bit-store `v` to the span and returns `true` if it was independent of the vectors already there.
+    ///
+    /// If `v` is independent, it becomes the next *accepted* vector (see [`BitBasis::reduce`]). The basis is kept in
+    /// reduced echelon form, so an insertion costs at most two passes over the basis vectors.
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut basis: BitBasis = BitBasis::new(4);
+    /// assert!(basis.insert(&BitVector::from_string("1100").unwrap()));
+    /// assert!(basis.insert(&BitVector::from_string("0110").unwrap()));
+    /// assert!(!basis.insert(&BitVector::from_string("1010").unwrap()));
+    /// assert!(!basis.insert(&BitVector::from_string("0000").unwrap()));
+    /// ```
+    pub fn insert<Src: BitStore<Word>>(&mut self, v: &Src) -> bool {
+        let (r, mut c) = self.reduce_with_combination(v);
+        let Some(p) = r.last_set()
+        else {
+            return false;
+        };
+
+        // `v` is the next accepted vector and `r` is `v` plus the sum of the accepted vectors picked out by `c`.
+        c.set(self.rank(), true);
+
+        // The residual has no set pivots already. Clear its pivot from the other vectors to keep the basis reduced.
+        for (row, comb) in self.rows.iter_mut().zip(self.combinations.iter_mut()) {
+            if row[p] {
+                *row ^= &r;
+                *comb ^= &c;
+            }
+        }
+
+        // Keep the vectors sorted by decreasing pivot.
+        let at = self.pivots.partition_point(|&q| q > p);
+        self.rows.insert(at, r);
+        self.pivots.insert(at, p);
+        self.combinations.insert(at, c);
+        true
+    }
+
+    /// Adds all the vectors in the span of another basis to this one and returns the number of vectors added.
+    ///
+    /// The vectors of `other` are inserted in turn, so the accepted vectors of this basis will include some of the
+    /// basis vectors of `other`, not the vectors originally inserted into `other`.
+    ///
+    /// # Panics
+    /// Panics if the bases are for bit-vectors of different lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut a = BitBasis::from_matrix(&BitMatrix::<usize>::from_string("1100 0110").unwrap());
+    /// let b = BitBasis::from_matrix(&BitMatrix::<usize>::from_string("1010 0001").unwrap());
+    /// assert_eq!(a.merge(&b), 1);
+    /// assert_eq!(a.rank(), 3);
+    /// assert!(a.contains(&BitVector::<usize>::from_string("1011").unwrap()));
+    /// ```
+    pub fn merge(&mut self, other: &BitBasis<Word>) -> usize {
+        assert_eq!(self.len, other.len, "Bases are for lengths {} and {}", self.len, other.len);
+        other.rows.iter().filter(|row| self.insert(*row)).count()
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// Trait implementations for XOR bases.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// Two bases are equal if they span the same subspace.
+///
+/// The reduced echelon form of a subspace is unique, so this only compares the basis vectors and not the record of
+/// which vectors were accepted.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let a = BitBasis::from_matrix(&BitMatrix::<usize>::from_string("1100 0110").unwrap());
+/// let b = BitBasis::from_matrix(&BitMatrix::<usize>::from_string("1010 1100").unwrap());
+/// assert_eq!(a, b);
+/// ```
+impl<Word: Unsigned> PartialEq for BitBasis<Word> {
+    fn eq(&self, other: &Self) -> bool { self.len == other.len && self.rows == other.rows }
+}
+
+impl<Word: Unsigned> Eq for BitBasis<Word> {}
+
+/// The `Display` trait implementation for a basis shows the basis vectors as the rows of a bit-matrix.
+///
+/// The alternate form `{:#}` shows them on one line like `1010 1100`.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let basis = BitBasis::from_matrix(&BitMatrix::<usize>::from_string("1100 0110").unwrap());
+/// assert_eq!(format!("{basis:#}"), "1010 1100");
+/// ```
+impl<Word: Unsigned> fmt::Display for BitBasis<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Display::fmt(&self.to_matrix(), f) }
+}
+
+/// The `Debug` trait implementation for a basis is the same as the alternate `Display` implementation.
+impl<Word: Unsigned> fmt::Debug for BitBasis<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{self:#}") }
+}
@@ -0,0 +1,822 @@
+//! [`LinearCode`] is a binary linear code described by a generator matrix and a parity-check matrix.
+#![allow(non_snake_case)]
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitPermutation,
+    BitStore,
+    BitSubspace,
+    BitVector,
+    FixedWeight,
+    GrayCode,
+    SyndromeDecoder,
+    Unsigned,
+    WeightDistribution,
+};
+
+// Standard library imports.
+use std::fmt;
+
+/// The largest dimension of a code whose minimum distance is found by walking through all its codewords.
+const GRAY_CODE_DIMENSION: usize = 16;
+
+#[doc = include_str!("../docs/code.md")]
+#[derive(Clone)]
+pub struct LinearCode<Word: Unsigned = usize> {
+    // The length of the codewords.
+    n: usize,
+
+    // A `k x n` generator matrix with independent rows.
+    G: BitMatrix<Word>,
+
+    // An `(n - k) x n` parity-check matrix with independent rows.
+    H: BitMatrix<Word>,
+}
+
+/// Constructors for linear codes.
+impl<Word: Unsigned> LinearCode<Word> {
+    /// Returns the code spanned by the rows of a generator matrix `G`.
+    ///
+    /// The codewords have `G.cols()` elements. If the rows of `G` are independent then `G` is kept as the generator
+    /// matrix and [`LinearCode::encode`] uses it. Otherwise we use the reduced row echelon form of the row space.
+    ///
+    /// # Panics
+    /// Panics if `G` has no rows as that loses the length of the codewords. Use [`LinearCode::from_subspace`] with
+    /// [`BitSubspace::zero`] for the code that is just the zero codeword.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let G: BitMatrix = BitMatrix::from_string("1000011 0100101 0010110 0001111").unwrap();
+    /// let code = LinearCode::from_generator(&G);
+    /// assert_eq!((code.n(), code.k()), (7, 4));
+    /// assert_eq!(code.generator(), &G);
+    /// assert!((&G * &code.parity_check().transposed()).none());
+    /// ```
+    #[must_use]
+    pub fn from_generator(G: &BitMatrix<Word>) -> Self {
+        let n = G.cols();
+        let space = BitSubspace::from_matrix(G);
+        let H = Self::complement(&space);
+        let G = if space.dim() == G.rows() { G.clone() } else { space.to_matrix() };
+        Self { n, G, H }
+    }
+
+    /// Returns the code whose codewords `c` satisfy `H.c = 0` for a parity-check matrix `H`.
+    ///
+    /// The codewords have `H.cols()` elements. If the rows of `H` are independent then `H` is kept as the parity-check
+    /// matrix and [`LinearCode::syndrome`] uses it. Otherwise we use the reduced row echelon form of the row space.
+    ///
+    /// # Panics
+    /// Panics if `H` has no rows as that loses the length of the codewords. Use [`LinearCode::from_subspace`] with
+    /// [`BitSubspace::full`] for the code that has every bit-vector as a codeword.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H);
+    /// assert_eq!((code.n(), code.k()), (7, 4));
+    /// assert_eq!(code.parity_check(), &H);
+    /// ```
+    #[must_use]
+    pub fn from_parity_check(H: &BitMatrix<Word>) -> Self {
+        let n = H.cols();
+        let space = BitSubspace::from_matrix(H);
+        let G = Self::complement(&space);
+        let H = if space.dim() == H.rows() { H.clone() } else { space.to_matrix() };
+        Self { n, G, H }
+    }
+
+    /// Returns the code whose codewords are the elements of a subspace.
+    ///
+    /// The generator matrix is the canonical basis of the subspace.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::from_subspace(&BitSubspace::full(5));
+    /// assert_eq!((code.n(), code.k()), (5, 5));
+    /// assert!(code.parity_check().is_empty());
+    /// ```
+    #[must_use]
+    pub fn from_subspace(space: &BitSubspace<Word>) -> Self {
+        let n = space.vector_len();
+        let G = space.to_matrix();
+        let H = Self::complement(space);
+        Self { n, G, H }
+    }
+
+    /// Returns a parity-check matrix for the code with generator matrix `G`.
+    ///
+    /// The result has independent rows that span the orthogonal complement of the row space of `G`. It is found from
+    /// the systematic form `[I | P]` as `[P^T | I]` with the columns put back in their original order.
+    ///
+    /// # Panics
+    /// Panics if `G` has no rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let G: BitMatrix = BitMatrix::from_string("1000011 0100101 0010110 0001111").unwrap();
+    /// let H = LinearCode::generator_to_parity_check(&G);
+    /// assert_eq!(H.to_compact_binary_string(), "0111100 1011010 1101001");
+    /// assert_eq!(LinearCode::parity_check_to_generator(&H).row_space(), G.row_space());
+    /// ```
+    #[must_use]
+    pub fn generator_to_parity_check(G: &BitMatrix<Word>) -> BitMatrix<Word> {
+        Self::complement(&BitSubspace::from_matrix(G))
+    }
+
+    /// Returns a generator matrix for the code with parity-check matrix `H`.
+    ///
+    /// This is the same calculation as [`LinearCode::generator_to_parity_check`] as each matrix spans the orthogonal
+    /// complement of the row space of the other.
+    ///
+    /// # Panics
+    /// Panics if `H` has no rows.
+    #[must_use]
+    pub fn parity_check_to_generator(H: &BitMatrix<Word>) -> BitMatrix<Word> {
+        Self::complement(&BitSubspace::from_matrix(H))
+    }
+
+    /// Returns a basis for the orthogonal complement of `space` as the rows of a bit-matrix.
+    fn complement(space: &BitSubspace<Word>) -> BitMatrix<Word> {
+        // With the systematic form `[I | P]` the rows of `[P^T | I]` are orthogonal to every row and independent.
+        let (n, k) = (space.vector_len(), space.dim());
+        let (G, p) = Self::systematic(space);
+        let q = p.inverse();
+        let H = |i: usize, j: usize| if j < k { G[j][k + i] } else { j - k == i };
+        BitMatrix::from_fn(n - k, n, |i, j| H(i, q[j]))
+    }
+
+    /// Returns the systematic form of the code spanned by `space` and the column permutation that goes with it.
+    fn systematic(space: &BitSubspace<Word>) -> (BitMatrix<Word>, BitPermutation) {
+        // The pivots of the reduced row echelon form come first and then the other columns in order.
+        let n = space.vector_len();
+        let mut is_pivot = vec![false; n];
+        for &p in space.pivots() {
+            is_pivot[p] = true;
+        }
+        let mut map = space.pivots().to_vec();
+        map.extend((0..n).filter(|&j| !is_pivot[j]));
+        let p = BitPermutation::from_vec(map).unwrap();
+        let rows = space.vectors();
+        (BitMatrix::from_fn(space.dim(), n, |i, j| rows[i][p[j]]), p)
+    }
+}
+
+/// Constructors for classical codes.
+impl<Word: Unsigned> LinearCode<Word> {
+    /// Returns the `[2^m - 1, 2^m - 1 - m, 3]` Hamming code.
+    ///
+    /// Column `j` of the parity-check matrix is the binary form of `j + 1` with the least significant bit in row zero,
+    /// so the syndrome of a single error at position `j` is the binary form of `j + 1`. The Hamming codes are perfect
+    /// single-error-correcting codes.
+    ///
+    /// # Panics
+    /// Panics if `m < 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::hamming(3);
+    /// assert_eq!((code.n(), code.k(), code.d()), (7, 4, Some(3)));
+    /// assert_eq!(code.parity_check().to_compact_binary_string(), "1010101 0110011 0001111");
+    /// ```
+    #[must_use]
+    pub fn hamming(m: usize) -> Self {
+        assert!(m >= 2, "A Hamming code needs at least 2 parity checks not {m}");
+        Self::from_parity_check(&Self::binary_columns(m))
+    }
+
+    /// Returns the `[2^m, 2^m - 1 - m, 4]` extended Hamming code, which is the Hamming code with an overall parity bit.
+    ///
+    /// The extended Hamming codes correct single errors and detect double errors.
+    ///
+    /// # Panics
+    /// Panics if `m < 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::extended_hamming(3);
+    /// assert_eq!((code.n(), code.k(), code.d()), (8, 4, Some(4)));
+    /// ```
+    #[must_use]
+    pub fn extended_hamming(m: usize) -> Self { Self::hamming(m).extended() }
+
+    /// Returns a single-error-correcting, double-error-detecting (SECDED) code for `k` data bits.
+    ///
+    /// This is the usual code for protecting memory words. It is a shortened extended Hamming code with the fewest
+    /// check bits `m` such that `2^m - m - 1 >= k`, so `k = 64` gives the `[72, 64, 4]` code used for 64-bit words.
+    /// The encoder is systematic with the `k` data bits first, then the `m` Hamming check bits, and then the overall
+    /// parity bit.
+    ///
+    /// # Panics
+    /// Panics if `k` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::secded(64);
+    /// assert_eq!((code.n(), code.k()), (72, 64));
+    /// let data: BitVector = BitVector::random_seeded(64, 42);
+    /// let c = code.encode(&data);
+    /// assert_eq!(BitVector::from_store(&c.slice(0..64)), data);
+    ///
+    /// // Single errors are corrected and double errors are detected.
+    /// let decoder = code.decoder();
+    /// let mut r = c.clone();
+    /// r.flip(17);
+    /// assert_eq!(decoder.decode(&r), Some((c.clone(), BitVector::unit(17, 72))));
+    /// r.flip(68);
+    /// assert_eq!(decoder.decode(&r), None);
+    /// ```
+    #[must_use]
+    pub fn secded(k: usize) -> Self {
+        assert!(k > 0, "A SECDED code needs at least one data bit");
+        let mut m = 2;
+        while (1 << m) - m - 1 < k {
+            m += 1;
+        }
+
+        // The data bits use the first `k` columns of the Hamming parity-check matrix that are not unit vectors.
+        let columns = Self::binary_columns(m);
+        let data: Vec<usize> = (0..columns.cols()).filter(|&j| !(j + 1).is_power_of_two()).take(k).collect();
+        let column = |j: usize| columns.col(data[j]);
+        let n = k + m + 1;
+        let G = BitMatrix::from_fn(k, n, |i, j| {
+            if j < k {
+                i == j
+            }
+            else if j < k + m {
+                column(i)[j - k]
+            }
+            else {
+                column(i).count_ones() % 2 == 0
+            }
+        });
+        let H = BitMatrix::from_fn(m + 1, n, |i, j| {
+            if i == m {
+                true
+            }
+            else if j < k {
+                column(j)[i]
+            }
+            else {
+                j - k == i
+            }
+        });
+        Self { n, G, H }
+    }
+
+    /// Returns the `[2^m - 1, m, 2^(m-1)]` simplex code, which is the dual of the Hamming code.
+    ///
+    /// Every non-zero codeword of the simplex code has the same weight `2^(m-1)`.
+    ///
+    /// # Panics
+    /// Panics if `m < 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::simplex(3);
+    /// assert_eq!((code.n(), code.k(), code.d()), (7, 3, Some(4)));
+    /// assert_eq!(code, LinearCode::hamming(3).dual());
+    /// ```
+    #[must_use]
+    pub fn simplex(m: usize) -> Self {
+        assert!(m >= 2, "A simplex code needs a dimension of at least 2 not {m}");
+        Self::from_generator(&Self::binary_columns(m))
+    }
+
+    /// Returns the `[n, 1, n]` repetition code whose only non-zero codeword is the all-ones word.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::repetition(5);
+    /// assert_eq!((code.n(), code.k(), code.d()), (5, 1, Some(5)));
+    /// let mut r = code.encode(&BitVector::<usize>::ones(1));
+    /// r.flip(0);
+    /// r.flip(3);
+    /// assert_eq!(code.decoder().decode(&r).unwrap().0, BitVector::ones(5));
+    /// ```
+    #[must_use]
+    pub fn repetition(n: usize) -> Self {
+        assert!(n > 0, "A repetition code needs a length of at least 1");
+        Self::from_generator(&BitMatrix::ones(1, n))
+    }
+
+    /// Returns the `[n, n - 1, 2]` single parity-check code whose codewords are the words of even weight.
+    ///
+    /// This is the dual of the repetition code. It detects any single error but corrects none.
+    ///
+    /// # Panics
+    /// Panics if `n < 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::single_parity_check(5);
+    /// assert_eq!((code.n(), code.k(), code.d()), (5, 4, Some(2)));
+    /// assert_eq!(code, LinearCode::repetition(5).dual());
+    /// ```
+    #[must_use]
+    pub fn single_parity_check(n: usize) -> Self {
+        assert!(n >= 2, "A single parity-check code needs a length of at least 2 not {n}");
+        Self::from_parity_check(&BitMatrix::ones(1, n))
+    }
+
+    /// Returns the `[23, 12, 7]` binary Golay code, which is a perfect three-error-correcting code.
+    ///
+    /// This is the cyclic code with generator polynomial `1 + x^2 + x^4 + x^5 + x^6 + x^10 + x^11`, so row `i` of the
+    /// generator matrix is that polynomial shifted by `i` places.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::golay();
+    /// assert_eq!((code.n(), code.k(), code.d()), (23, 12, Some(7)));
+    /// ```
+    #[must_use]
+    pub fn golay() -> Self {
+        const G: [bool; 12] = [true, false, true, false, true, true, true, false, false, false, true, true];
+        Self::from_generator(&BitMatrix::from_fn(12, 23, |i, j| j >= i && j - i < 12 && G[j - i]))
+    }
+
+    /// Returns the `[24, 12, 8]` extended binary Golay code, which is the Golay code with an overall parity bit.
+    ///
+    /// The extended Golay code is self-dual and every codeword has a weight that is a multiple of four.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::extended_golay();
+    /// assert_eq!((code.n(), code.k(), code.d()), (24, 12, Some(8)));
+    /// assert!(code.is_self_dual());
+    /// ```
+    #[must_use]
+    pub fn extended_golay() -> Self { Self::golay().extended() }
+
+    /// Returns the `m x (2^m - 1)` bit-matrix whose column `j` is the binary form of `j + 1`.
+    fn binary_columns(m: usize) -> BitMatrix<Word> { BitMatrix::from_fn(m, (1 << m) - 1, |i, j| (j + 1) >> i & 1 == 1) }
+}
+
+/// Methods to query a linear code.
+impl<Word: Unsigned> LinearCode<Word> {
+    /// Returns the length `n` of the codewords.
+    #[must_use]
+    pub fn n(&self) -> usize { self.n }
+
+    /// Returns the dimension `k` of the code which is the number of message bits in each codeword.
+    #[must_use]
+    pub fn k(&self) -> usize { self.G.rows() }
+
+    /// Returns the number of redundant bits `n - k` in each codeword which is the length of a syndrome.
+    #[must_use]
+    pub fn redundancy(&self) -> usize { self.n - self.k() }
+
+    /// Returns the minimum distance `d` of the code or `None` if the code is just the zero codeword.
+    ///
+    /// This is the smallest weight of a non-zero codeword. Codes of small dimension are searched exhaustively in Gray
+    /// code order. Other codes use the Brouwer–Zimmermann algorithm, which enumerates low-weight messages for several
+    /// systematic generator matrices with disjoint information sets and stops as soon as the lower bound on `d` from
+    /// those messages meets the lightest codeword found. That handles many medium-sized codes, but the cost still
+    /// grows exponentially with the size of the code.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H);
+    /// assert_eq!(code.d(), Some(3));
+    /// assert_eq!(code.dual().d(), Some(4));
+    /// assert_eq!(LinearCode::<usize>::secded(64).d(), Some(4));
+    /// ```
+    #[must_use]
+    pub fn d(&self) -> Option<usize> {
+        let (n, k) = (self.n, self.k());
+        if k == 0 {
+            return None;
+        }
+        if k == n {
+            return Some(1);
+        }
+
+        // Walk through the codewords changing one generator row at a time if there are few of them.
+        if k <= GRAY_CODE_DIMENSION {
+            let mut codeword = BitVector::zeros(n);
+            let mut d = n;
+            for (_, i) in GrayCode::<Word>::new(k).skip(1) {
+                codeword ^= &self.G[i.unwrap()];
+                d = d.min(codeword.count_ones());
+            }
+            return Some(d);
+        }
+        Some(self.brouwer_zimmermann())
+    }
+
+    /// Returns the minimum distance of a code with `0 < k < n` using the Brouwer–Zimmermann algorithm.
+    fn brouwer_zimmermann(&self) -> usize {
+        let (n, k) = (self.n, self.k());
+
+        // Greedily pick information sets that use as many new columns as possible. Each generator matrix is in reduced
+        // row echelon form with the unused columns first and `ranks[j]` is the number of its pivots that are new.
+        let mut used = vec![false; n];
+        let mut matrices = Vec::new();
+        let mut ranks = Vec::new();
+        loop {
+            let mut order: Vec<usize> = (0..n).filter(|&j| !used[j]).collect();
+            let fresh = order.len();
+            order.extend((0..n).filter(|&j| used[j]));
+            let rows: Vec<_> = self.G.row_iter().map(|row| BitVector::<Word>::from_fn(n, |j| row[order[j]])).collect();
+            let space = BitSubspace::from_vectors(n, &rows);
+            let rank = space.pivots().iter().filter(|&&p| p < fresh).count();
+            if rank == 0 {
+                break;
+            }
+            for &p in space.pivots().iter().filter(|&&p| p < fresh) {
+                used[order[p]] = true;
+            }
+            matrices.push(space.vectors().to_vec());
+            ranks.push(rank);
+        }
+
+        // A codeword that is not the sum of at most `w` rows of matrix `j` has weight at least `w + 1 - (k - rank)` on
+        // the new columns of that matrix, and those columns are disjoint.
+        let bound = |w: usize, rank: usize| (w + 1).saturating_sub(k - rank);
+        let mut upper = n;
+        for w in 1..=k {
+            for j in 0..matrices.len() {
+                for message in FixedWeight::<Word>::new(k, w) {
+                    let mut codeword = BitVector::zeros(n);
+                    for i in message.set_bits() {
+                        codeword ^= &matrices[j][i];
+                    }
+                    upper = upper.min(codeword.count_ones());
+                }
+                let lower = ranks[..=j].iter().map(|&r| bound(w, r)).sum::<usize>()
+                    + ranks[j + 1..].iter().map(|&r| bound(w - 1, r)).sum::<usize>();
+                if lower >= upper {
+                    return upper;
+                }
+            }
+        }
+        upper
+    }
+
+    /// Returns the weight distribution of the code, which is the number of codewords of each weight.
+    ///
+    /// If `k <= n - k` the codewords are enumerated in Gray code order. Otherwise the smaller dual code is enumerated
+    /// and its weight distribution is turned into ours by the MacWilliams transform. Either way the cost is about
+    /// `2^min(k, n - k)` bit-vector additions.
+    ///
+    /// # Panics
+    /// Panics if the MacWilliams transform overflows, see [`WeightDistribution::macwilliams`].
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::hamming(3);
+    /// let weights = code.weight_distribution();
+    /// assert_eq!(weights.counts(), &[1, 0, 0, 7, 7, 0, 0, 1]);
+    /// assert_eq!(weights.to_string(), "x^7 + 7x^4y^3 + 7x^3y^4 + y^7");
+    /// assert_eq!(code.dual().weight_distribution(), weights.macwilliams());
+    /// ```
+    #[must_use]
+    pub fn weight_distribution(&self) -> WeightDistribution {
+        if self.k() <= self.redundancy() {
+            Self::gray_code_weights(self.n, &self.G)
+        }
+        else {
+            Self::gray_code_weights(self.n, &self.H).macwilliams()
+        }
+    }
+
+    /// Returns the weight distribution of the span of the independent rows of length `n` of a bit-matrix `M`.
+    fn gray_code_weights(n: usize, M: &BitMatrix<Word>) -> WeightDistribution {
+        let mut counts = vec![0; n + 1];
+        let mut codeword = BitVector::zeros(n);
+        for (_, i) in GrayCode::<Word>::new(M.rows()) {
+            if let Some(i) = i {
+                codeword ^= &M[i];
+            }
+            counts[codeword.count_ones()] += 1;
+        }
+        WeightDistribution::from_counts(counts)
+    }
+
+    /// Returns the generator matrix, which is `k x n` with independent rows.
+    #[must_use]
+    pub fn generator(&self) -> &BitMatrix<Word> { &self.G }
+
+    /// Returns the parity-check matrix, which is `(n - k) x n` with independent rows.
+    #[must_use]
+    pub fn parity_check(&self) -> &BitMatrix<Word> { &self.H }
+
+    /// Returns the codewords as a subspace of the bit-vectors of length `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H);
+    /// assert_eq!(code.to_subspace(), BitSubspace::kernel_of(&H));
+    /// ```
+    #[must_use]
+    pub fn to_subspace(&self) -> BitSubspace<Word> {
+        BitSubspace::from_vectors(self.n, &self.G.row_iter().cloned().collect::<Vec<_>>())
+    }
+
+    /// Returns the systematic form `[I | P]` of the generator matrix and the column permutation `p` that goes with it.
+    ///
+    /// Column `j` of the systematic form is column `p[j]` of the code, so the permuted code has the message bits in
+    /// its first `k` places. The systematic form generates the code `{p.apply(c)}` for the codewords `c`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H);
+    /// let (S, p) = code.systematic_form();
+    /// assert_eq!(S.sub_matrix(0..4, 0..4), BitMatrix::identity(4));
+    /// assert!(S.row_iter().all(|row| code.contains(&p.inverse().apply(row))));
+    /// ```
+    #[must_use]
+    pub fn systematic_form(&self) -> (BitMatrix<Word>, BitPermutation) { Self::systematic(&self.to_subspace()) }
+
+    /// Returns `true` if the bit-store `word` is a codeword, i.e., if its syndrome is zero.
+    ///
+    /// # Panics
+    /// Panics if `word` does not have `n` elements.
+    #[must_use]
+    pub fn contains<Src: BitStore<Word>>(&self, word: &Src) -> bool { self.syndrome(word).none() }
+
+    /// Returns the codeword for a message `m` with `k` elements, which is the sum of the rows of the generator matrix
+    /// picked out by `m`.
+    ///
+    /// # Panics
+    /// Panics if `m` does not have `k` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let G: BitMatrix = BitMatrix::from_string("1000011 0100101 0010110 0001111").unwrap();
+    /// let code = LinearCode::from_generator(&G);
+    /// let c = code.encode(&BitVector::<usize>::from_string("1010").unwrap());
+    /// assert_eq!(c.to_string(), "1010101");
+    /// assert!(code.contains(&c));
+    /// ```
+    #[must_use]
+    pub fn encode<Src: BitStore<Word>>(&self, m: &Src) -> BitVector<Word> {
+        assert_eq!(m.len(), self.k(), "Message has {} elements but the code has dimension {}", m.len(), self.k());
+        let mut result = BitVector::zeros(self.n);
+        for i in m.set_bits() {
+            result ^= &self.G[i];
+        }
+        result
+    }
+
+    /// Returns the syndrome `H.w` of the bit-store `word` which has `n - k` elements.
+    ///
+    /// # Panics
+    /// Panics if `word` does not have `n` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H);
+    /// let e: BitVector = BitVector::unit(4, 7);
+    /// assert_eq!(code.syndrome(&e).to_string(), "101");
+    /// ```
+    #[must_use]
+    pub fn syndrome<Src: BitStore<Word>>(&self, word: &Src) -> BitVector<Word> {
+        assert_eq!(word.len(), self.n, "Word has {} elements but the code has length {}", word.len(), self.n);
+        BitVector::from_fn(self.redundancy(), |i| self.H[i].dot(word))
+    }
+
+    /// Returns a syndrome decoder that corrects every error pattern of weight at most `t = (d - 1) / 2`.
+    ///
+    /// Those are the patterns that can always be corrected. Other received words are reported as uncorrectable,
+    /// except for a perfect code such as a Hamming or Golay code where every received word is within `t` of a
+    /// codeword. The minimum distance and the table are both found by search, so this is for short codes.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let code: LinearCode = LinearCode::golay();
+    /// let decoder = code.decoder();
+    /// assert_eq!(decoder.len(), 1 << 11);
+    /// let c = code.encode(&BitVector::<usize>::ones(12));
+    /// let e: BitVector = BitVector::from_string("01000000010000000000100").unwrap();
+    /// assert_eq!(decoder.decode(&c.xor(&e)), Some((c, e)));
+    /// ```
+    #[must_use]
+    pub fn decoder(&self) -> SyndromeDecoder<Word> {
+        let t = self.d().map_or(0, |d| (d - 1) / 2);
+        SyndromeDecoder::build(self.n, &self.H, t)
+    }
+
+    /// Returns `true` if the code is contained in its dual, i.e., if every pair of codewords is orthogonal.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let hamming = LinearCode::from_parity_check(&H);
+    /// assert!(!hamming.is_self_orthogonal());
+    /// assert!(hamming.dual().is_self_orthogonal());
+    /// ```
+    #[must_use]
+    pub fn is_self_orthogonal(&self) -> bool {
+        let k = self.k();
+        (0..k).all(|i| (i..k).all(|j| !self.G[i].dot(&self.G[j])))
+    }
+
+    /// Returns `true` if the code is equal to its dual, i.e., if it is self-orthogonal with `2k = n`.
+    #[must_use]
+    pub fn is_self_dual(&self) -> bool { 2 * self.k() == self.n && self.is_self_orthogonal() }
+}
+
+/// Methods that make new codes from old ones.
+impl<Word: Unsigned> LinearCode<Word> {
+    /// Returns the dual code whose generator matrix is the parity-check matrix of this code and vice versa.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H);
+    /// let dual = code.dual();
+    /// assert_eq!(dual.generator(), &H);
+    /// assert_eq!(dual.dual(), code);
+    /// ```
+    #[must_use]
+    pub fn dual(&self) -> Self { Self { n: self.n, G: self.H.clone(), H: self.G.clone() } }
+
+    /// Returns the hull of the code, which is its intersection with its dual.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H);
+    /// assert_eq!(code.hull(), code.dual());
+    /// ```
+    #[must_use]
+    pub fn hull(&self) -> Self { Self::from_subspace(&self.to_subspace().intersection(&self.dual().to_subspace())) }
+
+    /// Returns the code punctured at some positions, i.e., with those elements deleted from every codeword.
+    ///
+    /// The punctured code has length `n - positions.len()` and its dimension drops if two codewords only differ at
+    /// the deleted positions.
+    ///
+    /// # Panics
+    /// Panics if any of the positions is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H).extended().punctured(&[7]);
+    /// assert_eq!(code, LinearCode::from_parity_check(&H));
+    /// ```
+    #[must_use]
+    pub fn punctured(&self, positions: &[usize]) -> Self {
+        let kept = self.kept_positions(positions);
+        let rows: Vec<_> = self.G.row_iter().map(|row| BitVector::from_fn(kept.len(), |j| row[kept[j]])).collect();
+        Self::from_subspace(&BitSubspace::from_vectors(kept.len(), &rows))
+    }
+
+    /// Returns the code shortened at some positions, i.e., the codewords that are zero at those positions with the
+    /// positions deleted.
+    ///
+    /// The shortened code has length `n - positions.len()` and its parity-check matrix is the parity-check matrix of
+    /// this code with those columns deleted.
+    ///
+    /// # Panics
+    /// Panics if any of the positions is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H).shortened(&[6]);
+    /// assert_eq!((code.n(), code.k(), code.d()), (6, 3, Some(3)));
+    /// ```
+    #[must_use]
+    pub fn shortened(&self, positions: &[usize]) -> Self {
+        let kept = self.kept_positions(positions);
+        let rows: Vec<_> = self.H.row_iter().map(|row| BitVector::from_fn(kept.len(), |j| row[kept[j]])).collect();
+        Self::from_subspace(&BitSubspace::from_vectors(kept.len(), &rows).orthogonal_complement())
+    }
+
+    /// Returns the code extended by an overall parity bit, so every codeword has even weight.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let code = LinearCode::from_parity_check(&H).extended();
+    /// assert_eq!((code.n(), code.k(), code.d()), (8, 4, Some(4)));
+    /// assert!(code.is_self_dual());
+    /// ```
+    #[must_use]
+    pub fn extended(&self) -> Self {
+        let n = self.n;
+        let parity = |i: usize| self.G[i].count_ones() % 2 == 1;
+        let G = BitMatrix::from_fn(self.k(), n + 1, |i, j| if j < n { self.G[i][j] } else { parity(i) });
+        Self::with_generator(n + 1, G)
+    }
+
+    /// Returns the code augmented by the all-ones word, i.e., the span of the code and the all-ones word.
+    ///
+    /// If the all-ones word is already a codeword then this returns a copy of the code.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let simplex = LinearCode::from_generator(&H);
+    /// let augmented = simplex.augmented();
+    /// assert_eq!(augmented.k(), 4);
+    /// assert_eq!(augmented, LinearCode::from_parity_check(&H));
+    /// ```
+    #[must_use]
+    pub fn augmented(&self) -> Self {
+        if self.contains(&BitVector::ones(self.n)) {
+            return self.clone();
+        }
+        let mut rows: Vec<_> = self.G.row_iter().cloned().collect();
+        rows.push(BitVector::ones(self.n));
+        Self::with_generator(self.n, BitMatrix::from_fn(rows.len(), self.n, |i, j| rows[i][j]))
+    }
+
+    /// Returns the code with a generator matrix `G` that has independent rows of length `n`.
+    fn with_generator(n: usize, G: BitMatrix<Word>) -> Self {
+        let H = Self::complement(&BitSubspace::from_vectors(n, &G.row_iter().cloned().collect::<Vec<_>>()));
+        Self { n, G, H }
+    }
+
+    /// Returns the positions in `0..n` that are not in `positions` in increasing order.
+    fn kept_positions(&self, positions: &[usize]) -> Vec<usize> {
+        let mut deleted = vec![false; self.n];
+        for &p in positions {
+            assert!(p < self.n, "Position {p} is out of bounds for a code of length {}", self.n);
+            deleted[p] = true;
+        }
+        (0..self.n).filter(|&j| !deleted[j]).collect()
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// Trait implementations for linear codes.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// Two codes are equal if they have the same codewords, whatever generator and parity-check matrices they use.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let a: LinearCode = LinearCode::from_generator(&BitMatrix::from_string("1100 0011").unwrap());
+/// let b: LinearCode = LinearCode::from_generator(&BitMatrix::from_string("1111 0011").unwrap());
+/// assert_eq!(a, b);
+/// ```
+impl<Word: Unsigned> PartialEq for LinearCode<Word> {
+    fn eq(&self, other: &Self) -> bool { self.n == other.n && self.to_subspace() == other.to_subspace() }
+}
+
+impl<Word: Unsigned> Eq for LinearCode<Word> {}
+
+/// The `Display` trait implementation for a code shows its parameters and generator matrix.
+///
+/// The alternate form `{:#}` shows the generator matrix on one line.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let code: LinearCode = LinearCode::from_generator(&BitMatrix::from_string("1100 0011").unwrap());
+/// assert_eq!(format!("{code:#}"), "[4, 2] code with generator 1100 0011");
+/// ```
+impl<Word: Unsigned> fmt::Display for LinearCode<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "[{}, {}] code with generator {:#}", self.n, self.k(), self.G)
+        }
+        else {
+            write!(f, "[{}, {}] code with generator\n{}", self.n, self.k(), self.G)
+        }
+    }
+}
+
+/// The `Debug` trait implementation for a code is the same as the alternate `Display` implementation.
+impl<Word: Unsigned> fmt::Debug for LinearCode<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{self:#}") }
+}
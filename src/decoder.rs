@@ -0,0 +1,299 @@
+//! [`SyndromeDecoder`] is a hard-decision decoder for binary linear codes that looks up coset leaders by syndrome.
+#![allow(non_snake_case)]
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitStore,
+    BitVector,
+    Unsigned,
+};
+
+// Standard library imports.
+use std::{
+    collections::HashMap,
+    fmt,
+};
+
+/// The largest number of parity checks for which the lookup table is a plain array indexed by the syndrome.
+const DENSE_TABLE_BITS: usize = 24;
+
+/// The marker in a dense table for a syndrome that has no coset leader.
+const NO_LEADER: u32 = u32::MAX;
+
+#[doc = include_str!("../docs/decoder.md")]
+#[derive(Clone)]
+pub struct SyndromeDecoder<Word: Unsigned = usize> {
+    // The length of the codewords, which we keep separately as a parity-check matrix without rows has no columns.
+    n: usize,
+
+    // The parity-check matrix.
+    H: BitMatrix<Word>,
+
+    // The columns of `H`, which are the syndromes of the single bit errors.
+    columns: Vec<BitVector<Word>>,
+
+    // The largest weight of a coset leader in the table.
+    max_weight: usize,
+
+    // For each syndrome with a coset leader, the last position added when the leader was found (see `leader`).
+    table: Table<Word>,
+}
+
+/// The lookup table from syndromes to the last position of their coset leaders.
+#[derive(Clone)]
+enum Table<Word: Unsigned> {
+    // Indexed by the syndrome as the binary number `s[0] + 2 s[1] + ...` with `NO_LEADER` for the missing syndromes.
+    Dense(Vec<u32>),
+
+    // Keyed by the syndrome for bounded tables that only reach a few of the syndromes, or for parity-check matrices
+    // with too many rows for a dense table.
+    Sparse(HashMap<BitVector<Word>, u32>),
+}
+
+/// Constructors for syndrome decoders.
+impl<Word: Unsigned> SyndromeDecoder<Word> {
+    /// Returns a complete decoder for the code with parity-check matrix `H`, which has a coset leader for every
+    /// syndrome.
+    ///
+    /// Every received word is decoded to a nearest codeword. The table has `2^r` entries where `r` is the number of
+    /// rows of `H`, so this is for codes with at most 24 parity checks.
+    ///
+    /// # Panics
+    /// Panics if `H` has more than 24 rows or more than `2^32 - 1` columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let decoder = SyndromeDecoder::complete(&H);
+    /// assert_eq!(decoder.len(), 8);
+    /// assert_eq!(decoder.max_weight(), 1);
+    /// ```
+    #[must_use]
+    pub fn complete(H: &BitMatrix<Word>) -> Self {
+        assert!(H.rows() <= DENSE_TABLE_BITS, "A complete decoder needs at most {DENSE_TABLE_BITS} parity checks");
+        Self::build(H.cols(), H, usize::MAX)
+    }
+
+    /// Returns a decoder for the code with parity-check matrix `H` that corrects every error pattern of weight at most
+    /// `t`.
+    ///
+    /// Received words whose syndrome is not that of a pattern of weight at most `t` are reported as uncorrectable by
+    /// [`SyndromeDecoder::decode`]. The table has an entry for each correctable syndrome, so the cost is about the
+    /// number of error patterns of weight at most `t`. That makes this mode suitable for longer codes with many parity
+    /// checks.
+    ///
+    /// # Panics
+    /// Panics if `H` has more than `2^32 - 1` columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let decoder = SyndromeDecoder::bounded(&H, 0);
+    /// assert_eq!(decoder.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn bounded(H: &BitMatrix<Word>, t: usize) -> Self { Self::build(H.cols(), H, t) }
+
+    /// Builds the table with a breadth-first search over the syndromes.
+    ///
+    /// The syndromes of the error patterns of weight `w + 1` are the syndromes of weight `w` plus a column of `H`, so
+    /// the first time we reach a syndrome we have found a lightest error pattern for it.
+    ///
+    /// The codewords have `n` elements which must match the columns of `H` unless `H` has no rows.
+    pub(crate) fn build(n: usize, H: &BitMatrix<Word>, t: usize) -> Self {
+        let r = H.rows();
+        assert!(r == 0 || H.cols() == n, "Parity-check matrix has {} columns but the code has length {n}", H.cols());
+        assert!(n < NO_LEADER as usize, "Parity-check matrix has too many columns");
+        let columns: Vec<BitVector<Word>> = (0..n).map(|j| BitVector::from_fn(r, |i| H[i][j])).collect();
+        let mut max_weight = 0;
+
+        // A dense table only pays for itself if the search can reach most of the `2^r` syndromes.
+        let dense = r <= DENSE_TABLE_BITS && error_patterns(n, t, 1 << r) == 1 << r;
+        let table = if dense {
+            let values: Vec<usize> = columns.iter().map(to_index).collect();
+            let mut table = vec![NO_LEADER; 1 << r];
+            let mut frontier = vec![0];
+            while !frontier.is_empty() && max_weight < t {
+                let mut next = Vec::new();
+                for s in frontier {
+                    for (j, &c) in values.iter().enumerate() {
+                        let s = s ^ c;
+                        if s != 0 && table[s] == NO_LEADER {
+                            table[s] = j as u32;
+                            next.push(s);
+                        }
+                    }
+                }
+                frontier = next;
+                if !frontier.is_empty() {
+                    max_weight += 1;
+                }
+            }
+            Table::Dense(table)
+        }
+        else {
+            let mut table = HashMap::new();
+            let mut frontier = vec![BitVector::zeros(r)];
+            while !frontier.is_empty() && max_weight < t {
+                let mut next = Vec::new();
+                for s in &frontier {
+                    for (j, c) in columns.iter().enumerate() {
+                        let s = s ^ c;
+                        if s.any() && !table.contains_key(&s) {
+                            table.insert(s.clone(), j as u32);
+                            next.push(s);
+                        }
+                    }
+                }
+                frontier = next;
+                if !frontier.is_empty() {
+                    max_weight += 1;
+                }
+            }
+            Table::Sparse(table)
+        };
+        Self { n, H: H.clone(), columns, max_weight, table }
+    }
+}
+
+/// Methods to query and use a syndrome decoder.
+impl<Word: Unsigned> SyndromeDecoder<Word> {
+    /// Returns the parity-check matrix.
+    #[must_use]
+    pub fn parity_check(&self) -> &BitMatrix<Word> { &self.H }
+
+    /// Returns the number of syndromes in the table including the zero syndrome.
+    ///
+    /// This is the number of cosets of the code that the decoder can correct.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match &self.table {
+            Table::Dense(table) => 1 + table.iter().filter(|&&j| j != NO_LEADER).count(),
+            Table::Sparse(table) => 1 + table.len(),
+        }
+    }
+
+    /// Returns `true` if the table only has the zero syndrome so the decoder corrects no errors.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.len() == 1 }
+
+    /// Returns the largest weight of a coset leader in the table.
+    ///
+    /// For a complete decoder this is the _covering radius_ of the code.
+    #[must_use]
+    pub fn max_weight(&self) -> usize { self.max_weight }
+
+    /// Returns the syndrome `H.w` of the bit-store `word`.
+    ///
+    /// # Panics
+    /// Panics if `word` does not have one element for each column of `H`.
+    #[must_use]
+    pub fn syndrome<Src: BitStore<Word>>(&self, word: &Src) -> BitVector<Word> {
+        let n = self.n;
+        assert_eq!(word.len(), n, "Word has {} elements but the code has length {n}", word.len());
+        let mut result = BitVector::zeros(self.H.rows());
+        for j in word.set_bits() {
+            result ^= &self.columns[j];
+        }
+        result
+    }
+
+    /// Returns a lightest error pattern with the given syndrome or `None` if that syndrome is not in the table.
+    ///
+    /// # Panics
+    /// Panics if the syndrome does not have one element for each row of `H`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let decoder = SyndromeDecoder::complete(&H);
+    /// let e = decoder.coset_leader(&BitVector::<usize>::from_string("101").unwrap()).unwrap();
+    /// assert_eq!(e.to_string(), "0000100");
+    /// ```
+    #[must_use]
+    pub fn coset_leader<Src: BitStore<Word>>(&self, syndrome: &Src) -> Option<BitVector<Word>> {
+        let r = self.H.rows();
+        assert_eq!(syndrome.len(), r, "Syndrome has {} elements but there are {r} parity checks", syndrome.len());
+
+        // Follow the chain of positions back to the zero syndrome.
+        let mut result = BitVector::zeros(self.n);
+        let mut s = BitVector::from_store(syndrome);
+        while s.any() {
+            let j = match &self.table {
+                Table::Dense(table) => Some(table[to_index(&s)]).filter(|&j| j != NO_LEADER),
+                Table::Sparse(table) => table.get(&s).copied(),
+            }? as usize;
+            result.flip(j);
+            s ^= &self.columns[j];
+        }
+        Some(result)
+    }
+
+    /// Decodes a received word and returns the corrected codeword and the error pattern, or `None` if the syndrome of
+    /// the received word is not in the table.
+    ///
+    /// The error pattern is the coset leader for the syndrome of `received`, which is a lightest bit-vector in the
+    /// same coset, and the codeword is `received` plus the error pattern.
+    ///
+    /// # Panics
+    /// Panics if `received` does not have one element for each column of `H`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let H: BitMatrix = BitMatrix::from_string("1010101 0110011 0001111").unwrap();
+    /// let decoder = SyndromeDecoder::complete(&H);
+    /// let c: BitVector = BitVector::from_string("1010101").unwrap();
+    /// let mut r = c.clone();
+    /// r.flip(2);
+    /// let (decoded, e) = decoder.decode(&r).unwrap();
+    /// assert_eq!(decoded, c);
+    /// assert_eq!(e, BitVector::unit(2, 7));
+    /// ```
+    #[must_use]
+    pub fn decode<Src: BitStore<Word>>(&self, received: &Src) -> Option<(BitVector<Word>, BitVector<Word>)> {
+        let e = self.coset_leader(&self.syndrome(received))?;
+        let codeword = received.xor(&e);
+        Some((codeword, e))
+    }
+}
+
+/// Returns the bit-store `s` as the binary number `s[0] + 2 s[1] + 4 s[2] + ...` for a short syndrome.
+fn to_index<Word: Unsigned, Src: BitStore<Word>>(s: &Src) -> usize { s.set_bits().fold(0, |x, i| x | (1 << i)) }
+
+/// Returns the number of error patterns of length `n` with weight at most `t`, or `cap` if there are at least that
+/// many.
+fn error_patterns(n: usize, t: usize, cap: usize) -> usize {
+    let mut total = 0;
+    let mut binomial = 1;
+    for w in 0..=t.min(n) {
+        total += binomial;
+        if total >= cap {
+            return cap;
+        }
+        binomial = binomial * (n - w) / (w + 1);
+    }
+    total
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// Trait implementations for syndrome decoders.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// The `Debug` trait implementation for a decoder shows the size of the code and of the table.
+impl<Word: Unsigned> fmt::Debug for SyndromeDecoder<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SyndromeDecoder {{ n: {}, r: {}, syndromes: {}, max_weight: {} }}",
+            self.n,
+            self.H.rows(),
+            self.len(),
+            self.max_weight
+        )
+    }
+}
@@ -0,0 +1,333 @@
+#![doc = include_str!("../docs/enumeration.md")]
+
+// Crate types.
+use crate::{
+    BitStore,
+    BitVector,
+    Unsigned,
+};
+
+// ---------------------------------------------------------------------------------------------------------------------
+// The `GrayCode` iterator.
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// An iterator over all the bit-vectors of length `n` in reflected binary Gray code order.
+///
+/// Each item is a pair `(v, flipped)` where `flipped` is the index of the one element that differs from the previous
+/// item. The first item is the zero vector with `flipped` set to `None`. Element `k` of the sequence is the Gray code
+/// of the binary number `k` as returned by [`BitStore::to_gray`].
+///
+/// The successor is found from the parity of the current vector, so the iterator works for any `n` and each step
+/// costs a single clone of the current vector.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let items: Vec<(String, Option<usize>)> = GrayCode::<usize>::new(3).map(|(v, i)| (v.to_string(), i)).collect();
+/// assert_eq!(items[0], ("000".to_string(), None));
+/// assert_eq!(items[1], ("100".to_string(), Some(0)));
+/// assert_eq!(items[2], ("110".to_string(), Some(1)));
+/// assert_eq!(items[3], ("010".to_string(), Some(0)));
+/// assert_eq!(items[4], ("011".to_string(), Some(2)));
+/// assert_eq!(items.len(), 8);
+/// ```
+pub struct GrayCode<Word: Unsigned = usize> {
+    current: Option<BitVector<Word>>,
+    flipped: Option<usize>,
+    odd:     bool,
+}
+
+/// Construct a `GrayCode` iterator and map between its items and their positions in the sequence.
+impl<Word: Unsigned> GrayCode<Word> {
+    /// Creates an iterator over all the bit-vectors of length `n` in Gray code order.
+    #[must_use]
+    pub fn new(n: usize) -> Self { Self { current: Some(BitVector::zeros(n)), flipped: None, odd: false } }
+
+    /// Returns the position of the bit-store `v` in the Gray code sequence for its length.
+    ///
+    /// # Panics
+    /// Panics if the position does not fit in a `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// for (k, (v, _)) in GrayCode::<u8>::new(5).enumerate() {
+    ///     assert_eq!(GrayCode::rank(&v), k);
+    ///     assert_eq!(GrayCode::unrank(5, k), v);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn rank<Src: BitStore<Word>>(v: &Src) -> usize { to_usize(&v.from_gray()) }
+
+    /// Returns the bit-vector of length `n` at position `r` in the Gray code sequence.
+    ///
+    /// # Panics
+    /// Panics if `r` is not less than `2^n`.
+    #[must_use]
+    pub fn unrank(n: usize, r: usize) -> BitVector<Word> {
+        assert!(n >= usize::BITS as usize || r >> n == 0, "Position {r} is out of range for length {n}");
+        from_usize(n, r ^ (r >> 1))
+    }
+}
+
+/// Implement the `Iterator` trait for `GrayCode`.
+impl<Word: Unsigned> Iterator for GrayCode<Word> {
+    type Item = (BitVector<Word>, Option<usize>);
+
+    /// Returns the next bit-vector in Gray code order and the index of the element that was flipped to reach it.
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.as_mut()?;
+        let result = (current.clone(), self.flipped);
+
+        // With even weight we flip element 0 and otherwise we flip the element after the first set one.
+        let i = if self.odd { current.first_set().map_or(current.len(), |p| p + 1) } else { 0 };
+        if i < current.len() {
+            current.flip(i);
+            self.flipped = Some(i);
+            self.odd = !self.odd;
+        }
+        else {
+            self.current = None;
+        }
+        Some(result)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------------------
+// The `FixedWeight` iterator.
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// An iterator over all the bit-vectors of length `n` with exactly `k` set elements.
+///
+/// The bit-vectors come in increasing order as the binary numbers `v[0] + 2 v[1] + 4 v[2] + ...`, which is the
+/// _colexicographic_ order on the sets of indices of the set elements. The first item has its `k` set elements at the
+/// start and the last has them at the end. Each successor is found with a word-level version of Gosper's hack: the
+/// lowest block of set elements moves its top element up by one and the rest of the block drops to the start.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let items: Vec<String> = FixedWeight::<usize>::new(4, 2).map(|v| v.to_string()).collect();
+/// assert_eq!(items, ["1100", "1010", "0110", "1001", "0101", "0011"]);
+/// ```
+pub struct FixedWeight<Word: Unsigned = usize> {
+    current: Option<BitVector<Word>>,
+}
+
+/// Construct a `FixedWeight` iterator and map between its items and their positions in the sequence.
+impl<Word: Unsigned> FixedWeight<Word> {
+    /// Creates an iterator over the bit-vectors of length `n` with exactly `k` set elements.
+    ///
+    /// If `k > n` there are no such bit-vectors and the iterator is empty.
+    #[must_use]
+    pub fn new(n: usize, k: usize) -> Self {
+        let current = (k <= n).then(|| BitVector::from_fn(n, |i| i < k));
+        Self { current }
+    }
+
+    /// Returns the position of the bit-store `v` in the sequence of bit-vectors of its length and weight.
+    ///
+    /// If the set elements of `v` are at `c_1 < c_2 < ... < c_k` then the position is the sum of the binomial
+    /// coefficients `C(c_i, i)`.
+    ///
+    /// # Panics
+    /// Panics if the position does not fit in a `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// for (r, v) in FixedWeight::<u8>::new(9, 4).enumerate() {
+    ///     assert_eq!(FixedWeight::rank(&v), r);
+    ///     assert_eq!(FixedWeight::unrank(9, 4, r), v);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn rank<Src: BitStore<Word>>(v: &Src) -> usize {
+        v.set_bits().enumerate().map(|(i, c)| binomial(c, i + 1)).fold(0, checked_add)
+    }
+
+    /// Returns the bit-vector at position `r` in the sequence of bit-vectors of length `n` with `k` set elements.
+    ///
+    /// # Panics
+    /// Panics if `k > n` or if `r` is not less than the binomial coefficient `C(n, k)`.
+    #[must_use]
+    pub fn unrank(n: usize, k: usize, r: usize) -> BitVector<Word> {
+        assert!(k <= n, "There are no bit-vectors of length {n} with {k} set elements");
+        assert!(binomial_or_max(n, k) > r, "Position {r} is out of range for {k} set elements in length {n}");
+
+        // Peel off the set elements from the top using the largest binomial coefficient that fits each time.
+        let mut result = BitVector::zeros(n);
+        let mut r = r;
+        let mut c = n;
+        for i in (1..=k).rev() {
+            c -= 1;
+            while binomial_or_max(c, i) > r {
+                c -= 1;
+            }
+            result.set(c, true);
+            r -= binomial(c, i);
+        }
+        result
+    }
+}
+
+/// Implement the `Iterator` trait for `FixedWeight`.
+impl<Word: Unsigned> Iterator for FixedWeight<Word> {
+    type Item = BitVector<Word>;
+
+    /// Returns the next bit-vector with the given weight in increasing binary order.
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.as_mut()?;
+        let result = current.clone();
+
+        // The lowest block of set elements runs from `p` up to but not including `q`.
+        let next = current.first_set().and_then(|p| current.next_unset(p).map(|q| (p, q)));
+        match next {
+            Some((p, q)) => {
+                current.set(q, true);
+                current.slice_mut(0..q).set_all(false);
+                if q > p + 1 {
+                    current.slice_mut(0..q - p - 1).set_all(true);
+                }
+            },
+            None => self.current = None,
+        }
+        Some(result)
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------------------
+// The `BoundedWeight` iterator.
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// An iterator over all the bit-vectors of length `n` with at most `k` set elements.
+///
+/// The bit-vectors come in order of increasing weight and then in the [`FixedWeight`] order for each weight. That is
+/// the natural order for searches that want the lightest solution first, such as looking for coset leaders or
+/// low-weight codewords.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let items: Vec<String> = BoundedWeight::<usize>::new(3, 2).map(|v| v.to_string()).collect();
+/// assert_eq!(items, ["000", "100", "010", "001", "110", "101", "011"]);
+/// ```
+pub struct BoundedWeight<Word: Unsigned = usize> {
+    n:       usize,
+    k:       usize,
+    weight:  usize,
+    current: FixedWeight<Word>,
+}
+
+/// Construct a `BoundedWeight` iterator and map between its items and their positions in the sequence.
+impl<Word: Unsigned> BoundedWeight<Word> {
+    /// Creates an iterator over the bit-vectors of length `n` with at most `k` set elements.
+    #[must_use]
+    pub fn new(n: usize, k: usize) -> Self { Self { n, k: k.min(n), weight: 0, current: FixedWeight::new(n, 0) } }
+
+    /// Returns the position of the bit-store `v` in the sequence of bit-vectors of its length ordered by weight.
+    ///
+    /// The position does not depend on the bound `k` as long as `v` is in the sequence.
+    ///
+    /// # Panics
+    /// Panics if the position does not fit in a `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// for (r, v) in BoundedWeight::<u8>::new(7, 3).enumerate() {
+    ///     assert_eq!(BoundedWeight::rank(&v), r);
+    ///     assert_eq!(BoundedWeight::unrank(7, r), v);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn rank<Src: BitStore<Word>>(v: &Src) -> usize {
+        let below = (0..v.count_ones()).map(|w| binomial(v.len(), w)).fold(0, checked_add);
+        checked_add(below, FixedWeight::rank(v))
+    }
+
+    /// Returns the bit-vector of length `n` at position `r` in the sequence ordered by weight.
+    ///
+    /// # Panics
+    /// Panics if `r` is not less than `2^n`.
+    #[must_use]
+    pub fn unrank(n: usize, r: usize) -> BitVector<Word> {
+        let mut r = r;
+        for w in 0..=n {
+            let count = binomial_or_max(n, w);
+            if r < count {
+                return FixedWeight::unrank(n, w, r);
+            }
+            r -= count;
+        }
+        panic!("Position is out of range for length {n}");
+    }
+}
+
+/// Implement the `Iterator` trait for `BoundedWeight`.
+impl<Word: Unsigned> Iterator for BoundedWeight<Word> {
+    type Item = BitVector<Word>;
+
+    /// Returns the next bit-vector in order of weight.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(v) = self.current.next() {
+                return Some(v);
+            }
+            if self.weight == self.k {
+                return None;
+            }
+            self.weight += 1;
+            self.current = FixedWeight::new(self.n, self.weight);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------------------
+// Helper functions for the ranks.
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// Returns the binomial coefficient `C(n, k)` or `usize::MAX` if it is too large to fit in a `usize`.
+fn binomial_or_max(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+
+    // Each partial product `C(n, 0) * ... ` is itself a binomial coefficient so the divisions are exact.
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for j in 0..k {
+        result = result * (n - j) as u128 / (j + 1) as u128;
+        if result > usize::MAX as u128 {
+            return usize::MAX;
+        }
+    }
+    result as usize
+}
+
+/// Returns the binomial coefficient `C(n, k)`.
+///
+/// Panics if it does not fit in a `usize`.
+fn binomial(n: usize, k: usize) -> usize {
+    let result = binomial_or_max(n, k);
+    assert!(result < usize::MAX, "Binomial coefficient C({n}, {k}) is too large for a usize");
+    result
+}
+
+/// Returns `a + b` for two pieces of a position.
+///
+/// Panics if the sum does not fit in a `usize`.
+fn checked_add(a: usize, b: usize) -> usize { a.checked_add(b).expect("Position is too large for a usize") }
+
+/// Returns the bit-store `v` as the binary number `v[0] + 2 v[1] + 4 v[2] + ...`.
+fn to_usize<Word: Unsigned, Src: BitStore<Word>>(v: &Src) -> usize {
+    if let Some(last) = v.last_set() {
+        assert!(last < usize::BITS as usize, "Position is too large for a usize");
+    }
+    v.set_bits().fold(0, |x, i| x | (1 << i))
+}
+
+/// Returns the bit-vector of length `n` whose elements are the bits of `x`.
+fn from_usize<Word: Unsigned>(n: usize, x: usize) -> BitVector<Word> {
+    BitVector::from_fn(n, |i| i < usize::BITS as usize && (x >> i) & 1 == 1)
+}
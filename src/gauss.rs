@@ -11,13 +11,15 @@ use crate::{
 
 #[doc = include_str!("../docs/gauss.md")]
 pub struct BitGauss<Word: Unsigned = usize> {
-    // The *row echelon form* of the matrix `A` where we are solving `A = b`.
-    A_ref: BitMatrix<Word>,
+    // A basis for the kernel of the matrix `A` stored as the rows of a bit-matrix -- one row per free variable.
+    // Row `t` is the solution to `A.x = 0` with a 1 for free variable `t` and 0 for all the other free variables.
+    kernel: BitMatrix<Word>,
 
-    // The equivalent transformed version of the vector `b` where we are solving `A.x = b`.
-    b_ref: BitVector<Word>,
+    // The particular solution for each right-hand side stored as the rows of a bit-matrix.
+    // These are the solutions we get by setting all the free variables to 0.
+    particular: BitMatrix<Word>,
 
-    // The rank of the matrix `A`. This is also the number of non-zero rows in `A_ref`
+    // The rank of the matrix `A`.
     rank: usize,
 
     // The index locations of any "free" variables if the system is underdetermined.
@@ -28,11 +30,15 @@ pub struct BitGauss<Word: Unsigned = usize> {
     solution_count: usize,
 }
 
+/// Constructors.
 impl<Word: Unsigned> BitGauss<Word> {
     /// Constructs a new `BitGauss` struct where we are solving the system of linear equations `A.x = b`.
     ///
+    /// The bit-matrix `A` can have any shape, so the system can be over or under-determined.
+    /// If `A` is `m x n` then `b` must have `m` elements and the solutions `x` will have `n` elements.
+    ///
     /// # Panics
-    /// Panics if the `A` matrix is not square or if the `A` matrix and `b` vector have a different number of rows.
+    /// Panics if the `A` matrix is empty or if the `A` matrix and `b` vector have a different number of rows.
     ///
     /// # Examples
     /// ```
@@ -45,40 +51,93 @@ impl<Word: Unsigned> BitGauss<Word> {
     /// assert_eq!(solver.is_consistent(), true);
     /// assert_eq!(solver.free_count(), 2);
     /// assert_eq!(solver.solution_count(), 4);
+    /// let A: BitMatrix = BitMatrix::from_string("1100 0110").unwrap();
+    /// let b: BitVector = BitVector::from_string("11").unwrap();
+    /// let solver: BitGauss = BitGauss::new(&A, &b);
+    /// assert_eq!(solver.free_count(), 2);
+    /// assert_eq!(&A * &solver.x().unwrap(), b);
     /// ```
     #[must_use]
     pub fn new(A: &BitMatrix<Word>, b: &BitVector<Word>) -> Self {
-        assert!(A.is_square(), "The matrix must be square not {}x{}", A.rows(), A.cols());
+        assert!(!A.is_empty(), "The matrix must not be empty");
         assert!(A.rows() == b.len(), "The matrix and vector must have the same number of rows");
 
         // Create a working copy of A, and augment it with b as an extra column on the right.
-        let mut A_ref = A.clone();
-        A_ref.append_col(b);
+        let mut AB = A.clone();
+        AB.append_col(b);
+        Self::from_augmented(AB, A.cols())
+    }
+
+    /// Constructs a new `BitGauss` struct where we are solving the system of linear equations `A.X = B`.
+    ///
+    /// Each column of `B` is a separate right-hand side, and the corresponding column of `X` is a solution for it.
+    /// The system is consistent only if there is a solution for *every* one of those columns.
+    /// If `A` is `m x n` and `B` is `m x k` then the solutions `X` are `n x k` bit-matrices.
+    ///
+    /// # Panics
+    /// Panics if the `A` matrix is empty or if the `A` and `B` matrices have a different number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::random(30, 40);
+    /// let B: BitMatrix = &A * &BitMatrix::random(40, 5);
+    /// let solver: BitGauss = BitGauss::new_for_matrix(&A, &B);
+    /// assert!(solver.is_consistent());
+    /// assert_eq!(&A * &solver.X().unwrap(), B);
+    /// ```
+    #[must_use]
+    pub fn new_for_matrix(A: &BitMatrix<Word>, B: &BitMatrix<Word>) -> Self {
+        assert!(!A.is_empty(), "The matrix must not be empty");
+        assert!(A.rows() == B.rows(), "The left and right-hand side matrices must have the same number of rows");
+
+        // Create a working copy of A, and augment it with the columns of B on the right.
+        let mut AB = A.clone();
+        AB.append_cols(B);
+        Self::from_augmented(AB, A.cols())
+    }
 
-        // Get the reduced row echelon form of A|b and the vector that marks the pivot columns.
-        let mut has_pivot = A_ref.to_reduced_echelon_form();
+    /// Helper function that does the work for the constructors given the augmented matrix `A|B` where `A` has `n`
+    /// columns.
+    fn from_augmented(mut AB: BitMatrix<Word>, n: usize) -> Self {
+        let k = AB.cols() - n;
 
-        // Grab the last column of the reduced A|b as a standalone vector, removing it from the matrix.
-        let b_ref = A_ref.remove_col().unwrap();
+        // Get the reduced row echelon form of A|B and the vector that marks the pivot columns.
+        let mut has_pivot = AB.to_reduced_echelon_form();
 
-        // Can also remove the last element from `has_pivot` as it corresponded to the extra column we added to `A_ref`.
-        let _ = has_pivot.pop();
+        // Only the pivots in the columns of A matter (a pivot in a column of B just means that right-hand side has no
+        // solution). Any extra pivot rows are below the `rank` rows with pivots in A, so those rows are not disturbed.
+        has_pivot.resize(n);
+        let pivots: Vec<usize> = has_pivot.set_bits().collect();
 
         // The rank of the matrix `A` is the number of columns with a pivot.
-        // This is also the number of non-zero rows in `A_ref`
-        let rank = has_pivot.count_ones();
+        // This is also the number of rows at the top of `AB` that have a non-zero `A` part.
+        let rank = pivots.len();
 
         // Any column *without* a pivot corresponds to a free variable in the system. Collect those indices.
-        has_pivot.flip_all();
-        let free: Vec<usize> = has_pivot.set_bits().collect();
-
-        // Check that the zero rows in `A_ref` are matched with zero entries in `b_ref`. This is consistency.
-        let mut consistent = true;
-        // Any zero rows in `A_ref` are at the bottom from `rank` to the end.
-        for i in rank..A_ref.rows() {
-            if b_ref[i] {
-                consistent = false;
-                break;
+        let free: Vec<usize> = has_pivot.unset_bits().collect();
+
+        // The system is consistent if the zero rows in the `A` part are matched with zero rows in the `B` part.
+        let consistent = (rank..AB.rows()).all(|i| AB[i].slice(n..n + k).none());
+
+        // Each free variable gives a kernel basis vector with a 1 in that slot and the pivot variables solved for.
+        let mut kernel = BitMatrix::zeros(free.len(), n);
+        for (t, &f) in free.iter().enumerate() {
+            kernel[t].set(f, true);
+            for (i, &p) in pivots.iter().enumerate() {
+                if AB[i][f] {
+                    kernel[t].set(p, true);
+                }
+            }
+        }
+
+        // Setting all the free variables to zero gives a particular solution for each column in the `B` part.
+        let mut particular = BitMatrix::zeros(k, n);
+        for (i, &p) in pivots.iter().enumerate() {
+            for j in 0..k {
+                if AB[i][n + j] {
+                    particular.set(j, p, true);
+                }
             }
         }
 
@@ -93,9 +152,12 @@ impl<Word: Unsigned> BitGauss<Word> {
         }
 
         // Return the struct.
-        Self { A_ref, b_ref, rank, free, solution_count }
+        Self { kernel, particular, rank, free, solution_count }
     }
+}
 
+/// Queries about the system of linear equations.
+impl<Word: Unsigned> BitGauss<Word> {
     /// Returns the rank of the matrix `A`.
     ///
     /// # Examples
@@ -140,7 +202,8 @@ impl<Word: Unsigned> BitGauss<Word> {
 
     /// Returns `true` if the system of linear equations `A.x = b` is consistent.
     ///
-    /// A system is consistent if there is at least one solution.
+    /// A system is consistent if there is at least one solution. If the right-hand side is a bit-matrix `B`, there must
+    /// be a solution for every one of its columns.
     ///
     /// # Examples
     /// ```
@@ -149,15 +212,43 @@ impl<Word: Unsigned> BitGauss<Word> {
     /// let b: BitVector = BitVector::from_string("111").unwrap();
     /// let solver: BitGauss = BitGauss::new(&A, &b);
     /// assert!(solver.is_consistent());
+    /// let B: BitMatrix = BitMatrix::from_string("10 10 11").unwrap();
+    /// let solver: BitGauss = BitGauss::new_for_matrix(&A, &B);
+    /// assert!(!solver.is_consistent());
     /// ```
     #[inline]
     #[must_use]
     pub fn is_consistent(&self) -> bool { self.solution_count > 0 }
 
+    /// Returns the maximum number of solutions we can index into.
+    ///
+    /// This may be 0, 1, or 2^f for some `f` where `f` is the number of free variables in an underdetermined system.
+    /// For the `xi(i: usize)` function we limit that to the largest power of 2 that fits in `usize`.
+    ///
+    /// If `usize` is 64 bits then `solution_count = min(2^f, 2^63)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("111 111 111").unwrap();
+    /// let b: BitVector = BitVector::from_string("111").unwrap();
+    /// let solver: BitGauss = BitGauss::new(&A, &b);
+    /// assert_eq!(solver.solution_count(), 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn solution_count(&self) -> usize { self.solution_count }
+}
+
+/// Methods that return solutions for the system `A.x = b`.
+impl<Word: Unsigned> BitGauss<Word> {
     /// Returns a solution to the system of linear equations `A.x = b` or `None` if the system is inconsistent.
     ///
-    /// If the system is underdetermined with `f` free variables the returned solution will have `f` random 0/1 entries
-    /// for those indices.
+    /// If the system is underdetermined with `f` free variables the returned solution is drawn uniformly at random
+    /// from the `2^f` possibilities.
+    ///
+    /// # Panics
+    /// Panics if the solver was constructed with a bit-matrix right-hand side that has more than one column.
     ///
     /// # Examples
     /// ```
@@ -168,37 +259,36 @@ impl<Word: Unsigned> BitGauss<Word> {
     /// assert_eq!(solver.x().unwrap().to_string(), "111");
     /// ```
     #[must_use]
-    pub fn x(&self) -> Option<BitVector<Word>> {
-        if !self.is_consistent() {
-            return None;
-        }
-
-        // Create a random starting point.
-        let mut result = BitVector::random(self.b_ref.len());
-
-        // All non-free variables will be overwritten by back substitution.
-        self.back_substitute_into(&mut result);
-        Some(result)
-    }
+    pub fn x(&self) -> Option<BitVector<Word>> { self.x_seeded(0) }
 
-    /// Returns the maximum number of solutions we can index into.
+    /// Returns a solution to the system of linear equations `A.x = b` or `None` if the system is inconsistent.
     ///
-    /// This may be 0, 1, or 2^f for some `f` where `f` is the number of free variables in an underdetermined system.
-    /// For the `xi(i: usize)` function we limit that to the largest power of 2 that fits in `usize`.
+    /// If the system is underdetermined with `f` free variables the returned solution is drawn uniformly at random
+    /// from the `2^f` possibilities. For reproducibility, the random number generator is seeded with `seed` and then
+    /// reset to its previous state. A seed of `0` means we use the random number generator as is.
     ///
-    /// If `usize` is 64 bits then `solution_count = min(2^f, 2^63)`.
+    /// # Panics
+    /// Panics if the solver was constructed with a bit-matrix right-hand side that has more than one column.
     ///
     /// # Examples
     /// ```
     /// use gf2::*;
-    /// let A: BitMatrix = BitMatrix::from_string("111 111 111").unwrap();
-    /// let b: BitVector = BitVector::from_string("111").unwrap();
+    /// let A: BitMatrix = BitMatrix::random(20, 50);
+    /// let b: BitVector = &A * &BitVector::random(50);
     /// let solver: BitGauss = BitGauss::new(&A, &b);
-    /// assert_eq!(solver.solution_count(), 4);
+    /// let x = solver.x_seeded(42).unwrap();
+    /// assert_eq!(&A * &x, b);
+    /// assert_eq!(solver.x_seeded(42).unwrap(), x);
     /// ```
-    #[inline]
     #[must_use]
-    pub fn solution_count(&self) -> usize { self.solution_count }
+    pub fn x_seeded(&self, seed: u64) -> Option<BitVector<Word>> {
+        self.assert_vector_rhs();
+        if !self.is_consistent() {
+            return None;
+        }
+        let coeffs = BitVector::random_seeded(self.free.len(), seed);
+        Some(self.solution_for(0, &coeffs))
+    }
 
     /// Returns the `i`th solution to the system of linear equations `A.x = b` or `None` if the system is
     /// inconsistent or if `i` is out of bounds.
@@ -209,7 +299,10 @@ impl<Word: Unsigned> BitGauss<Word> {
     /// If the system is underdetermined with `f` free variables, it has `2^f` possible solutions.
     /// If `f` is large, `2^f` may not fit in `usize` but here we limit the number of *indexable* solutions to the
     /// largest power of 2 that fits in `usize`. The indexing scheme is certainly not unique but it is consistent across
-    /// runs.
+    /// runs. Use the [`BitGauss::solutions`] iterator to visit every solution no matter how many there are.
+    ///
+    /// # Panics
+    /// Panics if the solver was constructed with a bit-matrix right-hand side that has more than one column.
     ///
     /// # Examples
     /// ```
@@ -222,6 +315,7 @@ impl<Word: Unsigned> BitGauss<Word> {
     /// assert_eq!(solver.xi(1).unwrap().to_string(), "010", "xi(1) = 010");
     /// assert_eq!(solver.xi(2).unwrap().to_string(), "001", "xi(2) = 001");
     /// assert_eq!(solver.xi(3).unwrap().to_string(), "111", "xi(3) = 111");
+    /// assert_eq!(solver.xi(4), None);
     /// let A: BitMatrix = BitMatrix::identity(3);
     /// let solver: BitGauss = BitGauss::new(&A, &b);
     /// assert_eq!(solver.solution_count(), 1);
@@ -229,37 +323,247 @@ impl<Word: Unsigned> BitGauss<Word> {
     /// ```
     #[must_use]
     pub fn xi(&self, i: usize) -> Option<BitVector<Word>> {
+        self.assert_vector_rhs();
         if !self.is_consistent() {
             return None;
         }
-        if i > self.solution_count() {
+        if i >= self.solution_count() {
             return None;
         }
 
-        // We start with a zero vector and then set the free variable slots to the fixed bit pattern for `i`.
-        let mut x = BitVector::zeros(self.b_ref.len());
+        // The free variables are set to the fixed bit pattern for `i`.
+        let mut coeffs = BitVector::zeros(self.free.len());
         let mut i = i;
-        for f in 0..self.free.len() {
-            x.set(self.free[f], i & 1 != 0);
+        for t in 0..self.free.len() {
+            if i == 0 {
+                break;
+            }
+            coeffs.set(t, i & 1 != 0);
             i >>= 1;
         }
+        Some(self.solution_for(0, &coeffs))
+    }
 
-        // Back substitution will now overwrite any non-free variables in `x` with their correct values.
-        self.back_substitute_into(&mut x);
-        Some(x)
+    /// Returns the *particular* solution to `A.x = b` where all the free variables are 0, or `None` if the system is
+    /// inconsistent.
+    ///
+    /// The full solution set is the affine space `x0 + span(kernel_basis)`, see [`BitGauss::kernel_basis`].
+    ///
+    /// # Panics
+    /// Panics if the solver was constructed with a bit-matrix right-hand side that has more than one column.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("111 111 111").unwrap();
+    /// let b: BitVector = BitVector::from_string("111").unwrap();
+    /// let solver: BitGauss = BitGauss::new(&A, &b);
+    /// assert_eq!(solver.x0().unwrap().to_string(), "100");
+    /// ```
+    #[must_use]
+    pub fn x0(&self) -> Option<BitVector<Word>> {
+        self.assert_vector_rhs();
+        if !self.is_consistent() {
+            return None;
+        }
+        Some(self.particular[0].clone())
     }
 
-    /// Helper function that performs back substitution to solve for the non-free variables in `x`.
-    fn back_substitute_into(&self, x: &mut BitVector<Word>) {
-        // Iterate from the bottom up, starting at the first non-zero row, solving for the non-free variables in `x`.
-        for i in (0..self.rank).rev() {
-            let j = self.A_ref[i].first_set().unwrap();
-            x.set(j, self.b_ref[i]);
-            for k in j + 1..x.len() {
-                if self.A_ref[i][k] {
-                    x.set(j, x[j] ^ x[k]);
-                }
+    /// Returns a basis for the kernel (null space) of the matrix `A` as the rows of a bit-matrix.
+    ///
+    /// There is one basis vector for each free variable. Any solution of the system is a particular solution plus
+    /// some combination of these basis vectors. If `A` has full column rank, the returned bit-matrix has no rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("111 111 111").unwrap();
+    /// let b: BitVector = BitVector::from_string("111").unwrap();
+    /// let solver: BitGauss = BitGauss::new(&A, &b);
+    /// assert_eq!(solver.kernel_basis().to_compact_binary_string(), "110 101");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn kernel_basis(&self) -> &BitMatrix<Word> { &self.kernel }
+
+    /// Returns an iterator over *all* the solutions to the system `A.x = b`.
+    ///
+    /// The solutions are visited in Gray-code order, so each one differs from its predecessor by a single kernel basis
+    /// vector and each step costs just one bit-vector `XOR`. Unlike [`BitGauss::xi`], the iterator is not limited to
+    /// the number of solutions that fit in a `usize`. It yields nothing if the system is inconsistent.
+    ///
+    /// # Panics
+    /// Panics if the solver was constructed with a bit-matrix right-hand side that has more than one column.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("111 111 111").unwrap();
+    /// let b: BitVector = BitVector::from_string("111").unwrap();
+    /// let solver: BitGauss = BitGauss::new(&A, &b);
+    /// let solutions: Vec<String> = solver.solutions().map(|x| x.to_string()).collect();
+    /// assert_eq!(solutions, vec!["100", "010", "111", "001"]);
+    /// ```
+    #[must_use]
+    pub fn solutions(&self) -> Solutions<'_, Word> { Solutions::new(self) }
+}
+
+/// Methods that return solutions for the system `A.X = B`.
+impl<Word: Unsigned> BitGauss<Word> {
+    /// Returns a solution to the system of linear equations `A.X = B` or `None` if the system is inconsistent.
+    ///
+    /// Each column of the returned bit-matrix is drawn uniformly at random from the solutions for the corresponding
+    /// column of `B`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::ones(3, 3);
+    /// let B: BitMatrix = BitMatrix::ones(3, 4);
+    /// let solver: BitGauss = BitGauss::new_for_matrix(&A, &B);
+    /// assert_eq!(&A * &solver.X().unwrap(), B);
+    /// ```
+    #[must_use]
+    pub fn X(&self) -> Option<BitMatrix<Word>> { self.X_seeded(0) }
+
+    /// Returns a solution to the system of linear equations `A.X = B` or `None` if the system is inconsistent.
+    ///
+    /// Each column of the returned bit-matrix is drawn uniformly at random from the solutions for the corresponding
+    /// column of `B`. For reproducibility, the random number generator is seeded with `seed` and then reset to its
+    /// previous state. A seed of `0` means we use the random number generator as is.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::random(20, 30);
+    /// let B: BitMatrix = &A * &BitMatrix::random(30, 3);
+    /// let solver: BitGauss = BitGauss::new_for_matrix(&A, &B);
+    /// let X = solver.X_seeded(42).unwrap();
+    /// assert_eq!(&A * &X, B);
+    /// assert_eq!(solver.X_seeded(42).unwrap(), X);
+    /// ```
+    #[must_use]
+    pub fn X_seeded(&self, seed: u64) -> Option<BitMatrix<Word>> {
+        if !self.is_consistent() {
+            return None;
+        }
+
+        // The random coefficients for the kernel basis vectors -- one column per right-hand side.
+        let f = self.free.len();
+        let k = self.particular.rows();
+        let coeffs = if f > 0 { BitMatrix::random_seeded(f, k, seed) } else { BitMatrix::zeros(0, k) };
+
+        // Build the solutions as rows and then transpose.
+        let mut result = BitMatrix::zeros(k, self.kernel.cols());
+        for j in 0..k {
+            let c = if f > 0 { coeffs.col(j) } else { BitVector::new() };
+            result[j].copy_store(&self.solution_for(j, &c));
+        }
+        Some(result.transposed())
+    }
+
+    /// Returns the *particular* solution to `A.X = B` where all the free variables are 0, or `None` if the system is
+    /// inconsistent.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("111 111 111").unwrap();
+    /// let B: BitMatrix = BitMatrix::from_string("10 10 10").unwrap();
+    /// let solver: BitGauss = BitGauss::new_for_matrix(&A, &B);
+    /// assert_eq!(solver.X0().unwrap().to_compact_binary_string(), "10 00 00");
+    /// ```
+    #[must_use]
+    pub fn X0(&self) -> Option<BitMatrix<Word>> {
+        if !self.is_consistent() {
+            return None;
+        }
+        Some(self.particular.transposed())
+    }
+}
+
+/// Private helper methods.
+impl<Word: Unsigned> BitGauss<Word> {
+    /// Returns the solution for right-hand side `j` that is the particular solution plus the combination of kernel
+    /// basis vectors picked out by the bits in `coeffs`.
+    fn solution_for(&self, j: usize, coeffs: &BitVector<Word>) -> BitVector<Word> {
+        let mut x = self.particular[j].clone();
+        for t in coeffs.set_bits() {
+            x ^= &self.kernel[t];
+        }
+        x
+    }
+
+    /// Checks that the solver has a single right-hand side as needed by the methods that return bit-vectors.
+    fn assert_vector_rhs(&self) {
+        assert!(
+            self.particular.rows() == 1,
+            "This method needs a single right-hand side but the solver has {}",
+            self.particular.rows()
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------------------
+// The `Solutions` iterator.
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// An iterator over all the solutions of a consistent system `A.x = b` in Gray-code order.
+///
+/// This is returned by the [`BitGauss::solutions`] method.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let A: BitMatrix = BitMatrix::random(10, 16);
+/// let b: BitVector = &A * &BitVector::random(16);
+/// let solver: BitGauss = BitGauss::new(&A, &b);
+/// let mut count = 0;
+/// for x in solver.solutions() {
+///     assert_eq!(&A * &x, b);
+///     count += 1;
+/// }
+/// assert_eq!(count, solver.solution_count());
+/// ```
+pub struct Solutions<'a, Word: Unsigned> {
+    solver:  &'a BitGauss<Word>,
+    x:       Option<BitVector<Word>>,
+    counter: BitVector<Word>,
+}
+
+/// Construct a `Solutions` iterator.
+impl<'a, Word: Unsigned> Solutions<'a, Word> {
+    /// Creates a new `Solutions` iterator for the given `BitGauss` solver.
+    ///
+    /// # Panics
+    /// Panics if the solver was constructed with a bit-matrix right-hand side that has more than one column.
+    #[must_use]
+    pub fn new(solver: &'a BitGauss<Word>) -> Self {
+        let x = solver.x0();
+        let counter = BitVector::zeros(solver.free_count());
+        Self { solver, x, counter }
+    }
+}
+
+/// Implement the `Iterator` trait for `Solutions`.
+impl<Word: Unsigned> Iterator for Solutions<'_, Word> {
+    type Item = BitVector<Word>;
+
+    /// Returns the next solution or `None` if we have visited all of them.
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.x.take()?;
+
+        // Going from index `i` to `i + 1` the Gray code flips the bit at the position of the first unset bit of `i`.
+        // If there are no unset bits in the counter we have visited every solution.
+        if let Some(t) = self.counter.first_unset() {
+            if t > 0 {
+                self.counter.slice_mut(0..t).set_all(false);
             }
+            self.counter.set(t, true);
+            let mut next = current.clone();
+            next ^= &self.solver.kernel[t];
+            self.x = Some(next);
         }
+        Some(current)
     }
 }
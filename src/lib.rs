@@ -45,6 +45,14 @@ pub use iterators::{
     Words,
 };
 
+// `GrayCode`, `FixedWeight`, and `BoundedWeight` iterators that enumerate all the bit-vectors of a given length.
+pub mod enumeration;
+pub use enumeration::{
+    BoundedWeight,
+    FixedWeight,
+    GrayCode,
+};
+
 // `BitPolynomial` is a polynomial over GF(2) --- a _bit-polynomial_.
 pub mod polynomial;
 pub use polynomial::BitPolynomial;
@@ -53,6 +61,42 @@ pub use polynomial::BitPolynomial;
 pub mod matrix;
 pub use matrix::BitMatrix;
 
+// `BitMatrixView`, `BitMatrixViewMut`, `BitColumn`, and `BitColumnMut` are non-owning views into a bit-matrix.
+pub mod view;
+pub use view::{
+    BitColumn,
+    BitColumnMut,
+    BitMatrixView,
+    BitMatrixViewMut,
+};
+
+// `BitBasis` is an incrementally built basis for a subspace of bit-vectors --- an _XOR basis_.
+pub mod basis;
+pub use basis::BitBasis;
+
+// `BitSubspace` is a subspace of bit-vectors and `SubspaceElements` iterates over its elements or cosets.
+pub mod subspace;
+pub use subspace::{
+    BitSubspace,
+    SubspaceElements,
+};
+
+// `LinearCode` is a binary linear code described by a generator matrix and a parity-check matrix.
+pub mod code;
+pub use code::LinearCode;
+
+// `SyndromeDecoder` decodes a binary linear code by looking up a coset leader for the syndrome of a received word.
+pub mod decoder;
+pub use decoder::SyndromeDecoder;
+
+// `ReedMuller` is a Reed–Muller code with majority-logic and fast Hadamard decoders.
+pub mod reed_muller;
+pub use reed_muller::ReedMuller;
+
+// `WeightDistribution` counts the codewords of each weight and converts between a code and its dual.
+pub mod weights;
+pub use weights::WeightDistribution;
+
 // `BitGauss` is a Gaussian elimination solver for systems of linear equations over GF(2).
 pub mod gauss;
 pub use gauss::BitGauss;
@@ -61,6 +105,41 @@ pub use gauss::BitGauss;
 pub mod lu;
 pub use lu::BitLU;
 
+// `BitPermutation` is a permutation of indices that acts on bit-stores and on the rows or columns of bit-matrices.
+pub mod permutation;
+pub use permutation::BitPermutation;
+
+// `PrimaryComponent` is one piece of the primary decomposition of the space that a square bit-matrix acts on.
+pub mod primary;
+pub use primary::PrimaryComponent;
+
+// `QuadraticForm` is a quadratic form over GF(2) that can be classified and reduced to a canonical form.
+pub mod quadratic;
+pub use quadratic::QuadraticForm;
+
+// `SparseBitMatrix` is a bit-matrix that only stores the positions of its set elements --- a _sparse bit-matrix_.
+pub mod sparse;
+pub use sparse::SparseBitMatrix;
+
+// `StructuredGauss` reduces a large sparse linear system to a much smaller dense one that `BitGauss` can handle.
+pub mod structured;
+pub use structured::StructuredGauss;
+
 // `rng` is a helper module that needs to be visible but which exports nothing outside the crate.
 // It provides a simple shared PRNG that is used to fill bit-stores and bit-matrices with random values.
 mod rng;
+
+// `parallel` is a helper module that shares the heavier bit-matrix and bit-store algorithms across several threads.
+// The thread-count controls are only exported when the crate is built with the `parallel` feature.
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::{
+    num_threads,
+    parallel_threshold,
+    set_num_threads,
+    set_parallel_threshold,
+};
+
+// `primes` is a helper module with some simple number theory for `u128` values that exports nothing outside the crate.
+// It is used to compute the multiplicative orders of bit-polynomials and bit-matrices.
+mod primes;
@@ -4,9 +4,12 @@
 // Crate types.
 use crate::{
     BitMatrix,
+    BitPermutation,
     BitStore,
     BitVector,
     Unsigned,
+    matrix::Elimination,
+    parallel,
 };
 
 #[doc = include_str!("../docs/lu.md")]
@@ -57,6 +60,15 @@ impl<Word: Unsigned> BitLU<Word> {
 
         // Set things up
         let mut LU = A.clone();
+
+        // Large bit-matrices get shared across threads.
+        let threads = parallel::threads_for(A.rows() * Word::words_needed(A.cols()));
+        if threads > 1 {
+            let (has_pivot, swaps) = LU.eliminate_in_parallel(threads, Elimination::LU);
+            let rank = has_pivot.count_ones();
+            return Self { LU, swaps, rank };
+        }
+
         let mut swaps = vec![0; A.rows()];
         let mut rank = A.rows();
 
@@ -84,14 +96,12 @@ impl<Word: Unsigned> BitLU<Word> {
                 swaps[j] = p;
             }
 
-            // Clear out the column below the pivot (at this point LU(j,j) == 1)
-            let jp1 = j + 1;
-            for i in jp1..A.rows() {
+            // Clear out the column below the pivot (at this point LU(j,j) == 1) leaving the multipliers in place.
+            let mut row_j = LU[j].clone();
+            row_j.slice_mut(0..=j).set_all(false);
+            for i in j + 1..A.rows() {
                 if LU[i][j] {
-                    for k in jp1..A.cols() {
-                        let tmp = LU[i][k] ^ LU[j][k];
-                        LU.set(i, k, tmp);
-                    }
+                    LU[i] ^= &row_j;
                 }
             }
         }
@@ -168,6 +178,22 @@ impl<Word: Unsigned> BitLU<Word> {
     #[must_use]
     pub fn swaps(&self) -> &[usize] { &self.swaps }
 
+    /// Returns the row permutation `P` as a [`BitPermutation`].
+    ///
+    /// Row `i` of `P.A` is row `p[i]` of `A`, and `p.to_matrix()` is the same as [`BitLU::P`].
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::random(20, 20);
+    /// let lu: BitLU = BitLU::new(&A);
+    /// let p = lu.permutation();
+    /// assert_eq!(p.to_matrix(), lu.P());
+    /// assert_eq!(p.permute_rows(&A), &lu.L() * &lu.U());
+    /// ```
+    #[must_use]
+    pub fn permutation(&self) -> BitPermutation { BitPermutation::from_swaps(&self.swaps) }
+
     /// Returns the permutation matrix as a vector of showing the index positions of the non-zero entries.
     ///
     /// A permutation matrix is just some row permutation of the identity matrix, so it has a single non-zero, 1, entry
@@ -175,17 +201,22 @@ impl<Word: Unsigned> BitLU<Word> {
     ///
     /// In the literature, the permutation vector is often given as a permutation of the index vector. For example, the
     /// permutation vector `[0,2,1,4,3]` tells you that elements/rows 1 and 2 are swapped, as are elements/rows 3 and 4.
-    /// This form is easy to interpret at a glance and is returned by the `P_vector` method.
+    /// This form is easy to interpret at a glance.
+    ///
+    /// See the [`permutation`](BitLU::permutation) method for the same information as a [`BitPermutation`] and the
+    /// [`swaps`](BitLU::swaps) method for an alternative form that is more convenient for executing the permutations
+    /// in place.
     ///
-    /// See the [`swaps`](BitLU::swaps) method for an alternative form of the permutation matrix that is more
-    /// convenient for executing the permutations in place.
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("001 100 010").unwrap();
+    /// let lu: BitLU = BitLU::new(&A);
+    /// assert_eq!(lu.permutation_vector(), [1, 2, 0]);
+    /// ```
     #[inline]
     #[must_use]
-    pub fn permutation_vector(&self) -> Vec<usize> {
-        let mut P = (0..self.LU.rows()).collect::<Vec<_>>();
-        P.sort_by_key(|&i| self.swaps[i]);
-        P
-    }
+    pub fn permutation_vector(&self) -> Vec<usize> { self.permutation().to_vec() }
 
     /// Permutes the rows of a bit-matrix `B` in place using the stored row swap instructions.
     ///
@@ -300,27 +331,53 @@ impl<Word: Unsigned> BitLU<Word> {
         let mut X = B.clone();
         self.permute_matrix(&mut X);
 
-        // Solve for each column.
-        for c in 0..B.cols() {
-            // Forward substitution.
-            for i in 0..n {
-                for j in 0..i {
-                    if self.LU[i][j] {
-                        X.set(i, c, X[i][c] ^ X[j][c]);
-                    }
+        // The substitutions work on whole rows of `X` so all the columns get solved at once. The columns are
+        // independent of each other so a large `X` gets split into blocks of whole words, and each thread solves its
+        // own block in place.
+        let words = Word::words_needed(B.cols());
+        let threads = parallel::threads_for(n * n * words / 2).min(words).max(1);
+        let width = words.div_ceil(threads).max(1);
+        let mut blocks: Vec<Vec<&mut [Word]>> = (0..words.div_ceil(width)).map(|_| Vec::with_capacity(n)).collect();
+        for row in X.row_iter_mut() {
+            for (block, row_words) in blocks.iter_mut().zip(row.store_mut().chunks_mut(width)) {
+                block.push(row_words);
+            }
+        }
+        parallel::for_each_chunk_mut(&mut blocks, threads, |_, chunk| {
+            for block in chunk {
+                self.substitute(block);
+            }
+        });
+
+        // Return the solution.
+        Some(X)
+    }
+
+    /// Runs the forward and backward substitutions for `L.U.X = B` on the rows of an already permuted copy of `B`.
+    ///
+    /// Each row is given as the same range of words from the corresponding row of `X`, so the rows can be a block of
+    /// columns from the full solution.
+    fn substitute(&self, X: &mut [&mut [Word]]) {
+        let n = self.LU.rows();
+
+        // Forward substitution.
+        for i in 0..n {
+            let (above, rest) = X.split_at_mut(i);
+            for j in self.LU[i].set_bits().take_while(|&j| j < i) {
+                for (x, y) in rest[0].iter_mut().zip(above[j].iter()) {
+                    *x ^= *y;
                 }
             }
-            // Backward substitution.
-            for i in (0..n).rev() {
-                for j in i + 1..n {
-                    if self.LU[i][j] {
-                        X.set(i, c, X[i][c] ^ X[j][c]);
-                    }
+        }
+        // Backward substitution.
+        for i in (0..n).rev() {
+            let (upto, below) = X.split_at_mut(i + 1);
+            for j in self.LU[i].set_bits().skip_while(|&j| j <= i) {
+                for (x, y) in upto[i].iter_mut().zip(below[j - i - 1].iter()) {
+                    *x ^= *y;
                 }
             }
         }
-        // Return the solution.
-        Some(X)
     }
 
     /// Returns the inverse of the matrix `A` as a full independent bit-matrix. Returns `None` if the matrix is
@@ -2,13 +2,20 @@
 
 // Crate imports.
 use crate::{
+    BitColumn,
+    BitColumnMut,
     BitGauss,
     BitLU,
+    BitMatrixView,
+    BitMatrixViewMut,
     BitPolynomial,
     BitSlice,
     BitStore,
     BitVector,
+    PrimaryComponent,
     Unsigned,
+    parallel,
+    primes,
     rng,
 };
 
@@ -19,6 +26,7 @@ use crate::array::BitArray;
 // Standard library imports.
 use core::f64;
 use std::{
+    collections::BTreeMap,
     fmt,
     fmt::Write,
     ops::{
@@ -40,6 +48,13 @@ use std::{
         Sub,
         SubAssign,
     },
+    sync::{
+        RwLock,
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+    },
 };
 
 #[doc = include_str!("../docs/matrix.md")]
@@ -304,6 +319,351 @@ impl<Word: Unsigned> BitMatrix<Word> {
     pub fn random_biased(r: usize, c: usize, p: f64) -> Self { Self::random_biased_seeded(r, c, p, 0) }
 }
 
+/// Constructors for random bit-matrices with some prescribed structure.
+///
+/// # Note
+/// Each of these has a `_seeded` version that seeds the RNG for reproducible results. A seed of `0` indicates we
+/// should randomly seed the RNG.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns a uniformly random invertible `n x n` bit-matrix, i.e., a uniform sample from the group `GL(n,2)`, where
+    /// the RNG is seeded to `seed`.
+    ///
+    /// The rows are built one at a time, each uniformly random among the bit-vectors outside the span of the rows
+    /// so far. There is no rejection of whole bit-matrices as there is when you draw fair random bit-matrices until
+    /// one is invertible, which only succeeds with [`BitMatrix::probability_invertible`] each time.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m1: BitMatrix = BitMatrix::random_invertible_seeded(50, 42);
+    /// let m2: BitMatrix = BitMatrix::random_invertible_seeded(50, 42);
+    /// assert_eq!(m1, m2);
+    /// assert!(m1.inverse().is_some());
+    /// ```
+    #[must_use]
+    pub fn random_invertible_seeded(n: usize, seed: u64) -> Self {
+        // If given a non-zero seed we need to save and restore the old seed.
+        let old_seed = rng::seed();
+        if seed != 0 {
+            rng::set_seed(seed);
+        }
+
+        let result = Self::random_independent_rows(n, n);
+
+        // Restore the old RNG seed.
+        if seed != 0 {
+            rng::set_seed(old_seed);
+        }
+        result
+    }
+
+    /// Returns a uniformly random invertible `n x n` bit-matrix, i.e., a uniform sample from the group `GL(n,2)`.
+    ///
+    /// See the `random_invertible_seeded` method for a way to get reproducible results.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_invertible(50);
+    /// assert_eq!(m.rank(), 50);
+    /// ```
+    #[must_use]
+    pub fn random_invertible(n: usize) -> Self { Self::random_invertible_seeded(n, 0) }
+
+    /// Returns a uniformly random `r x c` bit-matrix of rank `k`, where the RNG is seeded to `seed`.
+    ///
+    /// The bit-matrix is the product `X.Y` of a random `r x k` bit-matrix `X` of full column rank and a random `k x c`
+    /// bit-matrix `Y` of full row rank. Every rank `k` bit-matrix has the same number of such factorisations, so the
+    /// result is uniform over all the bit-matrices of rank `k`.
+    ///
+    /// # Panics
+    /// Panics if `k` is larger than `r` or `c`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m1: BitMatrix = BitMatrix::random_with_rank_seeded(30, 40, 12, 42);
+    /// let m2: BitMatrix = BitMatrix::random_with_rank_seeded(30, 40, 12, 42);
+    /// assert_eq!(m1, m2);
+    /// assert_eq!(m1.rank(), 12);
+    /// ```
+    #[must_use]
+    pub fn random_with_rank_seeded(r: usize, c: usize, k: usize, seed: u64) -> Self {
+        assert!(k <= r && k <= c, "A {r} x {c} bit-matrix cannot have rank {k}");
+        if k == 0 {
+            return Self::zeros(r, c);
+        }
+
+        // If given a non-zero seed we need to save and restore the old seed.
+        let old_seed = rng::seed();
+        if seed != 0 {
+            rng::set_seed(seed);
+        }
+
+        let x = Self::random_independent_rows(k, r).transposed();
+        let y = Self::random_independent_rows(k, c);
+        let result = &x * &y;
+
+        // Restore the old RNG seed.
+        if seed != 0 {
+            rng::set_seed(old_seed);
+        }
+        result
+    }
+
+    /// Returns a uniformly random `r x c` bit-matrix of rank `k`.
+    ///
+    /// See the `random_with_rank_seeded` method for a way to get reproducible results.
+    ///
+    /// # Panics
+    /// Panics if `k` is larger than `r` or `c`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_with_rank(30, 40, 12);
+    /// assert_eq!(m.rank(), 12);
+    /// ```
+    #[must_use]
+    pub fn random_with_rank(r: usize, c: usize, k: usize) -> Self { Self::random_with_rank_seeded(r, c, k, 0) }
+
+    /// Returns a uniformly random symmetric `n x n` bit-matrix, where the RNG is seeded to `seed`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_symmetric_seeded(20, 42);
+    /// assert_eq!(m, BitMatrix::random_symmetric_seeded(20, 42));
+    /// assert!(m.is_symmetric());
+    /// ```
+    #[must_use]
+    pub fn random_symmetric_seeded(n: usize, seed: u64) -> Self { Self::random_triangle_seeded(n, true, true, seed) }
+
+    /// Returns a uniformly random symmetric `n x n` bit-matrix.
+    ///
+    /// See the `random_symmetric_seeded` method for a way to get reproducible results.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_symmetric(20);
+    /// assert!(m.is_symmetric());
+    /// ```
+    #[must_use]
+    pub fn random_symmetric(n: usize) -> Self { Self::random_symmetric_seeded(n, 0) }
+
+    /// Returns a uniformly random alternating `n x n` bit-matrix, where the RNG is seeded to `seed`.
+    ///
+    /// An alternating bit-matrix is symmetric with a zero diagonal. These are the matrices of alternating bilinear
+    /// forms, i.e., forms with `x.A.x = 0` for all `x`. Not to be confused with the checker-board pattern from
+    /// [`BitMatrix::alternating`].
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_alternating_seeded(20, 42);
+    /// assert_eq!(m, BitMatrix::random_alternating_seeded(20, 42));
+    /// assert!(m.is_symmetric());
+    /// assert_eq!(m.count_ones_on_diagonal(), 0);
+    /// ```
+    #[must_use]
+    pub fn random_alternating_seeded(n: usize, seed: u64) -> Self { Self::random_triangle_seeded(n, true, false, seed) }
+
+    /// Returns a uniformly random alternating `n x n` bit-matrix (symmetric with a zero diagonal).
+    ///
+    /// See the `random_alternating_seeded` method for a way to get reproducible results.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_alternating(20);
+    /// assert!(m.is_symmetric());
+    /// assert_eq!(m.count_ones_on_diagonal(), 0);
+    /// ```
+    #[must_use]
+    pub fn random_alternating(n: usize) -> Self { Self::random_alternating_seeded(n, 0) }
+
+    /// Returns a uniformly random upper unitriangular `n x n` bit-matrix, where the RNG is seeded to `seed`.
+    ///
+    /// The diagonal is all ones, the elements below the diagonal are zero, and the elements above it are random.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_unit_upper_seeded(20, 42);
+    /// assert_eq!(m, BitMatrix::random_unit_upper_seeded(20, 42));
+    /// assert_eq!(m.unit_upper(), m);
+    /// ```
+    #[must_use]
+    pub fn random_unit_upper_seeded(n: usize, seed: u64) -> Self {
+        let mut result = Self::random_triangle_seeded(n, false, false, seed);
+        result.set_diagonal(true);
+        result
+    }
+
+    /// Returns a uniformly random upper unitriangular `n x n` bit-matrix.
+    ///
+    /// See the `random_unit_upper_seeded` method for a way to get reproducible results.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_unit_upper(20);
+    /// assert_eq!(m.unit_upper(), m);
+    /// ```
+    #[must_use]
+    pub fn random_unit_upper(n: usize) -> Self { Self::random_unit_upper_seeded(n, 0) }
+
+    /// Returns a uniformly random lower unitriangular `n x n` bit-matrix, where the RNG is seeded to `seed`.
+    ///
+    /// The diagonal is all ones, the elements above the diagonal are zero, and the elements below it are random.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_unit_lower_seeded(20, 42);
+    /// assert_eq!(m, BitMatrix::random_unit_lower_seeded(20, 42));
+    /// assert_eq!(m.unit_lower(), m);
+    /// ```
+    #[must_use]
+    pub fn random_unit_lower_seeded(n: usize, seed: u64) -> Self {
+        Self::random_unit_upper_seeded(n, seed).transposed()
+    }
+
+    /// Returns a uniformly random lower unitriangular `n x n` bit-matrix.
+    ///
+    /// See the `random_unit_lower_seeded` method for a way to get reproducible results.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random_unit_lower(20);
+    /// assert_eq!(m.unit_lower(), m);
+    /// ```
+    #[must_use]
+    pub fn random_unit_lower(n: usize) -> Self { Self::random_unit_lower_seeded(n, 0) }
+
+    /// Returns a random square bit-matrix with characteristic polynomial `p`, where the RNG is seeded to `seed`.
+    ///
+    /// The result is `S.C.S^-1` where `C` is the companion matrix of `p` and `S` is a uniformly random invertible
+    /// bit-matrix. It is therefore uniform over the similarity class of `C`, which is the class of bit-matrices with
+    /// characteristic polynomial `p` whose minimal polynomial is also `p`. If `p` is square-free, that is every
+    /// bit-matrix with characteristic polynomial `p`.
+    ///
+    /// # Panics
+    /// Panics if `p` is the zero polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p: BitPolynomial = BitPolynomial::from_coefficients(BitVector::from_string("110100001").unwrap());
+    /// assert_eq!(p.to_string(), "1 + x + x^3 + x^8");
+    /// let m: BitMatrix = BitMatrix::random_with_characteristic_polynomial_seeded(&p, 42);
+    /// assert_eq!(m, BitMatrix::random_with_characteristic_polynomial_seeded(&p, 42));
+    /// assert_eq!(m.characteristic_polynomial(), p);
+    /// ```
+    #[must_use]
+    pub fn random_with_characteristic_polynomial_seeded(p: &BitPolynomial<Word>, seed: u64) -> Self {
+        assert!(!p.is_zero(), "The zero polynomial is not the characteristic polynomial of any bit-matrix");
+        let n = p.degree();
+        if n == 0 {
+            return Self::new();
+        }
+
+        // The top row of the companion matrix holds the lower order coefficients of `p` in reverse order.
+        let top_row = BitVector::from_fn(n, |j| p.coeff(n - 1 - j));
+        let companion = Self::companion(&top_row);
+
+        let s = Self::random_invertible_seeded(n, seed);
+        let s_inv = s.inverse().expect("A random invertible bit-matrix should be invertible");
+        &(&s * &companion) * &s_inv
+    }
+
+    /// Returns a random square bit-matrix with characteristic polynomial `p`.
+    ///
+    /// See the `random_with_characteristic_polynomial_seeded` method for a way to get reproducible results.
+    ///
+    /// # Panics
+    /// Panics if `p` is the zero polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p: BitPolynomial = BitPolynomial::random(10);
+    /// let m: BitMatrix = BitMatrix::random_with_characteristic_polynomial(&p);
+    /// assert_eq!(m.characteristic_polynomial(), p);
+    /// ```
+    #[must_use]
+    pub fn random_with_characteristic_polynomial(p: &BitPolynomial<Word>) -> Self {
+        Self::random_with_characteristic_polynomial_seeded(p, 0)
+    }
+
+    /// Returns `k` uniformly random linearly independent rows of length `n` using the current state of the RNG.
+    ///
+    /// Row `i` is `s + t` where `t` is a uniformly random element of the span of the earlier rows and `s` is a
+    /// uniformly random non-zero bit-vector that vanishes on the leading columns of the earlier `s` vectors. Those
+    /// bit-vectors span a complement of the earlier rows, so `s + t` is uniform over the bit-vectors outside that
+    /// span.
+    ///
+    /// There are no redraws. The first (up to) 64 free columns of `s` are the bits of a random index in `1..2^f`, read
+    /// from the most significant bit down, and any other free columns are filled in at random.
+    fn random_independent_rows(k: usize, n: usize) -> Self {
+        assert!(k <= n, "Cannot have {k} linearly independent rows of length {n}");
+        let mut result = Self::zeros(k, n);
+        let mut is_lead = vec![false; n];
+        for i in 0..k {
+            let free: Vec<usize> = (0..n).filter(|&j| !is_lead[j]).collect();
+            let f = free.len().min(64);
+
+            // Map a random word onto `1..2^f` with a multiply and shift so the result is never zero.
+            let range = if f == 64 { u64::MAX } else { (1 << f) - 1 };
+            let index = 1 + ((u128::from(rng::u64()) * u128::from(range)) >> 64) as u64;
+            let mut s = BitVector::zeros(n);
+            for (q, &j) in free.iter().enumerate() {
+                let bit = if q < f { (index >> (f - 1 - q)) & 1 == 1 } else { rng::bool() };
+                s.set(j, bit);
+            }
+            is_lead[s.first_set().unwrap()] = true;
+            for r in 0..i {
+                if rng::bool() {
+                    s ^= &result.m_rows[r];
+                }
+            }
+            result.m_rows[i] = s;
+        }
+        result
+    }
+
+    /// Returns a random `n x n` bit-matrix that is zero below the diagonal, optionally with a random diagonal and
+    /// optionally reflected to make it symmetric.
+    fn random_triangle_seeded(n: usize, symmetric: bool, diagonal: bool, seed: u64) -> Self {
+        // If given a non-zero seed we need to save and restore the old seed.
+        let old_seed = rng::seed();
+        if seed != 0 {
+            rng::set_seed(seed);
+        }
+
+        let mut result = Self::zeros(n, n);
+        for i in 0..n {
+            let start = if diagonal { i } else { i + 1 };
+            for j in start..n {
+                if rng::bool() {
+                    result.set(i, j, true);
+                    if symmetric {
+                        result.set(j, i, true);
+                    }
+                }
+            }
+        }
+
+        // Restore the old RNG seed.
+        if seed != 0 {
+            rng::set_seed(old_seed);
+        }
+        result
+    }
+}
+
 /// Constructors for some "special" square bit-matrices.
 impl<Word: Unsigned> BitMatrix<Word> {
     /// Constructs the n x n zero matrix.
@@ -437,6 +797,30 @@ impl<Word: Unsigned> BitMatrix<Word> {
         result.set_sub_diagonal(1, true);
         result
     }
+
+    /// Returns the block diagonal *Frobenius matrix* with companion matrices along the diagonal.
+    ///
+    /// Each companion matrix is passed in compact form as its top row (see [`BitMatrix::companion`]).
+    /// This is the expanded form of the compact result from [`BitMatrix::frobenius_form`].
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let top_rows: Vec<BitVector> = vec![BitVector::from_string("1").unwrap(), BitVector::from_string("01").unwrap()];
+    /// let m: BitMatrix = BitMatrix::frobenius(&top_rows);
+    /// assert_eq!(m.to_compact_binary_string(), "100 001 010");
+    /// ```
+    #[must_use]
+    pub fn frobenius(top_rows: &[BitVector<Word>]) -> Self {
+        let n = top_rows.iter().map(BitStore::len).sum();
+        let mut result = Self::zero(n);
+        let mut k = 0;
+        for top_row in top_rows {
+            result.replace_sub_matrix(k, k, &Self::companion(top_row));
+            k += top_row.len();
+        }
+        result
+    }
 }
 
 /// Bit-matrix constructors that can fail.
@@ -756,6 +1140,21 @@ impl<Word: Unsigned> BitMatrix<Word> {
         }
         true
     }
+
+    /// Returns `true` if the square bit-matrix is *alternating*, i.e., symmetric with a zero diagonal.
+    ///
+    /// These are the matrices of alternating bilinear forms (forms with `x.A.x = 0` for all `x`). Not to be confused
+    /// with the checker-board pattern from [`BitMatrix::alternating`].
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("011 101 110").unwrap();
+    /// assert_eq!(m.is_alternating(), true);
+    /// assert_eq!(BitMatrix::<usize>::identity(3).is_alternating(), false);
+    /// ```
+    #[must_use]
+    pub fn is_alternating(&self) -> bool { self.is_symmetric() && self.count_ones_on_diagonal() == 0 }
 }
 
 /// Set and unset bit counts for a bit-matrix.
@@ -987,6 +1386,7 @@ impl<Word: Unsigned> BitMatrix<Word> {
     /// # Note
     /// - Matrices are stored by rows and there is no cheap slice style access to the matrix columns.
     /// - In contrast, the `Index` trait implementation `matrix[r]` provides a cheap way to access a row as a reference.
+    /// - Use [`BitMatrix::col_view`] for a strided view of the column that does not copy it.
     ///
     /// # Panics
     /// In debug mode, panics if `c` is out of bounds.
@@ -1606,37 +2006,7 @@ impl<Word: Unsigned> BitMatrix<Word> {
     /// ```
     #[must_use]
     pub fn sub_matrix<R: RangeBounds<usize>>(&self, rows: R, cols: R) -> Self {
-        // Get the start and end of the row range.
-        let r_start = match rows.start_bound() {
-            Bound::Included(start) => *start,
-            Bound::Excluded(start) => *start + 1,
-            Bound::Unbounded => 0,
-        };
-        let r_end = match rows.end_bound() {
-            Bound::Included(end) => *end + 1,
-            Bound::Excluded(end) => *end,
-            Bound::Unbounded => self.rows(),
-        };
-
-        // Check that the row range is valid.
-        assert!(r_start <= r_end, "Invalid row range");
-        assert!(r_end <= self.rows(), "Row range extends beyond the end of the bit-matrix");
-
-        // Get the start and end of the column range.
-        let c_start = match cols.start_bound() {
-            Bound::Included(start) => *start,
-            Bound::Excluded(start) => *start + 1,
-            Bound::Unbounded => 0,
-        };
-        let c_end = match cols.end_bound() {
-            Bound::Included(end) => *end + 1,
-            Bound::Excluded(end) => *end,
-            Bound::Unbounded => self.cols(),
-        };
-
-        // Check that the column range is valid.
-        assert!(c_start <= c_end, "Invalid column range");
-        assert!(c_end <= self.cols(), "Column range extends beyond the right edge of the bit-matrix");
+        let (r_start, r_end, c_start, c_end) = self.window_for(rows, cols);
 
         // Get the number of rows and columns in the sub-matrix.
         let r = r_end - r_start;
@@ -1676,22 +2046,355 @@ impl<Word: Unsigned> BitMatrix<Word> {
     }
 }
 
-/// Triangular sub-matrix methods.
+/// Methods to borrow non-owning views of a window, a row, or a column of a bit-matrix.
 impl<Word: Unsigned> BitMatrix<Word> {
-    /// Returns an independent *clone* of the lower triangular part of the bit-matrix.
+    /// Returns a read-only view of the window given by the row and column ranges without copying any elements.
+    ///
+    /// # Panics
+    /// Panics if the ranges are not valid for the bit-matrix, or if the window has rows but no columns (the rows of a
+    /// view are bit-slices which cannot be empty).
     ///
     /// # Examples
     /// ```
     /// use gf2::*;
-    /// let m: BitMatrix = BitMatrix::ones(3, 3);
-    /// let sub_m = m.lower();
-    /// assert_eq!(sub_m.to_compact_binary_string(), "100 110 111");
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// let view = m.view(1..4, 1..4);
+    /// assert_eq!(view.to_compact_binary_string(), "100 010 001");
+    /// assert_eq!(view.count_ones(), 3);
     /// ```
     #[must_use]
-    pub fn lower(&self) -> Self {
-        // Edge case:
-        if self.is_empty() {
-            return BitMatrix::new();
+    pub fn view<R: RangeBounds<usize>>(&self, rows: R, cols: R) -> BitMatrixView<'_, Word> {
+        let (r_start, r_end, c_start, c_end) = self.window_for(rows, cols);
+        BitMatrixView::new(&self.m_rows[r_start..r_end], c_start, c_end - c_start)
+    }
+
+    /// Returns a mutable view of the window given by the row and column ranges without copying any elements.
+    ///
+    /// # Panics
+    /// Panics if the ranges are not valid for the bit-matrix, or if the window has rows but no columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::zeros(4, 4);
+    /// m.view_mut(1..3, 1..3).copy_from(&BitMatrix::ones(2, 2));
+    /// assert_eq!(m.to_compact_binary_string(), "0000 0110 0110 0000");
+    /// ```
+    #[must_use]
+    pub fn view_mut<R: RangeBounds<usize>>(&mut self, rows: R, cols: R) -> BitMatrixViewMut<'_, Word> {
+        let (r_start, r_end, c_start, c_end) = self.window_for(rows, cols);
+        BitMatrixViewMut::new(&mut self.m_rows[r_start..r_end], c_start, c_end - c_start)
+    }
+
+    /// Returns a read-only view of column `c` without copying the column.
+    ///
+    /// # Panics
+    /// Panics if `c` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("110 011 111").unwrap();
+    /// let col = m.col_view(0);
+    /// assert_eq!(col.to_string(), "101");
+    /// assert_eq!(col.count_ones(), 2);
+    /// assert!(col.dot(&m.col(2)));
+    /// ```
+    #[must_use]
+    pub fn col_view(&self, c: usize) -> BitColumn<'_, Word> {
+        assert!(c < self.cols(), "Column {c} is not in bounds [0, {})", self.cols());
+        BitColumn::new(&self.m_rows, c)
+    }
+
+    /// Returns a mutable view of column `c` that is a full bit-store and is written back to the bit-matrix on drop.
+    ///
+    /// # Panics
+    /// Panics if `c` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::zeros(3, 3);
+    /// m.col_view_mut(1).copy_store(&BitVector::<usize>::from_string("101").unwrap());
+    /// assert_eq!(m.to_compact_binary_string(), "010 000 010");
+    /// ```
+    #[must_use]
+    pub fn col_view_mut(&mut self, c: usize) -> BitColumnMut<'_, Word> {
+        assert!(c < self.cols(), "Column {c} is not in bounds [0, {})", self.cols());
+        BitColumnMut::new(&mut self.m_rows, c)
+    }
+
+    /// Returns an iterator over the rows of the bit-matrix as references to bit-vectors.
+    ///
+    /// # Note
+    /// The name `rows` is already taken by the method that returns the number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// let weights: Vec<usize> = m.row_iter().map(|row| row.count_ones()).collect();
+    /// assert_eq!(weights, [1, 1, 1]);
+    /// ```
+    pub fn row_iter(&self) -> std::slice::Iter<'_, BitVector<Word>> { self.m_rows.iter() }
+
+    /// Returns an iterator over the rows of the bit-matrix as mutable references to bit-vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::identity(3);
+    /// for row in m.row_iter_mut() {
+    ///     row.set(0, true);
+    /// }
+    /// assert_eq!(m.to_compact_binary_string(), "100 110 101");
+    /// ```
+    pub fn row_iter_mut(&mut self) -> std::slice::IterMut<'_, BitVector<Word>> { self.m_rows.iter_mut() }
+
+    /// Returns an iterator over the columns of the bit-matrix as [`BitColumn`] views.
+    ///
+    /// # Note
+    /// The name `cols` is already taken by the method that returns the number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("110 011").unwrap();
+    /// let weights: Vec<usize> = m.col_iter().map(|col| col.count_ones()).collect();
+    /// assert_eq!(weights, [1, 2, 1]);
+    /// ```
+    pub fn col_iter(&self) -> impl ExactSizeIterator<Item = BitColumn<'_, Word>> {
+        (0..self.cols()).map(move |c| self.col_view(c))
+    }
+
+    /// Resolves a pair of row and column ranges to `(r_start, r_end, c_start, c_end)` and checks they are valid.
+    fn window_for<R: RangeBounds<usize>>(&self, rows: R, cols: R) -> (usize, usize, usize, usize) {
+        // Get the start and end of the row range.
+        let r_start = match rows.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => *start + 1,
+            Bound::Unbounded => 0,
+        };
+        let r_end = match rows.end_bound() {
+            Bound::Included(end) => *end + 1,
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => self.rows(),
+        };
+
+        // Check that the row range is valid.
+        assert!(r_start <= r_end, "Invalid row range");
+        assert!(r_end <= self.rows(), "Row range extends beyond the end of the bit-matrix");
+
+        // Get the start and end of the column range.
+        let c_start = match cols.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => *start + 1,
+            Bound::Unbounded => 0,
+        };
+        let c_end = match cols.end_bound() {
+            Bound::Included(end) => *end + 1,
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => self.cols(),
+        };
+
+        // Check that the column range is valid.
+        assert!(c_start <= c_end, "Invalid column range");
+        assert!(c_end <= self.cols(), "Column range extends beyond the right edge of the bit-matrix");
+        assert!(c_start < c_end || r_start == r_end, "A window with rows must have at least one column");
+        (r_start, r_end, c_start, c_end)
+    }
+}
+
+/// Methods to build bit-matrices out of blocks and to split bit-matrices into blocks.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns a new bit-matrix made by placing the passed bit-matrices side by side from left to right.
+    ///
+    /// Each element is copied just once, unlike a chain of calls to [`BitMatrix::append_cols`].
+    ///
+    /// # Panics
+    /// Panics if the bit-matrices do not all have the same number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let a: BitMatrix = BitMatrix::identity(2);
+    /// let b: BitMatrix = BitMatrix::ones(2, 1);
+    /// let m = BitMatrix::hstack(&[&a, &b, &a]);
+    /// assert_eq!(m.to_compact_binary_string(), "10110 01101");
+    /// ```
+    #[must_use]
+    pub fn hstack(blocks: &[&BitMatrix<Word>]) -> Self {
+        let Some(first) = blocks.first()
+        else {
+            return Self::new();
+        };
+        let r = first.rows();
+        assert!(blocks.iter().all(|block| block.rows() == r), "All the blocks must have the same number of rows");
+        let c = blocks.iter().map(|block| block.cols()).sum();
+        let mut result = Self::zeros(r, c);
+        let mut left = 0;
+        for block in blocks {
+            result.replace_sub_matrix(0, left, block);
+            left += block.cols();
+        }
+        result
+    }
+
+    /// Returns a new bit-matrix made by placing the passed bit-matrices one above the other from top to bottom.
+    ///
+    /// Bit-matrices with no rows are skipped.
+    ///
+    /// # Panics
+    /// Panics if the non-empty bit-matrices do not all have the same number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let a: BitMatrix = BitMatrix::identity(2);
+    /// let b: BitMatrix = BitMatrix::ones(1, 2);
+    /// let m = BitMatrix::vstack(&[&a, &b, &a]);
+    /// assert_eq!(m.to_compact_binary_string(), "10 01 11 10 01");
+    /// ```
+    #[must_use]
+    pub fn vstack(blocks: &[&BitMatrix<Word>]) -> Self {
+        let mut non_empty = blocks.iter().filter(|block| !block.is_empty()).peekable();
+        let Some(first) = non_empty.peek()
+        else {
+            return Self::new();
+        };
+        let c = first.cols();
+        let mut m_rows = Vec::with_capacity(blocks.iter().map(|block| block.rows()).sum());
+        for block in non_empty {
+            assert_eq!(block.cols(), c, "All the blocks must have the same number of columns");
+            m_rows.extend_from_slice(&block.m_rows);
+        }
+        Self { m_rows }
+    }
+
+    /// Returns a new bit-matrix assembled from a grid of blocks where `blocks[i][j]` is the block in block-row `i` and
+    /// block-column `j`.
+    ///
+    /// The blocks in a block-row must all have the same number of rows and the block-rows must all end up with the
+    /// same number of columns.
+    ///
+    /// # Panics
+    /// Panics if the blocks do not fit together.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let i: BitMatrix = BitMatrix::identity(2);
+    /// let z: BitMatrix = BitMatrix::zeros(2, 2);
+    /// let m = BitMatrix::from_blocks(&[&[&i, &i], &[&z, &i]]);
+    /// assert_eq!(m.to_compact_binary_string(), "1010 0101 0010 0001");
+    /// ```
+    #[must_use]
+    pub fn from_blocks(blocks: &[&[&BitMatrix<Word>]]) -> Self {
+        let block_rows: Vec<Self> = blocks.iter().map(|block_row| Self::hstack(block_row)).collect();
+        Self::vstack(&block_rows.iter().collect::<Vec<_>>())
+    }
+
+    /// Splits the bit-matrix into a grid of blocks with the given block-row heights and block-column widths.
+    ///
+    /// The result is the inverse of [`BitMatrix::from_blocks`]: element `[i][j]` is the block in block-row `i` and
+    /// block-column `j`.
+    ///
+    /// # Panics
+    /// Panics if the heights do not add up to the number of rows or the widths do not add up to the number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("101 011 111").unwrap();
+    /// let blocks = m.to_blocks(&[2, 1], &[1, 2]);
+    /// assert_eq!(blocks[0][0].to_compact_binary_string(), "1 0");
+    /// assert_eq!(blocks[0][1].to_compact_binary_string(), "01 11");
+    /// assert_eq!(blocks[1][1].to_compact_binary_string(), "11");
+    /// let refs: Vec<Vec<&BitMatrix>> = blocks.iter().map(|row| row.iter().collect()).collect();
+    /// let rows: Vec<&[&BitMatrix]> = refs.iter().map(Vec::as_slice).collect();
+    /// assert_eq!(BitMatrix::from_blocks(&rows), m);
+    /// ```
+    #[must_use]
+    pub fn to_blocks(&self, heights: &[usize], widths: &[usize]) -> Vec<Vec<Self>> {
+        assert_eq!(heights.iter().sum::<usize>(), self.rows(), "Block heights must add up to the number of rows");
+        assert_eq!(widths.iter().sum::<usize>(), self.cols(), "Block widths must add up to the number of columns");
+        let mut result = Vec::with_capacity(heights.len());
+        let mut top = 0;
+        for &h in heights {
+            let mut block_row = Vec::with_capacity(widths.len());
+            let mut left = 0;
+            for &w in widths {
+                block_row.push(self.sub_matrix(top..top + h, left..left + w));
+                left += w;
+            }
+            result.push(block_row);
+            top += h;
+        }
+        result
+    }
+
+    /// Returns the Kronecker product of this `r x c` bit-matrix with another `p x q` bit-matrix.
+    ///
+    /// The result is the `rp x cq` block matrix whose block `(i, j)` is `self[i][j] * other`.
+    /// Reed-Muller, polar and product codes are all built from Kronecker products.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let a: BitMatrix = BitMatrix::from_string("11 01").unwrap();
+    /// let b: BitMatrix = BitMatrix::identity(2);
+    /// assert_eq!(a.kronecker(&b).to_compact_binary_string(), "1010 0101 0010 0001");
+    /// assert_eq!(b.kronecker(&a).to_compact_binary_string(), "1100 0100 0011 0001");
+    /// ```
+    #[must_use]
+    pub fn kronecker(&self, other: &BitMatrix<Word>) -> Self {
+        let (p, q) = (other.rows(), other.cols());
+        let mut result = Self::zeros(self.rows() * p, self.cols() * q);
+        for i in 0..self.rows() {
+            for j in 0..self.cols() {
+                if self.get(i, j) {
+                    result.replace_sub_matrix(i * p, j * q, other);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns the direct sum of this bit-matrix with another, i.e. the block diagonal matrix with `self` in the top
+    /// left and `other` in the bottom right.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let a: BitMatrix = BitMatrix::ones(1, 2);
+    /// let b: BitMatrix = BitMatrix::identity(2);
+    /// assert_eq!(a.direct_sum(&b).to_compact_binary_string(), "1100 0010 0001");
+    /// ```
+    #[must_use]
+    pub fn direct_sum(&self, other: &BitMatrix<Word>) -> Self {
+        let mut result = Self::zeros(self.rows() + other.rows(), self.cols() + other.cols());
+        result.replace_sub_matrix(0, 0, self);
+        result.replace_sub_matrix(self.rows(), self.cols(), other);
+        result
+    }
+}
+
+/// Triangular sub-matrix methods.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns an independent *clone* of the lower triangular part of the bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::ones(3, 3);
+    /// let sub_m = m.lower();
+    /// assert_eq!(sub_m.to_compact_binary_string(), "100 110 111");
+    /// ```
+    #[must_use]
+    pub fn lower(&self) -> Self {
+        // Edge case:
+        if self.is_empty() {
+            return BitMatrix::new();
         }
 
         // Start with a copy of the bit-matrix.
@@ -1893,19 +2596,18 @@ impl<Word: Unsigned> BitMatrix<Word> {
     pub fn dot_matrix(&self, rhs: &BitMatrix<Word>) -> Self {
         assert_eq!(self.cols(), rhs.rows(), "Incompatible dimensions: {} != {}", self.cols(), rhs.rows());
 
-        let r = self.rows();
-        let c = rhs.cols();
-        let mut result = BitMatrix::zeros(r, c);
+        let mut result = BitMatrix::zeros(self.rows(), rhs.cols());
 
-        // Row access is cheap, columns expensive, so arrange things to pull out columns as few times as possible.
-        for j in 0..c {
-            let rhs_col = rhs.col(j);
-            for i in 0..r {
-                if self.row(i).dot(&rhs_col) {
-                    result.set(i, j, true);
+        // Row access is cheap, so row i of the result is the sum of the rows of `rhs` picked out by row i of `self`.
+        // The result rows are independent of each other so large products get shared across threads.
+        let work = self.rows() * self.cols() * Word::words_needed(rhs.cols()) / 2;
+        parallel::for_each_chunk_mut(&mut result.m_rows, parallel::threads_for(work), |start, rows| {
+            for (i, row) in rows.iter_mut().enumerate() {
+                for k in self.m_rows[start + i].set_bits() {
+                    *row ^= &rhs.m_rows[k];
                 }
             }
-        }
+        });
         result
     }
 }
@@ -1991,79 +2693,286 @@ impl<Word: Unsigned> BitMatrix<Word> {
     }
 }
 
-/// Methods that convert bit-matrices to bit-vectors.
+/// Methods for bit-matrices over the *Boolean semiring* where addition is OR and multiplication is AND.
+///
+/// # Note
+/// A square bit-matrix `A` is also the adjacency matrix of a directed graph with an edge `i -> j` when `A[i][j]` is
+/// set. Boolean powers and closures of `A` then answer questions about paths in the graph.
 impl<Word: Unsigned> BitMatrix<Word> {
-    /// Returns a bit-vector that is the concatenation of the rows of the bit-matrix.
+    /// Returns the Boolean semiring product `A o v` of this bit-matrix with a bit-store `v` as a new bit-vector.
+    ///
+    /// Element `i` of the result is the OR over `k` of `A[i][k] AND v[k]`, i.e., it is set if row `i` shares any set
+    /// element with `v`. If `v` is a set of vertices, then the result is the set of vertices with an edge into `v`.
+    ///
+    /// # Panics
+    /// Panics if the number of columns does not match the length of `v`.
     ///
     /// # Examples
     /// ```
     /// use gf2::*;
-    /// let m: BitMatrix = BitMatrix::identity(3);
-    /// assert_eq!(m.to_vector().to_string(), "100010001");
+    /// let A: BitMatrix = BitMatrix::from_string("110 011 000").unwrap();
+    /// let v: BitVector = BitVector::from_string("011").unwrap();
+    /// assert_eq!(A.boolean_dot(&v).to_string(), "110");
+    /// assert_eq!(A.dot(&v).to_string(), "100");
     /// ```
     #[must_use]
-    pub fn to_vector(&self) -> BitVector<Word> {
-        let mut result = BitVector::with_capacity(self.len());
-        for row in &self.m_rows {
-            result.append_store(row);
-        }
-        result
+    pub fn boolean_dot<Rhs: BitStore<Word>>(&self, v: &Rhs) -> BitVector<Word> {
+        assert_eq!(self.cols(), v.len(), "Bit-matrix has {} columns but bit-store has length {}", self.cols(), v.len());
+        BitVector::from_fn(self.rows(), |i| {
+            let row = &self.m_rows[i];
+            (0..row.words()).any(|w| row.word(w) & v.word(w) != Word::ZERO)
+        })
     }
 
-    /// Returns a bit-vector that is the concatenation of the columns of the bit-matrix.
+    /// Returns the Boolean semiring product `A o B` of this bit-matrix with another bit-matrix.
+    ///
+    /// Element `(i, j)` of the result is the OR over `k` of `A[i][k] AND B[k][j]`. Row `i` of the result is the OR of
+    /// the rows of `B` picked out by the set elements of row `i` of `A`, so we work a whole word at a time. For
+    /// adjacency matrices, the product has an edge `i -> j` if there is a path `i -> k` in `A` followed by `k -> j`
+    /// in `B`.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `A` does not match the number of rows of `B`.
     ///
     /// # Examples
     /// ```
     /// use gf2::*;
-    /// let m: BitMatrix = BitMatrix::identity(3);
-    /// assert_eq!(m.to_vector_of_cols().to_string(), "100010001");
+    /// let A: BitMatrix = BitMatrix::from_string("110 011 000").unwrap();
+    /// assert_eq!(A.boolean_dot_matrix(&A).to_compact_binary_string(), "111 011 000");
+    /// assert_eq!(A.dot_matrix(&A).to_compact_binary_string(), "101 011 000");
     /// ```
     #[must_use]
-    pub fn to_vector_of_cols(&self) -> BitVector<Word> {
-        let mut result = BitVector::with_capacity(self.len());
-        for col in 0..self.cols() {
-            result.append_store(&self.col(col));
+    pub fn boolean_dot_matrix(&self, rhs: &BitMatrix<Word>) -> Self {
+        assert_eq!(self.cols(), rhs.rows(), "Incompatible dimensions: {} != {}", self.cols(), rhs.rows());
+        let mut result = Self::zeros(self.rows(), rhs.cols());
+        for (i, row) in self.m_rows.iter().enumerate() {
+            for k in row.set_bits() {
+                result.m_rows[i] |= &rhs.m_rows[k];
+            }
         }
         result
     }
-}
 
-/// Methods to compute echelon forms for a bit-matrix.
-impl<Word: Unsigned> BitMatrix<Word> {
-    /// Transforms an arbitrary shaped, non-empty, bit-matrix to row-echelon form (in-place).
-    ///
-    /// The method returns a bit-vector that shows which columns have a "pivot" (a non-zero on or below the diagonal).
-    /// The matrix *rank* is the number of set bits in that bit-vector.
-    ///
-    /// A bit-matrix is in echelon form if the first 1 in any row is to the right of the first 1 in the preceding row.
-    /// It is a generalization of an upper triangular form --- the result is a matrix with a "staircase" shape.
+    /// Returns this square bit-matrix raised to the power `n` in the Boolean semiring.
     ///
-    /// The transformation is Gaussian elimination. Any all zero rows are moved to the bottom of the matrix.
-    ///
-    /// The echelon form is not unique.
+    /// For an adjacency matrix, element `(i, j)` of the result is set if there is a path `i -> j` with exactly `n`
+    /// edges. The zeroth power is the identity.
     ///
     /// # Panics
-    /// Panics if the bit-matrix is empty.
+    /// Panics if the bit-matrix is not square.
     ///
     /// # Examples
     /// ```
     /// use gf2::*;
-    /// let mut m: BitMatrix = BitMatrix::identity(3);
-    /// m.set(2, 1, false);
-    /// let has_pivot = m.to_echelon_form();
-    /// assert_eq!(has_pivot.to_string(), "111");
-    /// assert_eq!(m.to_compact_binary_string(), "100 010 001");
+    /// let A: BitMatrix = BitMatrix::right_shift(4, 1);
+    /// assert_eq!(A.boolean_to_the(2).to_compact_binary_string(), "0000 0000 1000 0100");
+    /// assert!(A.boolean_to_the(4).none());
     /// ```
     #[must_use]
-    pub fn to_echelon_form(&mut self) -> BitVector<Word> {
-        assert!(!self.is_empty(), "Bit-matrix must not be empty");
-
-        // We return a bit-vector that shows which columns have a pivot -- start by assuming none.
-        let mut has_pivot: BitVector<Word> = BitVector::zeros(self.cols());
-
-        // The current row of the echelon form we are working on.
-        let mut r = 0;
-        let num_rows = self.rows();
+    pub fn boolean_to_the(&self, n: usize) -> Self {
+        assert!(self.is_square(), "Bit-matrix must be square");
+
+        // Square and multiply from the least significant bit of `n`.
+        let mut result = Self::identity(self.rows());
+        let mut square = self.clone();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.boolean_dot_matrix(&square);
+            }
+            n >>= 1;
+            if n > 0 {
+                square = square.boolean_dot_matrix(&square);
+            }
+        }
+        result
+    }
+
+    /// Returns the *transitive closure* of this square bit-matrix.
+    ///
+    /// For an adjacency matrix, element `(i, j)` of the closure is set if there is a path `i -> j` with at least one
+    /// edge. We use Warshall's algorithm: for each `k`, every row `i` with an edge `i -> k` picks up all of row `k`
+    /// with a single word-parallel OR.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("0100 0010 0100 0000").unwrap();
+    /// assert_eq!(A.transitive_closure().to_compact_binary_string(), "0110 0110 0110 0000");
+    /// ```
+    #[must_use]
+    pub fn transitive_closure(&self) -> Self {
+        assert!(self.is_square(), "Bit-matrix must be square");
+        let mut result = self.clone();
+        for k in 0..result.rows() {
+            let row_k = result.m_rows[k].clone();
+            for i in 0..result.rows() {
+                if result.m_rows[i][k] {
+                    result.m_rows[i] |= &row_k;
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns the *reflexive transitive closure* of this square bit-matrix.
+    ///
+    /// For an adjacency matrix, element `(i, j)` of the closure is set if there is a path `i -> j` with zero or more
+    /// edges, so the diagonal is always set.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("0100 0010 0100 0000").unwrap();
+    /// assert_eq!(A.reflexive_transitive_closure().to_compact_binary_string(), "1110 0110 0110 0001");
+    /// ```
+    #[must_use]
+    pub fn reflexive_transitive_closure(&self) -> Self {
+        let mut result = self.transitive_closure();
+        result.set_diagonal(true);
+        result
+    }
+
+    /// Returns the set of vertices that can be reached from vertex `i` along zero or more edges as a bit-vector.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square or if `i` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("0100 0010 0100 0000").unwrap();
+    /// assert_eq!(A.reachable_from(0).to_string(), "1110");
+    /// assert_eq!(A.reachable_from(3).to_string(), "0001");
+    /// ```
+    #[must_use]
+    pub fn reachable_from(&self, i: usize) -> BitVector<Word> {
+        assert!(i < self.rows(), "Vertex {i} is out of bounds for a bit-matrix with {} rows", self.rows());
+        let mut sources = BitVector::zeros(self.rows());
+        sources.set(i, true);
+        self.reachable_from_set(&sources)
+    }
+
+    /// Returns the set of vertices that can be reached from any of the `sources` along zero or more edges.
+    ///
+    /// This is a breadth-first search where each step ORs in the rows for the whole frontier at once, so it is much
+    /// cheaper than computing the full transitive closure when you only need a few queries.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square or if `sources` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("0100 0000 0001 0000").unwrap();
+    /// let sources: BitVector = BitVector::from_string("1010").unwrap();
+    /// assert_eq!(A.reachable_from_set(&sources).to_string(), "1111");
+    /// ```
+    #[must_use]
+    pub fn reachable_from_set<Src: BitStore<Word>>(&self, sources: &Src) -> BitVector<Word> {
+        assert!(self.is_square(), "Bit-matrix must be square");
+        assert_eq!(sources.len(), self.rows(), "Length mismatch {} != {}", sources.len(), self.rows());
+        let mut reached = BitVector::from_store(sources);
+        let mut frontier = reached.clone();
+        while frontier.any() {
+            let mut next = BitVector::zeros(self.rows());
+            for k in frontier.set_bits() {
+                next |= &self.m_rows[k];
+            }
+
+            // The new frontier is whatever we had not reached before.
+            frontier = &next & &reached.flipped();
+            reached |= &frontier;
+        }
+        reached
+    }
+}
+
+/// Methods that convert bit-matrices to bit-vectors.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns a bit-vector that is the concatenation of the rows of the bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// assert_eq!(m.to_vector().to_string(), "100010001");
+    /// ```
+    #[must_use]
+    pub fn to_vector(&self) -> BitVector<Word> {
+        let mut result = BitVector::with_capacity(self.len());
+        for row in &self.m_rows {
+            result.append_store(row);
+        }
+        result
+    }
+
+    /// Returns a bit-vector that is the concatenation of the columns of the bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// assert_eq!(m.to_vector_of_cols().to_string(), "100010001");
+    /// ```
+    #[must_use]
+    pub fn to_vector_of_cols(&self) -> BitVector<Word> {
+        let mut result = BitVector::with_capacity(self.len());
+        for col in 0..self.cols() {
+            result.append_store(&self.col(col));
+        }
+        result
+    }
+}
+
+/// Methods to compute echelon forms for a bit-matrix.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Transforms an arbitrary shaped, non-empty, bit-matrix to row-echelon form (in-place).
+    ///
+    /// The method returns a bit-vector that shows which columns have a "pivot" (a non-zero on or below the diagonal).
+    /// The matrix *rank* is the number of set bits in that bit-vector.
+    ///
+    /// A bit-matrix is in echelon form if the first 1 in any row is to the right of the first 1 in the preceding row.
+    /// It is a generalization of an upper triangular form --- the result is a matrix with a "staircase" shape.
+    ///
+    /// The transformation is Gaussian elimination. Any all zero rows are moved to the bottom of the matrix.
+    ///
+    /// The echelon form is not unique.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::identity(3);
+    /// m.set(2, 1, false);
+    /// let has_pivot = m.to_echelon_form();
+    /// assert_eq!(has_pivot.to_string(), "111");
+    /// assert_eq!(m.to_compact_binary_string(), "100 010 001");
+    /// ```
+    #[must_use]
+    pub fn to_echelon_form(&mut self) -> BitVector<Word> {
+        assert!(!self.is_empty(), "Bit-matrix must not be empty");
+
+        // Large bit-matrices get shared across threads.
+        let threads = parallel::threads_for(self.rows() * self.m_rows[0].words());
+        if threads > 1 {
+            return self.eliminate_in_parallel(threads, Elimination::Echelon).0;
+        }
+
+        // We return a bit-vector that shows which columns have a pivot -- start by assuming none.
+        let mut has_pivot: BitVector<Word> = BitVector::zeros(self.cols());
+
+        // The current row of the echelon form we are working on.
+        let mut r = 0;
+        let num_rows = self.rows();
 
         // Iterate over each column.
         for j in 0..self.cols() {
@@ -2125,6 +3034,14 @@ impl<Word: Unsigned> BitMatrix<Word> {
     /// ```
     #[must_use]
     pub fn to_reduced_echelon_form(&mut self) -> BitVector<Word> {
+        assert!(!self.is_empty(), "Bit-matrix must not be empty");
+
+        // Large bit-matrices get shared across threads which clear out each pivot column above and below in one pass.
+        let threads = parallel::threads_for(self.rows() * self.m_rows[0].words());
+        if threads > 1 {
+            return self.eliminate_in_parallel(threads, Elimination::Reduced).0;
+        }
+
         // Start with the echelon form.
         let has_pivot = self.to_echelon_form();
 
@@ -2145,100 +3062,562 @@ impl<Word: Unsigned> BitMatrix<Word> {
         // Return the bit-vector that shows which columns have a pivot.
         has_pivot
     }
+
+    /// Crate-only method that runs Gaussian elimination with the rows shared out across `threads` threads.
+    ///
+    /// The threads stay alive for the whole elimination and step through the columns in lock-step. Row swaps would
+    /// cross the thread boundaries, so instead of moving rows every thread tracks the row order the single-threaded
+    /// code would have and the rows are put in that order at the end. The results are identical to the
+    /// single-threaded code.
+    ///
+    /// Returns the bit-vector of pivot columns and, for [`Elimination::LU`], the [`LAPACK`] style row swaps.
+    ///
+    /// [`LAPACK`]: https://en.wikipedia.org/wiki/LAPACK
+    pub(crate) fn eliminate_in_parallel(
+        &mut self, threads: usize, elimination: Elimination,
+    ) -> (BitVector<Word>, Vec<usize>) {
+        let num_rows = self.rows();
+        let num_cols = self.cols();
+        let chunk_len = num_rows.div_ceil(threads);
+        let barrier = parallel::LockStep::new(num_rows.div_ceil(chunk_len));
+
+        // Each column's pivot is agreed on through one of a pair of slots, and the pivot row is shared through a lock.
+        // Alternating the slots lets the first thread reset the slot for the next column without racing the others.
+        let slots = [AtomicUsize::new(usize::MAX), AtomicUsize::new(usize::MAX)];
+        let pivot_row = RwLock::new(BitVector::zeros(num_cols));
+
+        // The work done by the thread that owns the chunk of rows starting at row `start`.
+        let worker = |start: usize, rows: &mut [BitVector<Word>]| {
+            // A panic here tears down the barrier so the other threads panic too instead of waiting forever.
+            let _guard = barrier.guard();
+
+            // `order[r]` is the index of the row at position `r` in the single-threaded code & `pos` is its inverse.
+            let mut order: Vec<usize> = (0..num_rows).collect();
+            let mut pos = order.clone();
+            let mut has_pivot = BitVector::zeros(num_cols);
+            let mut swaps = Vec::new();
+
+            // The current row of the echelon form we are working on.
+            let mut r = 0;
+            for j in 0..num_cols {
+                if r == num_rows {
+                    break;
+                }
+
+                // Find the first of the rows at position `r` or later with a 1 in column j across all the chunks.
+                let slot = &slots[j % 2];
+                let mut first = usize::MAX;
+                for (k, row) in rows.iter().enumerate() {
+                    let p = pos[start + k];
+                    if p >= r && p < first && row[j] {
+                        first = p;
+                    }
+                }
+                slot.fetch_min(first, Ordering::Relaxed);
+                barrier.wait();
+
+                // The owner of the pivot row shares it (the LU decomposition leaves the multipliers alone).
+                let c = slot.load(Ordering::Relaxed);
+                if start == 0 {
+                    slots[(j + 1) % 2].store(usize::MAX, Ordering::Relaxed);
+                }
+                if c != usize::MAX && (start..start + rows.len()).contains(&order[c]) {
+                    let mut shared = pivot_row.write().unwrap();
+                    shared.clone_from(&rows[order[c] - start]);
+                    if elimination == Elimination::LU {
+                        shared.slice_mut(0..=j).set_all(false);
+                    }
+                }
+                barrier.wait();
+
+                // No pivot in this column? The LU decomposition still moves on to the next row.
+                if c == usize::MAX {
+                    if elimination == Elimination::LU {
+                        swaps.push(r);
+                        r += 1;
+                    }
+                    continue;
+                }
+
+                // Clear out column j in the rows after the pivot row (and before it for the reduced echelon form).
+                let p = order[c];
+                let shared = pivot_row.read().unwrap();
+                for (k, row) in rows.iter_mut().enumerate() {
+                    let q = start + k;
+                    if q != p && row[j] && (elimination == Elimination::Reduced || pos[q] >= r) {
+                        *row ^= &*shared;
+                    }
+                }
+                drop(shared);
+
+                // Every thread makes the same swap of positions `r` and `c` in its copy of the row order.
+                has_pivot.set(j, true);
+                if elimination == Elimination::LU {
+                    swaps.push(c);
+                }
+                order.swap(r, c);
+                pos[order[r]] = r;
+                pos[order[c]] = c;
+                r += 1;
+            }
+            (has_pivot, swaps, order)
+        };
+
+        // The calling thread takes the first chunk and its copy of the results is the one we keep.
+        let (has_pivot, swaps, order) = std::thread::scope(|scope| {
+            let mut chunks = self.m_rows.chunks_mut(chunk_len);
+            let first = chunks.next().unwrap();
+            for (k, chunk) in chunks.enumerate() {
+                let worker = &worker;
+                scope.spawn(move || worker((k + 1) * chunk_len, chunk));
+            }
+            worker(0, first)
+        });
+
+        // Finally put the rows in the order the single-threaded code would have left them in.
+        let mut rows = std::mem::take(&mut self.m_rows);
+        self.m_rows = order.iter().map(|&p| std::mem::take(&mut rows[p])).collect();
+        (has_pivot, swaps)
+    }
 }
 
-/// Method to compute the inverse of a bit-matrix if it exists.
+/// Methods to compute the rank of a bit-matrix and bases for its four fundamental subspaces.
+///
+/// # Note
+/// Each of the subspace methods returns its basis vectors as the *rows* of a new bit-matrix. Rows are cheap to access
+/// for our row-major storage and, for example, the `null_space` of a generator matrix is then directly a parity-check
+/// matrix.
 impl<Word: Unsigned> BitMatrix<Word> {
-    /// Returns the inverse of a square bit-matrix or `None` if the matrix is singular.
+    /// Returns the rank of an arbitrary shaped bit-matrix.
     ///
-    /// # Panics
-    /// Panics if the bit-matrix is empty or not square.
+    /// The rank is the number of linearly independent rows (or equivalently columns) in the bit-matrix.
+    /// The rank of an empty bit-matrix is zero.
     ///
     /// # Examples
     /// ```
     /// use gf2::*;
-    /// let m: BitMatrix = BitMatrix::identity(3);
-    /// assert_eq!(m.inverse().unwrap().to_compact_binary_string(), "100 010 001");
+    /// let m: BitMatrix = BitMatrix::ones(3, 5);
+    /// assert_eq!(m.rank(), 1);
+    /// let m: BitMatrix = BitMatrix::identity(4);
+    /// assert_eq!(m.rank(), 4);
+    /// let m: BitMatrix = BitMatrix::new();
+    /// assert_eq!(m.rank(), 0);
     /// ```
     #[must_use]
-    pub fn inverse(&self) -> Option<BitMatrix<Word>> {
-        // The bit-matrix must be square.
-        if !self.is_square() {
-            return None;
-        }
-
-        // Create a copy of the bit-matrix & augment it with the identity matrix on the right.
-        let mut matrix = self.clone();
-        matrix.append_cols(&BitMatrix::identity(self.rows()));
-
-        // Transform the augmented matrix to reduced row-echelon form (we don't need the pivot info).
-        let _ = matrix.to_reduced_echelon_form();
-
-        // If all went well the left half is the identity matrix and the right half is the inverse.
-        if matrix.sub_matrix(0..self.rows(), 0..self.cols()).is_identity() {
-            let inverse = matrix.sub_matrix(0..self.rows(), self.cols()..self.cols() * 2);
-            Some(inverse)
-        }
-        else {
-            None
+    pub fn rank(&self) -> usize {
+        if self.is_empty() {
+            return 0;
         }
+        let mut copy = self.clone();
+        copy.to_echelon_form().count_ones()
     }
-}
 
-/// Associated functions that determine the probability of a "fair coin" bit-matrix being invertible or singular.
-impl<Word: Unsigned> BitMatrix<Word> {
-    /// Returns the probability that a square `n x n` bit-matrix is invertible if each element is chosen independently
-    /// and uniformly at random by flips of a fair coin.
+    /// Returns the *nullity* of the bit-matrix which is the dimension of its null space.
     ///
-    /// # Note
-    /// For large `n`, the value is roughly 29% and that holds for n as low as 10.
+    /// By the rank-nullity theorem, this is the number of columns less the rank.
     ///
-    /// # Panics
-    /// Panics if `n` is 0. Based on the assumption that querying the probability of a 0 x 0 bit-matrix being
-    /// invertible is an upstream error somewhere.
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::ones(3, 5);
+    /// assert_eq!(m.nullity(), 4);
+    /// ```
+    #[must_use]
+    pub fn nullity(&self) -> usize { self.cols() - self.rank() }
+
+    /// Returns a basis for the *null space* (right kernel) of the bit-matrix as the rows of a new bit-matrix.
+    ///
+    /// If the bit-matrix `A` is `m x n` with rank `r` then the null space is the set of all `x` such that `A.x = 0`.
+    /// The returned bit-matrix `N` is `(n - r) x n` and its rows are a basis for that space, so `A.N^T = 0`.
+    ///
+    /// The basis comes from the reduced row echelon form of `A` where each *free* column (one without a pivot)
+    /// contributes one basis vector. If `A` has full column rank then we return an empty bit-matrix.
     ///
     /// # Examples
     /// ```
     /// use gf2::*;
-    /// assert!((<BitMatrix>::probability_invertible(3) - 0.289).abs() < 1e-3);
+    /// let A: BitMatrix = BitMatrix::from_string("1011 0110").unwrap();
+    /// let N = A.null_space();
+    /// assert_eq!(N.to_compact_binary_string(), "1110 1001");
+    /// assert!((&A * &N.transposed()).none());
+    /// let A: BitMatrix = BitMatrix::random(30, 50);
+    /// let N = A.null_space();
+    /// assert_eq!(N.rows(), 50 - A.rank());
+    /// assert_eq!(N.rank(), N.rows());
+    /// assert!((&A * &N.transposed()).none());
     /// ```
     #[must_use]
-    pub fn probability_invertible(n: usize) -> f64 {
-        // Edge case: 0 x 0 matrix is likely a mistake!
-        assert!(n > 0, "Querying the probability of a 0 x 0 bit-matrix being invertible. Upstream error???");
+    pub fn null_space(&self) -> BitMatrix<Word> {
+        // Edge case:
+        if self.is_empty() {
+            return BitMatrix::new();
+        }
 
-        // Formula is p(n) = \prod_{k = 1}^{n} (1 - 2^{-k}) which runs out of juice once n hits any size at all!
-        let mut n_prod = f64::MANTISSA_DIGITS;
+        // Get the reduced row echelon form along with the locations of the pivot columns.
+        let mut rref = self.clone();
+        let has_pivot = rref.to_reduced_echelon_form();
+        let pivots: Vec<usize> = has_pivot.set_bits().collect();
 
-        // Probability is the product of the probabilities of each row being linearly independent.
-        let mut result = 1.0;
-        let mut pow2 = 1.0;
-        while n_prod > 0 {
-            pow2 *= 0.5;
-            result *= 1.0 - pow2;
-            n_prod -= 1;
+        // Each free column `f` gives a basis vector with a 1 in slot `f` and the pivot variables solved for from the
+        // rows of the reduced echelon form.
+        let n = self.cols();
+        let mut result = BitMatrix::new();
+        for f in has_pivot.unset_bits() {
+            let mut x = BitVector::unit(f, n);
+            for (i, &p) in pivots.iter().enumerate() {
+                if rref[i][f] {
+                    x.set(p, true);
+                }
+            }
+            result.m_rows.push(x);
         }
         result
     }
 
-    /// Returns the probability that a square `n x n` bit-matrix is singular if each element is chosen independently
-    /// and uniformly at random by flips of a fair coin.
-    ///
-    /// # Note
-    /// For large `n`, the value is 71% and that holds for n as low as 10.
+    /// Returns a basis for the *left null space* (left kernel) of the bit-matrix as the rows of a new bit-matrix.
     ///
-    /// # Panics
-    /// Panics if `n` is 0. Based on the assumption that querying the probability of a 0 x 0 bit-matrix being
-    /// invertible is an upstream error somewhere.
+    /// If the bit-matrix `A` is `m x n` with rank `r` then the left null space is the set of all `y` such that `y.A =
+    /// 0`. The returned bit-matrix `N` is `(m - r) x m` and its rows are a basis for that space, so `N.A = 0`.
     ///
     /// # Examples
     /// ```
     /// use gf2::*;
-    /// assert!((BitMatrix::<u8>::probability_singular(3) - 0.711).abs() < 1e-3);
+    /// let A: BitMatrix = BitMatrix::from_string("10 01 11").unwrap();
+    /// let N = A.left_null_space();
+    /// assert_eq!(N.to_compact_binary_string(), "111");
+    /// let A: BitMatrix = BitMatrix::random(50, 30);
+    /// let N = A.left_null_space();
+    /// assert_eq!(N.rows(), 50 - A.rank());
+    /// assert!((&N * &A).none());
     /// ```
     #[must_use]
-    pub fn probability_singular(n: usize) -> f64 { 1.0 - Self::probability_invertible(n) }
-}
+    pub fn left_null_space(&self) -> BitMatrix<Word> { self.transposed().null_space() }
+
+    /// Returns a basis for the *row space* of the bit-matrix as the rows of a new bit-matrix.
+    ///
+    /// The basis is the set of non-zero rows of the reduced row echelon form of the bit-matrix. That form is unique so
+    /// two bit-matrices have the same row space if and only if this method returns the same basis for each.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("110 011 101").unwrap();
+    /// assert_eq!(A.row_space().to_compact_binary_string(), "101 011");
+    /// ```
+    #[must_use]
+    pub fn row_space(&self) -> BitMatrix<Word> {
+        // Edge case:
+        if self.is_empty() {
+            return BitMatrix::new();
+        }
+
+        // The rank is the number of non-zero rows in the reduced echelon form and those are at the top.
+        let mut rref = self.clone();
+        let rank = rref.to_reduced_echelon_form().count_ones();
+        rref.m_rows.truncate(rank);
+        rref
+    }
+
+    /// Returns a basis for the *column space* (the image) of the bit-matrix as the rows of a new bit-matrix.
+    ///
+    /// The basis vectors are the *pivot columns* of the original bit-matrix, i.e. those columns that correspond to
+    /// the columns with a pivot in its reduced row echelon form. If the bit-matrix is `m x n` with rank `r`, then the
+    /// returned bit-matrix is `r x m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("110 011 101").unwrap();
+    /// assert_eq!(A.column_space().to_compact_binary_string(), "101 110");
+    /// let A: BitMatrix = BitMatrix::random(30, 50);
+    /// let C = A.column_space();
+    /// assert_eq!(C.rows(), A.rank());
+    /// assert_eq!(C.rank(), A.rank());
+    /// ```
+    #[must_use]
+    pub fn column_space(&self) -> BitMatrix<Word> {
+        // Edge case:
+        if self.is_empty() {
+            return BitMatrix::new();
+        }
+
+        // Find the pivot columns and then copy those columns from the original matrix.
+        let mut rref = self.clone();
+        let has_pivot = rref.to_reduced_echelon_form();
+        let mut result = BitMatrix::new();
+        for j in has_pivot.set_bits() {
+            result.m_rows.push(self.col(j));
+        }
+        result
+    }
+
+    /// Returns a *rank decomposition* `(C, F)` of the bit-matrix where `A = C.F`.
+    ///
+    /// If the bit-matrix `A` is `m x n` with rank `r` then `C` is the `m x r` bit-matrix made up of the pivot columns
+    /// of `A` and `F` is the `r x n` bit-matrix made up of the non-zero rows of the reduced row echelon form of `A`.
+    /// So `C` has full column rank, and `F` has full row rank.
+    ///
+    /// If the bit-matrix is empty or has rank zero, then both factors are empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("110 011 101").unwrap();
+    /// let (C, F) = A.rank_decomposition();
+    /// assert_eq!(C.to_compact_binary_string(), "11 01 10");
+    /// assert_eq!(F.to_compact_binary_string(), "101 011");
+    /// assert_eq!(&C * &F, A);
+    /// let A: BitMatrix = BitMatrix::random(30, 50);
+    /// let (C, F) = A.rank_decomposition();
+    /// assert_eq!(C.cols(), A.rank());
+    /// assert_eq!(F.rows(), A.rank());
+    /// assert_eq!(&C * &F, A);
+    /// ```
+    #[must_use]
+    pub fn rank_decomposition(&self) -> (BitMatrix<Word>, BitMatrix<Word>) {
+        // Edge case:
+        if self.is_empty() {
+            return (BitMatrix::new(), BitMatrix::new());
+        }
+
+        // Use the reduced echelon form for `F` and the pivot columns of the original matrix for `C`.
+        let mut rref = self.clone();
+        let has_pivot = rref.to_reduced_echelon_form();
+        let rank = has_pivot.count_ones();
+        if rank == 0 {
+            return (BitMatrix::new(), BitMatrix::new());
+        }
+        let mut c = BitMatrix::zeros(self.rows(), rank);
+        for (k, j) in has_pivot.set_bits().enumerate() {
+            for i in 0..self.rows() {
+                c.set(i, k, self[i][j]);
+            }
+        }
+        rref.m_rows.truncate(rank);
+        (c, rref)
+    }
+}
+
+/// Methods to compute the inverse of a bit-matrix if it exists and the Pfaffian of an alternating bit-matrix.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns the inverse of a square bit-matrix or `None` if the matrix is singular.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is empty or not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// assert_eq!(m.inverse().unwrap().to_compact_binary_string(), "100 010 001");
+    /// ```
+    #[must_use]
+    pub fn inverse(&self) -> Option<BitMatrix<Word>> {
+        // The bit-matrix must be square.
+        if !self.is_square() {
+            return None;
+        }
+
+        // Create a copy of the bit-matrix & augment it with the identity matrix on the right.
+        let mut matrix = self.clone();
+        matrix.append_cols(&BitMatrix::identity(self.rows()));
+
+        // Transform the augmented matrix to reduced row-echelon form (we don't need the pivot info).
+        let _ = matrix.to_reduced_echelon_form();
+
+        // If all went well the left half is the identity matrix and the right half is the inverse.
+        if matrix.sub_matrix(0..self.rows(), 0..self.cols()).is_identity() {
+            let inverse = matrix.sub_matrix(0..self.rows(), self.cols()..self.cols() * 2);
+            Some(inverse)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the *Pfaffian* of an alternating bit-matrix or `None` if the bit-matrix is not alternating.
+    ///
+    /// The Pfaffian is the sum over all perfect matchings of the indices of the products of the matched elements. It
+    /// satisfies `Pf(A)^2 = det(A)` and every element of GF(2) is its own square, so over GF(2) the Pfaffian is just
+    /// the determinant. In particular it is `0` for odd sizes and `1` exactly when the alternating form is
+    /// non-degenerate. The Pfaffian of the empty bit-matrix is `1` by convention.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("0111 1011 1101 1110").unwrap();
+    /// assert_eq!(m.pfaffian(), Some(true));
+    /// let m: BitMatrix = BitMatrix::from_string("011 101 110").unwrap();
+    /// assert_eq!(m.pfaffian(), Some(false));
+    /// assert_eq!(BitMatrix::<usize>::identity(2).pfaffian(), None);
+    /// assert_eq!(BitMatrix::<usize>::new().pfaffian(), Some(true));
+    /// ```
+    #[must_use]
+    pub fn pfaffian(&self) -> Option<bool> {
+        // Edge case:
+        if self.rows() == 0 && self.cols() == 0 {
+            return Some(true);
+        }
+        if !self.is_alternating() {
+            return None;
+        }
+        Some(self.rank() == self.rows())
+    }
+}
+
+/// Methods to compute one-sided and generalised inverses of rectangular or singular bit-matrices.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns a *left inverse* `L` of the bit-matrix `A` so that `L.A = I` or `None` if there is no such inverse.
+    ///
+    /// If `A` is `m x n` then a left inverse exists if and only if `A` has full column rank `n` and it is `n x m`.
+    /// Unless `A` is square, the left inverse is not unique, and this is just one of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("10 01 11").unwrap();
+    /// let L = A.left_inverse().unwrap();
+    /// assert!((&L * &A).is_identity());
+    /// let A: BitMatrix = BitMatrix::ones(3, 2);
+    /// assert!(A.left_inverse().is_none());
+    /// ```
+    #[must_use]
+    pub fn left_inverse(&self) -> Option<BitMatrix<Word>> {
+        // Edge case:
+        if self.is_empty() {
+            return None;
+        }
+
+        // Augment a copy of the matrix with the identity matrix on the right: [A | I] -> [R | E] where E.A = R.
+        let (m, n) = (self.rows(), self.cols());
+        let mut matrix = self.clone();
+        matrix.append_cols(&BitMatrix::identity(m));
+        let has_pivot = matrix.to_reduced_echelon_form();
+
+        // A has full column rank if and only if each of its columns has a pivot and then the top of R is I.
+        if (0..n).any(|j| !has_pivot[j]) {
+            return None;
+        }
+        Some(matrix.sub_matrix(0..n, n..n + m))
+    }
+
+    /// Returns a *right inverse* `R` of the bit-matrix `A` so that `A.R = I` or `None` if there is no such inverse.
+    ///
+    /// If `A` is `m x n` then a right inverse exists if and only if `A` has full row rank `m` and it is `n x m`.
+    /// Unless `A` is square, the right inverse is not unique, and this is just one of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("101 011").unwrap();
+    /// let R = A.right_inverse().unwrap();
+    /// assert!((&A * &R).is_identity());
+    /// let A: BitMatrix = BitMatrix::ones(2, 3);
+    /// assert!(A.right_inverse().is_none());
+    /// ```
+    #[must_use]
+    pub fn right_inverse(&self) -> Option<BitMatrix<Word>> { self.transposed().left_inverse().map(|l| l.transposed()) }
+
+    /// Returns a *generalised inverse* `G` of the bit-matrix `A` so that `A.G.A = A` and `G.A.G = G`.
+    ///
+    /// Every bit-matrix has such a (reflexive) generalised inverse. If `A` is `m x n` then `G` is `n x m`.
+    /// If `A` is invertible then `G` is its inverse, if `A` has full column rank then `G` is a left inverse, and if
+    /// `A` has full row rank then `G` is a right inverse.
+    ///
+    /// We build `G` from the rank decomposition `A = C.F` as `G = F_r.C_l` where `F_r` is a right inverse of `F`
+    /// and `C_l` is a left inverse of `C`. Unlike the reals, over GF(2) there is not always a unique Moore-Penrose
+    /// inverse, so this is just one of the possible generalised inverses.
+    ///
+    /// If `A` is empty, then we return an empty bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::from_string("110 011 101").unwrap();
+    /// let G = A.generalised_inverse();
+    /// assert_eq!(&(&A * &G) * &A, A);
+    /// assert_eq!(&(&G * &A) * &G, G);
+    /// let A: BitMatrix = BitMatrix::random(30, 50);
+    /// let G = A.generalised_inverse();
+    /// assert_eq!(G.rows(), 50);
+    /// assert_eq!(G.cols(), 30);
+    /// assert_eq!(&(&A * &G) * &A, A);
+    /// assert_eq!(&(&G * &A) * &G, G);
+    /// ```
+    #[must_use]
+    pub fn generalised_inverse(&self) -> BitMatrix<Word> {
+        // Edge case:
+        if self.is_empty() {
+            return BitMatrix::new();
+        }
+
+        // A zero matrix has a zero generalised inverse.
+        let (c, f) = self.rank_decomposition();
+        if c.is_empty() {
+            return BitMatrix::zeros(self.cols(), self.rows());
+        }
+
+        // By construction, `C` has full column rank and `F` has full row rank, so the one-sided inverses exist.
+        let c_l = c.left_inverse().expect("C has full column rank");
+        let f_r = f.right_inverse().expect("F has full row rank");
+        f_r.dot_matrix(&c_l)
+    }
+}
+
+/// Associated functions that determine the probability of a "fair coin" bit-matrix being invertible or singular.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns the probability that a square `n x n` bit-matrix is invertible if each element is chosen independently
+    /// and uniformly at random by flips of a fair coin.
+    ///
+    /// # Note
+    /// For large `n`, the value is roughly 29% and that holds for n as low as 10.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0. Based on the assumption that querying the probability of a 0 x 0 bit-matrix being
+    /// invertible is an upstream error somewhere.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// assert!((<BitMatrix>::probability_invertible(3) - 0.289).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn probability_invertible(n: usize) -> f64 {
+        // Edge case: 0 x 0 matrix is likely a mistake!
+        assert!(n > 0, "Querying the probability of a 0 x 0 bit-matrix being invertible. Upstream error???");
+
+        // Formula is p(n) = \prod_{k = 1}^{n} (1 - 2^{-k}) which runs out of juice once n hits any size at all!
+        let mut n_prod = f64::MANTISSA_DIGITS;
+
+        // Probability is the product of the probabilities of each row being linearly independent.
+        let mut result = 1.0;
+        let mut pow2 = 1.0;
+        while n_prod > 0 {
+            pow2 *= 0.5;
+            result *= 1.0 - pow2;
+            n_prod -= 1;
+        }
+        result
+    }
+
+    /// Returns the probability that a square `n x n` bit-matrix is singular if each element is chosen independently
+    /// and uniformly at random by flips of a fair coin.
+    ///
+    /// # Note
+    /// For large `n`, the value is 71% and that holds for n as low as 10.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0. Based on the assumption that querying the probability of a 0 x 0 bit-matrix being
+    /// invertible is an upstream error somewhere.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// assert!((BitMatrix::<u8>::probability_singular(3) - 0.711).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn probability_singular(n: usize) -> f64 { 1.0 - Self::probability_invertible(n) }
+}
 
 /// Linear system solvers and decompositions ...
 impl<Word: Unsigned> BitMatrix<Word> {
@@ -2259,6 +3638,23 @@ impl<Word: Unsigned> BitMatrix<Word> {
     #[must_use]
     pub fn solver_for(&self, b: &BitVector<Word>) -> BitGauss<Word> { BitGauss::new(self, b) }
 
+    /// Returns the Gaussian elimination solver for this bit-matrix and the passed r.h.s. bit-matrix.
+    ///
+    /// Each column of the r.h.s. bit-matrix is a separate right-hand side.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::ones(3, 5);
+    /// let B: BitMatrix = BitMatrix::ones(3, 2);
+    /// let solver = A.solver_for_matrix(&B);
+    /// assert_eq!(solver.rank(), 1);
+    /// assert_eq!(solver.free_count(), 4);
+    /// assert_eq!(&A * &solver.X().unwrap(), B);
+    /// ```
+    #[must_use]
+    pub fn solver_for_matrix(&self, rhs: &BitMatrix<Word>) -> BitGauss<Word> { BitGauss::new_for_matrix(self, rhs) }
+
     /// Returns a solution to the system of linear equations `A.x = b` or `None` if the system is inconsistent.
     ///
     /// If the system is underdetermined with `f` free variables the returned solution will have `f` random 0/1 entries
@@ -2331,7 +3727,7 @@ impl<Word: Unsigned> BitMatrix<Word> {
     #[must_use]
     pub fn characteristic_polynomial(&self) -> BitPolynomial<Word> {
         assert!(self.is_square(), "Bit-matrix must be square not {}x{}", self.rows(), self.cols());
-        Self::characteristic_polynomial_frobenius_matrix(&self.frobenius_form())
+        Self::characteristic_polynomial_frobenius_matrix(&self.danilevsky_top_rows())
     }
 
     /// Associated function that returns the characteristic polynomial of a *Frobenius matrix* as a [`BitPolynomial`].
@@ -2394,25 +3790,14 @@ impl<Word: Unsigned> BitMatrix<Word> {
         BitPolynomial::from_coefficients(coeffs)
     }
 
-    /// Returns the *Frobenius form* of this bit-matrix in compact top-row only form.
-    ///
-    /// A Frobenius matrix is a square matrix that consists of one or more blocks of *companion matrices* along the
-    /// diagonal. The companion matrices are square matrices that are all zeros except for an arbitrary top row and
-    /// a principal sub-diagonal of all ones. Companion matrices can be compactly represented by their top rows
-    /// only.
-    ///
-    /// We can convert any bit-matrix to Frobenius form via a sequence of similarity transformations that preserve the
-    /// eigen-structure of the original matrix.
-    ///
-    /// We return the Frobenius companion matrices in a compact form as a `Vec` of their top rows as bit-vectors.
+    /// Returns the top rows of companion matrices whose characteristic polynomials multiply to give the characteristic
+    /// polynomial of this bit-matrix.
     ///
-    /// # Panics
-    /// Panics if the bit-matrix is not square.
-    #[must_use]
-    pub fn frobenius_form(&self) -> Vec<BitVector<Word>> {
-        // The bit-matrix must be square.
-        assert!(self.is_square(), "Bit-matrix must be square not {}x{}", self.rows(), self.cols());
-
+    /// We use Danilevsky's algorithm which reduces the bit-matrix via a sequence of similarity transformations to a
+    /// block upper triangular form with companion matrices on the diagonal. That is cheap and enough to read off the
+    /// characteristic polynomial, but the blocks above the diagonal need not be zero so the companion matrices may not
+    /// be the Frobenius normal form of the bit-matrix. See [`BitMatrix::frobenius_form`] for that.
+    fn danilevsky_top_rows(&self) -> Vec<BitVector<Word>> {
         // Space for the top rows of the companion matrices which we will return.
         let mut top_rows = Vec::new();
 
@@ -2433,11 +3818,12 @@ impl<Word: Unsigned> BitMatrix<Word> {
     /// reduced to this form via a sequence of similarity transformations. This methods performs a single one of those
     /// transformations.
     ///
-    /// This `frobenius_form` function calls here with an N x N bit-matrix. In each call the method concentrates on just
-    /// the top-left n x n sub-matrix. On the first call, n should be set to N. The method returns the top row of the
-    /// companion matrix that is the transformation of the bottom-right (n-k) x (n-k) sub-matrix. The caller can store
-    /// that result, decrement n, and call again on the smaller top-left sub-matrix. It may be that the whole matrix
-    /// gets reduced to a single companion matrix in one step and then there will be no need to call again.
+    /// The `danilevsky_top_rows` function calls here with an N x N bit-matrix. In each call the method concentrates on
+    /// just the top-left n x n sub-matrix. On the first call, n should be set to N. The method returns the top row
+    /// of the companion matrix that is the transformation of the bottom-right (n-k) x (n-k) sub-matrix. The caller
+    /// can store that result, decrement n, and call again on the smaller top-left sub-matrix. It may be that the
+    /// whole matrix gets reduced to a single companion matrix in one step and then there will be no need to call
+    /// again.
     ///
     /// The method tries to transform the n x n top-left sub-matrix to a companion matrix working from its bottom-right
     /// corner up. It stops when it gets to a point where the bottom-right (n-k) x (n-k) sub-matrix is in companion form
@@ -2487,23 +3873,38 @@ impl<Word: Unsigned> BitMatrix<Word> {
             // We can sparsely represent M as just a clone of that k'th row of `self`.
             let m = self[k].clone();
 
-            // Note the M^-1 is the same as M and self <- M^-1 * self just alters a few of our elements.
-            for j in 0..n {
-                self.set(k - 1, j, &m * &self.col(j));
+            // Note the M^-1 is the same as M and self <- M^-1 * self just replaces the first n elements of row k-1 with
+            // those of the sum of the rows picked out by m. Large sums are shared across threads.
+            let threads = parallel::threads_for(self.rows() * self.m_rows[0].words());
+            let sums = parallel::map_chunks(&self.m_rows, threads, |start, rows| {
+                let mut sum = BitVector::zeros(self.cols());
+                for (i, row) in rows.iter().enumerate() {
+                    if m[start + i] {
+                        sum ^= row;
+                    }
+                }
+                sum
+            });
+            let mut sum = BitVector::zeros(self.cols());
+            for partial in &sums {
+                sum ^= partial;
             }
+            self.m_rows[k - 1].slice_mut(0..n).copy_store(&sum.slice(0..n));
 
-            // We also use the sparsity of M when computing self <- self * M.
-            for i in 0..k {
-                for j in 0..n {
-                    let tmp = self[i][k - 1] & m[j];
-                    if j == k - 1 {
-                        self.set(i, j, tmp);
-                    }
-                    else {
-                        self.set(i, j, self[i][j] ^ tmp);
+            // We also use the sparsity of M when computing self <- self * M. Each of the first k rows with a 1 in
+            // column k-1 adds m to its first n elements, apart from element k-1 itself which is left alone.
+            let mut m = m;
+            if n < m.len() {
+                m.slice_mut(n..).set_all(false);
+            }
+            m.set(k - 1, false);
+            parallel::for_each_chunk_mut(&mut self.m_rows[..k], threads, |_, rows| {
+                for row in rows {
+                    if row[k - 1] {
+                        *row ^= &m;
                     }
                 }
-            }
+            });
 
             // Now put row k into companion form of all zeros with one on the sub-diagonal.
             // All the rows below k are already in companion form.
@@ -2525,6 +3926,965 @@ impl<Word: Unsigned> BitMatrix<Word> {
     }
 }
 
+/// Methods to compute the minimal polynomial of a bit-matrix and of a bit-vector under a bit-matrix.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns the *minimal polynomial* of this square bit-matrix `A`, i.e. the monic polynomial `f(x)` of least degree
+    /// such that `f(A) = 0`.
+    ///
+    /// The minimal polynomial divides the characteristic polynomial and has the same irreducible factors, but those
+    /// factors can appear with lower multiplicities. It is the last invariant factor in the Frobenius normal form, and
+    /// it is what determines the exact periods of the linear map `v -> A.v`.
+    ///
+    /// # Note
+    /// The minimal polynomial of a random vector under `A` is very often the minimal polynomial of `A`, so we first
+    /// take the lcm of the minimal polynomials of a couple of random vectors using
+    /// [`BitMatrix::minimal_polynomial_of`]. If that falls short of the characteristic polynomial, we find the true
+    /// multiplicity of each irreducible factor `p(x)` by checking the dimension of the kernel of `p(A)^k`. The
+    /// result is always exact.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(4);
+    /// assert_eq!(m.minimal_polynomial().to_string(), "1 + x");
+    /// let m: BitMatrix = BitMatrix::zeros(3, 3);
+    /// assert_eq!(m.minimal_polynomial().to_string(), "x");
+    /// let m: BitMatrix = BitMatrix::from_string("110 010 001").unwrap();
+    /// assert_eq!(m.characteristic_polynomial().to_string(), "1 + x + x^2 + x^3");
+    /// assert_eq!(m.minimal_polynomial().to_string(), "1 + x^2");
+    /// let m: BitMatrix = BitMatrix::random(50, 50);
+    /// assert!(m.minimal_polynomial().eval_matrix(&m).none());
+    /// ```
+    #[must_use]
+    pub fn minimal_polynomial(&self) -> BitPolynomial<Word> {
+        assert!(self.is_square(), "Bit-matrix must be square not {}x{}", self.rows(), self.cols());
+        let n = self.rows();
+        let c = self.characteristic_polynomial();
+
+        // If `f` divides the minimal polynomial then `f.g` is the lcm of `f` and the minimal polynomial of `v` where
+        // `g` is the minimal polynomial of `f(A).v`.
+        let mut f = BitPolynomial::one();
+        for _ in 0..2 {
+            let u = self.polynomial_dot(&f, &BitVector::random(n));
+            f *= &self.minimal_polynomial_of(&u);
+            if f.degree() == c.degree() {
+                return f;
+            }
+        }
+
+        // Otherwise top up the multiplicity `k` of each irreducible factor `p(x)` in `f` until the kernel of `p(A)^k`
+        // has the full dimension given by the multiplicity of `p(x)` in the characteristic polynomial.
+        for (p, m) in c.factors() {
+            let mut k = 0;
+            let mut quotient = f.clone();
+            loop {
+                let (q, r) = quotient.div_rem(&p);
+                if r.is_non_zero() {
+                    break;
+                }
+                quotient = q;
+                k += 1;
+            }
+            if k == m {
+                continue;
+            }
+            let p_of_a = p.eval_matrix(self);
+            let mut power = p_of_a.to_the(k);
+            while power.nullity() < m * p.degree() {
+                power = power.dot_matrix(&p_of_a);
+                f *= &p;
+            }
+        }
+        f
+    }
+
+    /// Returns the *minimal polynomial* of the bit-vector `v` under this square bit-matrix `A`, i.e. the monic
+    /// polynomial `f(x)` of least degree such that `f(A).v = 0`.
+    ///
+    /// This is the polynomial that annihilates the Krylov sequence `v, A.v, A^2.v, ...` so its degree is the dimension
+    /// of the cyclic subspace generated by `v`. It always divides the minimal polynomial of `A`. If `A` is the state
+    /// transition matrix of a linear state machine, then the period of the state `v` is the order of `f(x)`.
+    ///
+    /// # Note
+    /// We use Wiedemann's method which only needs products of `A` with bit-vectors. For a random `r`, the scalar
+    /// sequence `r.A^i.v` satisfies the same recurrences as the Krylov sequence, and the Berlekamp-Massey algorithm
+    /// gives us its minimal polynomial `g(x)` which is a factor of `f(x)`. If `g(A).v` is not zero we repeat the
+    /// process on that vector to pick up the rest of `f(x)`. The result is always exact.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square or if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::right_rotation(6, 1);
+    /// let v: BitVector = BitVector::from_string("101010").unwrap();
+    /// assert_eq!(m.minimal_polynomial_of(&v).to_string(), "1 + x^2");
+    /// let v: BitVector = BitVector::ones(6);
+    /// assert_eq!(m.minimal_polynomial_of(&v).to_string(), "1 + x");
+    /// let v: BitVector = BitVector::zeros(6);
+    /// assert!(m.minimal_polynomial_of(&v).is_one());
+    /// let top_row: BitVector = BitVector::from_string("0011").unwrap();
+    /// let m: BitMatrix = BitMatrix::companion(&top_row);
+    /// let v: BitVector = BitVector::unit(0, 4);
+    /// assert_eq!(m.minimal_polynomial_of(&v), m.characteristic_polynomial());
+    /// ```
+    #[must_use]
+    pub fn minimal_polynomial_of(&self, v: &BitVector<Word>) -> BitPolynomial<Word> {
+        assert!(self.is_square(), "Bit-matrix must be square not {}x{}", self.rows(), self.cols());
+        assert_eq!(v.len(), self.cols(), "Bit-vector has length {} but should have length {}", v.len(), self.cols());
+        let n = self.rows();
+
+        // Invariant: `f` times the minimal polynomial of `u` is the minimal polynomial of `v`.
+        let mut f = BitPolynomial::one();
+        let mut u = v.clone();
+        while u.any() {
+            // The minimal polynomial of `u` has degree at most n - deg(f) so twice that many terms are enough.
+            let len = 2 * (n - f.degree());
+            let r: BitVector<Word> = BitVector::random(n);
+            let mut seq: BitVector<Word> = BitVector::zeros(len);
+            let mut w = u.clone();
+            for i in 0..len {
+                seq.set(i, r.dot(&w));
+                w = self.dot(&w);
+            }
+
+            // An unlucky choice of `r` can give a proper factor (even 1) in which case we go around again.
+            let g = BitPolynomial::minimal_polynomial_of_sequence(&seq);
+            u = self.polynomial_dot(&g, &u);
+            f *= &g;
+        }
+        f
+    }
+
+    /// Returns `p(A).v` computed with Horner's method using only bit-matrix times bit-vector products.
+    fn polynomial_dot(&self, p: &BitPolynomial<Word>, v: &BitVector<Word>) -> BitVector<Word> {
+        let mut result = BitVector::zeros(v.len());
+        if p.is_zero() {
+            return result;
+        }
+        for i in (0..=p.degree()).rev() {
+            result = self.dot(&result);
+            if p.coeff(i) {
+                result ^= v;
+            }
+        }
+        result
+    }
+}
+
+/// Methods to compute the multiplicative order and cycle structure of an invertible bit-matrix.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns the multiplicative *order* of an invertible bit-matrix `A`, i.e. the smallest `e > 0` with `A^e = I`.
+    ///
+    /// This is also the length of the longest cycle of the linear map `v -> A.v` on `GF(2)^n`. For example, an `n x
+    /// n` state transition matrix has a full period of `2^n - 1` if and only if its order is `2^n - 1`.
+    ///
+    /// Returns `None` if the bit-matrix is singular, or if the order overflows a `u128`, or if the characteristic
+    /// polynomial has an irreducible factor of degree greater than 128.
+    ///
+    /// # Note
+    /// We factor the characteristic polynomial and then, for each irreducible factor `p(x)`, find the smallest `k`
+    /// such that the kernel of `p(A)^k` stops growing. The order is the lcm of the orders of the `p(x)^k` which in
+    /// turn is the order of the minimal polynomial of `A`.
+    ///
+    /// Finding the order of an irreducible factor of degree `d` means factoring `2^d - 1`. Our primality test is
+    /// deterministic up to `3.3e24` but only probabilistic above that, so for factors of degree 82 or more the answer
+    /// is not proven correct.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(4);
+    /// assert_eq!(m.order(), Some(1));
+    /// let m: BitMatrix = BitMatrix::right_rotation(5, 1);
+    /// assert_eq!(m.order(), Some(5));
+    /// let top_row: BitVector = BitVector::from_string("011").unwrap();
+    /// let m: BitMatrix = BitMatrix::companion(&top_row);
+    /// assert_eq!(m.order(), Some(7));
+    /// assert!(m.to_the(7).is_identity());
+    /// let m: BitMatrix = BitMatrix::zeros(3, 3);
+    /// assert_eq!(m.order(), None);
+    /// ```
+    #[must_use]
+    pub fn order(&self) -> Option<u128> {
+        assert!(self.is_square(), "Bit-matrix must be square not {}x{}", self.rows(), self.cols());
+        let mut result: u128 = 1;
+        for (p, kernels) in self.primary_kernels() {
+            // A factor of x in the characteristic polynomial means that the bit-matrix is singular.
+            if !p.coeff(0) {
+                return None;
+            }
+            let order = p.order()?.checked_mul(kernels.len().next_power_of_two() as u128)?;
+            result = primes::lcm(result, order)?;
+        }
+        Some(result)
+    }
+
+    /// Returns the *cycle structure* of the linear map `v -> A.v` on `GF(2)^n` for an invertible bit-matrix `A`.
+    ///
+    /// An invertible bit-matrix permutes the `2^n` vectors in `GF(2)^n` and so splits them into disjoint cycles.
+    /// We return a list of `(length, count)` pairs sorted by cycle length where `count` is the number of cycles of
+    /// that length. The zero vector is always a fixed point, so the first pair is always `(1, c)` for some `c >= 1`.
+    ///
+    /// Returns `None` if the bit-matrix is singular, or if any of the numbers involved overflow a `u128`, or if the
+    /// characteristic polynomial has an irreducible factor of degree greater than 128.
+    ///
+    /// # Note
+    /// The map splits `GF(2)^n` into the primary components `ker p(A)^e` for each irreducible factor `p(x)` of the
+    /// characteristic polynomial. Within a component, the vectors in `ker p(A)^k` but not in `ker p(A)^(k-1)` all
+    /// have the same period as `p(x)^k`. A general vector is a sum of its pieces in each component, and its period is
+    /// the lcm of the periods of those pieces.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// assert_eq!(m.cycle_structure(), Some(vec![(1, 8)]));
+    /// let top_row: BitVector = BitVector::from_string("011").unwrap();
+    /// let m: BitMatrix = BitMatrix::companion(&top_row);
+    /// assert_eq!(m.cycle_structure(), Some(vec![(1, 1), (7, 1)]));
+    /// let m: BitMatrix = BitMatrix::right_rotation(4, 1);
+    /// assert_eq!(m.cycle_structure(), Some(vec![(1, 2), (2, 1), (4, 3)]));
+    /// ```
+    #[must_use]
+    pub fn cycle_structure(&self) -> Option<Vec<(u128, u128)>> {
+        assert!(self.is_square(), "Bit-matrix must be square not {}x{}", self.rows(), self.cols());
+
+        // Map from period to the number of vectors with that period. We start with just the zero vector.
+        let mut points: BTreeMap<u128, u128> = BTreeMap::from([(1, 1)]);
+        for (p, kernels) in self.primary_kernels() {
+            // A factor of x in the characteristic polynomial means that the bit-matrix is singular.
+            if !p.coeff(0) {
+                return None;
+            }
+
+            // The periods & counts for the vectors in this component (the zero vector has period 1).
+            let p_order = p.order()?;
+            let mut component = vec![(1, 1)];
+            let mut previous = 1;
+            for (k, kernel) in kernels.iter().enumerate() {
+                let count = 1u128.checked_shl(u32::try_from(kernel.rows()).ok()?).filter(|&c| c != 0)?;
+                let period = p_order.checked_mul((k + 1).next_power_of_two() as u128)?;
+                component.push((period, count - previous));
+                previous = count;
+            }
+
+            // Combine with the components seen so far.
+            let mut combined = BTreeMap::new();
+            for (&period, &count) in &points {
+                for &(c_period, c_count) in &component {
+                    let entry = combined.entry(primes::lcm(period, c_period)?).or_insert(0u128);
+                    *entry = entry.checked_add(count.checked_mul(c_count)?)?;
+                }
+            }
+            points = combined;
+        }
+
+        // Each cycle of length L contains exactly L points.
+        Some(points.into_iter().map(|(period, count)| (period, count / period)).collect())
+    }
+
+    /// Returns a list of `(p, kernels)` pairs, one for each irreducible factor `p(x)` of the characteristic polynomial,
+    /// where `kernels[k-1]` is a basis for the kernel of `p(A)^k` for `k = 1, 2, ...` up to the point where the kernel
+    /// stops growing. The number of entries in `kernels` is then the multiplicity of `p(x)` in the minimal polynomial.
+    fn primary_kernels(&self) -> Vec<(BitPolynomial<Word>, Vec<BitMatrix<Word>>)> {
+        let mut result = Vec::new();
+        for (p, m) in self.characteristic_polynomial().factors() {
+            let target = m * p.degree();
+            let p_of_a = p.eval_matrix(self);
+            let mut power = p_of_a.clone();
+            let mut kernels = vec![power.null_space()];
+            while kernels[kernels.len() - 1].rows() < target {
+                power = power.dot_matrix(&p_of_a);
+                kernels.push(power.null_space());
+            }
+            result.push((p, kernels));
+        }
+        result
+    }
+}
+
+/// Methods to split the space that a square bit-matrix acts on into invariant subspaces.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns the *primary decomposition* of `GF(2)^n` into subspaces that are invariant under the linear map
+    /// `v -> A.v` where `A` is this `n x n` bit-matrix.
+    ///
+    /// If the characteristic polynomial of `A` factors as `p_1(x)^e_1 ... p_k(x)^e_k` where the `p_i(x)` are distinct
+    /// irreducibles, then `GF(2)^n` is the direct sum of the kernels of the `p_i(A)^e_i`. Each of those kernels is
+    /// mapped into itself by `A` so, in a basis made up of the component bases, `A` is block diagonal. For a linear
+    /// state machine that means the register splits into independent sub-registers.
+    ///
+    /// We return one [`PrimaryComponent`] per irreducible factor in the same order as
+    /// [`BitPolynomial::factors`]. Each one has a basis for its subspace and a cyclic vector for each of the cyclic
+    /// pieces that the subspace splits into.
+    ///
+    /// # Note
+    /// If `N = p(A)` and `K_s` is the kernel of `N^s`, then working down from the top we pick cyclic vectors at level
+    /// `s` from `K_s` until they, along with `K_(s-1)` and `N.K_(s+1)`, span all of `K_s`. This is the usual way to
+    /// build Jordan chains, but here each new vector `u` also brings in `A.u, ..., A^(d-1).u` where `d` is the degree
+    /// of `p(x)`.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::random(40, 40);
+    /// let components = A.primary_decomposition();
+    /// let dim: usize = components.iter().map(|c| c.dim()).sum();
+    /// assert_eq!(dim, 40);
+    /// for c in &components {
+    ///     assert_eq!(c.dim(), c.multiplicity() * c.factor().degree());
+    ///     let N = c.factor().eval_matrix(&A).to_the(c.exponent());
+    ///     for row in 0..c.dim() {
+    ///         assert!(N.dot(&c.basis()[row]).none());
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn primary_decomposition(&self) -> Vec<PrimaryComponent<Word>> {
+        assert!(self.is_square(), "Bit-matrix must be square not {}x{}", self.rows(), self.cols());
+        let mut result = Vec::new();
+        for (p, kernels) in self.primary_kernels() {
+            let d = p.degree();
+            let n_mat = p.eval_matrix(self);
+            let top = kernels.len();
+            let mut cyclic = Vec::new();
+            for s in (1..=top).rev() {
+                // The part of K_s that is already accounted for.
+                let mut span = EchelonRows::new();
+                if s > 1 {
+                    for row in &kernels[s - 2].m_rows {
+                        span.insert(row.clone());
+                    }
+                }
+                if s < top {
+                    for row in &kernels[s].m_rows {
+                        span.insert(n_mat.dot(row));
+                    }
+                }
+
+                // Any basis vector of K_s that is not yet spanned is a new cyclic vector.
+                for w in &kernels[s - 1].m_rows {
+                    if span.insert(w.clone()) {
+                        let mut v = w.clone();
+                        for _ in 1..d {
+                            v = self.dot(&v);
+                            span.insert(v.clone());
+                        }
+                        cyclic.push((s, w.clone()));
+                    }
+                }
+            }
+
+            // The basis is the concatenation of the companion chains for the cyclic pieces.
+            let mut basis = BitMatrix::new();
+            for (e, u) in &cyclic {
+                let mut f = BitPolynomial::one();
+                for _ in 0..*e {
+                    f *= &p;
+                }
+                basis.m_rows.extend(self.companion_chain(&f, u).1);
+            }
+            let multiplicity = basis.rows() / d;
+            result.push(PrimaryComponent::new(p, multiplicity, cyclic, basis));
+        }
+        result
+    }
+
+    /// Returns the top row of the companion matrix for `f(x)` along with a basis for the cyclic subspace generated by
+    /// `u` on which `A` acts as that companion matrix. We require that `f(x)` is the minimal polynomial of `u`.
+    ///
+    /// If `f(x) = x^d + c_0 x^(d-1) + ... + c_(d-1)` then the basis vectors `b_0 = u, b_(j+1) = A.b_j + c_j u` satisfy
+    /// `A.b_j = c_j b_0 + b_(j+1)` which is the action of the companion matrix.
+    fn companion_chain(&self, f: &BitPolynomial<Word>, u: &BitVector<Word>) -> (BitVector<Word>, Vec<BitVector<Word>>) {
+        let d = f.degree();
+        let top_row = BitVector::from_fn(d, |j| f.coeff(d - 1 - j));
+        let mut chain = Vec::with_capacity(d);
+        let mut b = u.clone();
+        for j in 0..d {
+            let mut next = self.dot(&b);
+            if top_row[j] {
+                next ^= u;
+            }
+            chain.push(b);
+            b = next;
+        }
+        (top_row, chain)
+    }
+}
+
+/// Methods to compute the Frobenius normal form of a bit-matrix and to check whether two bit-matrices are similar.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns the *Frobenius normal form* of this bit-matrix in compact top-row only form.
+    ///
+    /// A Frobenius matrix is a square matrix that consists of one or more blocks of *companion matrices* along the
+    /// diagonal. The companion matrices are square matrices that are all zeros except for an arbitrary top row and
+    /// a principal sub-diagonal of all ones. Companion matrices can be compactly represented by their top rows
+    /// only.
+    ///
+    /// Every square bit-matrix is similar to exactly one Frobenius matrix where the characteristic polynomial of each
+    /// companion block divides that of the next. Those polynomials are the *invariant factors* of the bit-matrix and
+    /// the last one is its minimal polynomial. Two bit-matrices are similar if and only if they have the same
+    /// Frobenius normal form.
+    ///
+    /// We return the Frobenius companion matrices in a compact form as a `Vec` of their top rows as bit-vectors.
+    /// You can use [`BitMatrix::frobenius`] to expand those into a full bit-matrix.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// let top_rows = m.frobenius_form();
+    /// assert_eq!(top_rows.len(), 3);
+    /// assert!(top_rows.iter().all(|row| row.to_string() == "1"));
+    /// let m: BitMatrix = BitMatrix::from_string("010 000 000").unwrap();
+    /// let top_rows = m.frobenius_form();
+    /// assert_eq!(top_rows.len(), 2);
+    /// assert_eq!(top_rows[0].to_string(), "0");
+    /// assert_eq!(top_rows[1].to_string(), "00");
+    /// ```
+    #[must_use]
+    pub fn frobenius_form(&self) -> Vec<BitVector<Word>> { self.frobenius_form_with_transform().0 }
+
+    /// Returns the *Frobenius normal form* of this bit-matrix `A` in compact top-row only form along with an invertible
+    /// bit-matrix `P` such that `P^-1.A.P = F` where `F` is the full Frobenius matrix.
+    ///
+    /// The top rows are the same as those returned by [`BitMatrix::frobenius_form`] and you can expand them into `F`
+    /// using [`BitMatrix::frobenius`]. The columns of `P` are a basis for `GF(2)^n` made up of chains of vectors
+    /// `u, A.u, A^2.u, ...` (suitably mixed) for one generating vector `u` per companion block.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::random(30, 30);
+    /// let (top_rows, P) = A.frobenius_form_with_transform();
+    /// let F = BitMatrix::frobenius(&top_rows);
+    /// assert_eq!(&P.inverse().unwrap() * &(&A * &P), F);
+    /// ```
+    #[must_use]
+    pub fn frobenius_form_with_transform(&self) -> (Vec<BitVector<Word>>, BitMatrix<Word>) {
+        assert!(self.is_square(), "Bit-matrix must be square not {}x{}", self.rows(), self.cols());
+
+        // We collect the basis vectors as rows and transpose at the end to get the columns of `P`.
+        let mut top_rows = Vec::new();
+        let mut basis = BitMatrix::new();
+        for (f, u) in self.invariant_factor_generators() {
+            let (top_row, chain) = self.companion_chain(&f, &u);
+            basis.m_rows.extend(chain);
+            top_rows.push(top_row);
+        }
+        (top_rows, basis.transposed())
+    }
+
+    /// Returns `true` if this bit-matrix is *similar* to `other`, i.e. if `other = S^-1.A.S` for some invertible `S`.
+    ///
+    /// Two square bit-matrices are similar if and only if they have the same Frobenius normal form.
+    /// Bit-matrices that are not square or have different sizes are never similar.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::random(20, 20);
+    /// let S: BitMatrix = BitMatrix::left_rotation(20, 3);
+    /// let B = &S.inverse().unwrap() * &(&A * &S);
+    /// assert!(A.is_similar_to(&B));
+    /// let A: BitMatrix = BitMatrix::identity(2);
+    /// let B: BitMatrix = BitMatrix::from_string("11 01").unwrap();
+    /// assert_eq!(A.characteristic_polynomial(), B.characteristic_polynomial());
+    /// assert!(!A.is_similar_to(&B));
+    /// ```
+    #[must_use]
+    pub fn is_similar_to(&self, other: &BitMatrix<Word>) -> bool {
+        self.is_square()
+            && other.is_square()
+            && self.rows() == other.rows()
+            && self.frobenius_form() == other.frobenius_form()
+    }
+
+    /// Returns an invertible bit-matrix `S` such that `S^-1.A.S = B` where `A` is this bit-matrix and `B` is `other`,
+    /// or `None` if the two bit-matrices are not similar.
+    ///
+    /// If `P` and `Q` are the transforms to the common Frobenius normal form `F` of `A` and `B` respectively, then
+    /// `P^-1.A.P = F = Q^-1.B.Q` so `S = P.Q^-1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::random(20, 20);
+    /// let T: BitMatrix = BitMatrix::right_rotation(20, 5);
+    /// let B = &T.inverse().unwrap() * &(&A * &T);
+    /// let S = A.similarity_transform_to(&B).unwrap();
+    /// assert_eq!(&S.inverse().unwrap() * &(&A * &S), B);
+    /// let A: BitMatrix = BitMatrix::identity(2);
+    /// let B: BitMatrix = BitMatrix::from_string("11 01").unwrap();
+    /// assert!(A.similarity_transform_to(&B).is_none());
+    /// ```
+    #[must_use]
+    pub fn similarity_transform_to(&self, other: &BitMatrix<Word>) -> Option<BitMatrix<Word>> {
+        if !self.is_square() || !other.is_square() || self.rows() != other.rows() {
+            return None;
+        }
+        let (f_self, p) = self.frobenius_form_with_transform();
+        let (f_other, q) = other.frobenius_form_with_transform();
+        if f_self != f_other {
+            return None;
+        }
+        Some(p.dot_matrix(&q.inverse()?))
+    }
+
+    /// Returns the invariant factors of the bit-matrix along with a generating vector for the cyclic subspace that
+    /// goes with each one. The factors are sorted so that each divides the next.
+    ///
+    /// The `i`'th largest invariant factor is the product of the `i`'th largest primary component for each irreducible
+    /// factor of the characteristic polynomial, and the sum of the generators of those components generates it.
+    fn invariant_factor_generators(&self) -> Vec<(BitPolynomial<Word>, BitVector<Word>)> {
+        let primary = self.primary_decomposition();
+        let count = primary.iter().map(|component| component.cyclic_vectors().len()).max().unwrap_or(0);
+        let mut result = Vec::with_capacity(count);
+        for i in 0..count {
+            let mut f = BitPolynomial::one();
+            let mut u = BitVector::zeros(self.rows());
+            for component in &primary {
+                if let Some((e, g)) = component.cyclic_vectors().get(i) {
+                    for _ in 0..*e {
+                        f *= component.factor();
+                    }
+                    u ^= g;
+                }
+            }
+            result.push((f, u));
+        }
+        result.reverse();
+        result
+    }
+}
+
+/// Methods for symplectic bit-matrices and the symplectic group `Sp(2n,2)`.
+///
+/// # Note
+/// A bit-vector of length `2n` is split into halves `(x|z)` and the *symplectic inner product* of two such bit-vectors
+/// is `<u, v> = u_x * v_z + u_z * v_x` (see [`BitStore::symplectic_dot`]). A `2n x 2n` bit-matrix `M` is *symplectic*
+/// if `<M.u, M.v> = <u, v>` for all `u` and `v`, or equivalently if `M^T.J.M = J` where `J` is the matrix of the
+/// symplectic form. The symplectic bit-matrices form the group `Sp(2n,2)` which, in quantum computing, is the Clifford
+/// group modulo Pauli operators and phases.
+impl<Word: Unsigned> BitMatrix<Word> {
+    /// Returns the `2n x 2n` bit-matrix `J = [[0, I], [I, 0]]` of the symplectic inner product, so `<u, v> = u.J.v`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let J: BitMatrix = BitMatrix::symplectic_form(2);
+    /// assert_eq!(J.to_compact_binary_string(), "0010 0001 1000 0100");
+    /// assert!(J.is_symplectic());
+    /// ```
+    #[must_use]
+    pub fn symplectic_form(n: usize) -> Self {
+        let mut result = Self::zero(2 * n);
+        result.set_super_diagonal(n, true);
+        result.set_sub_diagonal(n, true);
+        result
+    }
+
+    /// Returns the matrix of the *symplectic transvection* `T_h` for a non-zero bit-store `h` of even length.
+    ///
+    /// The transvection maps a bit-vector `v` to `T_h.v = v + <v, h> h`. It is symplectic and is its own inverse.
+    /// Transvections generate the whole of the symplectic group (see [`BitMatrix::transvection_decomposition`]).
+    ///
+    /// # Panics
+    /// Panics if `h` has an odd length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let h: BitVector = BitVector::from_string("1011").unwrap();
+    /// let T: BitMatrix = BitMatrix::transvection(&h);
+    /// assert!(T.is_symplectic());
+    /// assert!((&T * &T).is_identity());
+    /// let v: BitVector = BitVector::from_string("0100").unwrap();
+    /// assert_eq!(T.dot(&v).to_string(), "1111");
+    /// ```
+    #[must_use]
+    pub fn transvection<Src: BitStore<Word>>(h: &Src) -> Self {
+        assert!(h.len().is_multiple_of(2), "A transvection needs a bit-store of even length not {}", h.len());
+        let n = h.len() / 2;
+        let mut result = Self::identity(2 * n);
+        for i in 0..2 * n {
+            // Row `i` of `T_h` is `e_i + h_i (h.J)` and `h.J` is `h` with its two halves swapped.
+            if h.get(i) {
+                for j in 0..2 * n {
+                    if h.get((j + n) % (2 * n)) {
+                        result.flip(i, j);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns `true` if this is a symplectic bit-matrix, i.e., a `2n x 2n` bit-matrix `M` with `M^T.J.M = J`.
+    ///
+    /// Equivalently, the columns of `M` (the images of the standard basis vectors) form a *symplectic basis*: column
+    /// `i` and column `n + i` have inner product `1` and all other pairs of columns have inner product `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let M: BitMatrix = BitMatrix::random_symplectic(10);
+    /// assert!(M.is_symplectic());
+    /// assert!(!BitMatrix::<usize>::ones(4, 4).is_symplectic());
+    /// assert!(!BitMatrix::<usize>::identity(3).is_symplectic());
+    /// ```
+    #[must_use]
+    pub fn is_symplectic(&self) -> bool {
+        if !self.is_square() || !self.rows().is_multiple_of(2) {
+            return false;
+        }
+
+        // Check the inner products of the columns which are the rows of the transpose.
+        let n = self.rows() / 2;
+        let cols = self.transposed();
+        for i in 0..2 * n {
+            for j in i..2 * n {
+                if cols.m_rows[i].symplectic_dot(&cols.m_rows[j]) != (j == i + n) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns a symplectic basis for the span of the rows of this bit-matrix using symplectic Gram-Schmidt.
+    ///
+    /// The basis comes back as a list of *hyperbolic pairs* `(e_k, f_k)` and a list of *isotropic* vectors `g_k`:
+    /// - `<e_k, f_k> = 1` for each pair.
+    /// - Every other inner product among all the returned vectors is `0`.
+    ///
+    /// The isotropic vectors are a basis for the radical of the span (the part that is orthogonal to the whole span).
+    /// There are no isotropic vectors if the span is a symplectic subspace, and no pairs if the span is isotropic (as
+    /// it is for the generators of a stabilizer code).
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix has an odd number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::random(5, 20);
+    /// let (pairs, isotropic) = A.symplectic_gram_schmidt();
+    /// assert_eq!(2 * pairs.len() + isotropic.len(), A.rank());
+    /// for (e, f) in &pairs {
+    ///     assert!(e.symplectic_dot(f));
+    ///     assert!(isotropic.iter().all(|g| !g.symplectic_dot(e) && !g.symplectic_dot(f)));
+    /// }
+    /// ```
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    pub fn symplectic_gram_schmidt(&self) -> (Vec<(BitVector<Word>, BitVector<Word>)>, Vec<BitVector<Word>>) {
+        assert!(self.cols().is_multiple_of(2), "Symplectic Gram-Schmidt needs an even number of columns");
+        Self::symplectic_pairs(self.m_rows.clone(), &BitVector::symplectic_dot)
+    }
+
+    /// Returns a uniformly random `2n x 2n` symplectic bit-matrix, i.e., a uniform sample from `Sp(2n,2)`, where the
+    /// RNG is seeded to `seed`.
+    ///
+    /// We follow the approach of Koenig and Smolin and build the columns as a random symplectic basis one hyperbolic
+    /// pair at a time. Column `k` is a uniformly random non-zero vector in the symplectic complement of the earlier
+    /// pairs, and column `n + k` is uniformly random among the vectors in that complement with inner product `1` with
+    /// column `k`. The number of choices at each step does not depend on the earlier choices, so every symplectic
+    /// bit-matrix is equally likely.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let M: BitMatrix = BitMatrix::random_symplectic_seeded(10, 42);
+    /// assert_eq!(M, BitMatrix::random_symplectic_seeded(10, 42));
+    /// assert_eq!(M.rows(), 20);
+    /// assert!(M.is_symplectic());
+    /// ```
+    #[must_use]
+    pub fn random_symplectic_seeded(n: usize, seed: u64) -> Self {
+        // Edge case:
+        if n == 0 {
+            return Self::new();
+        }
+
+        // If given a non-zero seed we need to save and restore the old seed.
+        let old_seed = rng::seed();
+        if seed != 0 {
+            rng::set_seed(seed);
+        }
+
+        // A symplectic basis for the complement of the pairs chosen so far (starts as the standard basis).
+        let unit = |i: usize| {
+            let mut v = BitVector::zeros(2 * n);
+            v.set(i, true);
+            v
+        };
+        let mut basis: Vec<(BitVector<Word>, BitVector<Word>)> = (0..n).map(|i| (unit(i), unit(n + i))).collect();
+
+        // We fill in the columns of the result as the rows of its transpose.
+        let mut cols = vec![BitVector::zeros(2 * n); 2 * n];
+        for k in 0..n {
+            // Pick coordinates in the basis: a non-zero `e` and then an `f` with `<e, f> = 1`.
+            let m = basis.len();
+            let mut e_coords: BitVector<Word> = BitVector::zeros(2 * m);
+            while e_coords.none() {
+                e_coords.fill_random();
+            }
+            let mut f_coords: BitVector<Word> = BitVector::random(2 * m);
+            if !e_coords.symplectic_dot(&f_coords) {
+                // Flipping the partner of a set coordinate of `e` is a bijection from `<e, f> = 0` to `<e, f> = 1`.
+                let p = e_coords.first_set().unwrap();
+                f_coords.flip((p + m) % (2 * m));
+            }
+
+            // The basis is symplectic so the coordinates have the same inner products as the vectors themselves.
+            let combine = |coords: &BitVector<Word>| {
+                let mut v = BitVector::zeros(2 * n);
+                for (j, (a, b)) in basis.iter().enumerate() {
+                    if coords[j] {
+                        v ^= a;
+                    }
+                    if coords[m + j] {
+                        v ^= b;
+                    }
+                }
+                v
+            };
+            let e = combine(&e_coords);
+            let f = combine(&f_coords);
+
+            // Project the basis onto the complement of `e` and `f` and then recover a symplectic basis for that.
+            let mut projected = Vec::with_capacity(2 * m);
+            for (a, b) in basis {
+                projected.push(Self::symplectic_project(a, &e, &f, &BitVector::symplectic_dot));
+                projected.push(Self::symplectic_project(b, &e, &f, &BitVector::symplectic_dot));
+            }
+            basis = Self::symplectic_pairs(projected, &BitVector::symplectic_dot).0;
+
+            cols[k] = e;
+            cols[n + k] = f;
+        }
+
+        // Restore the old RNG seed.
+        if seed != 0 {
+            rng::set_seed(old_seed);
+        }
+        Self { m_rows: cols }.transposed()
+    }
+
+    /// Returns a uniformly random `2n x 2n` symplectic bit-matrix, i.e., a uniform sample from `Sp(2n,2)`.
+    ///
+    /// See the `random_symplectic_seeded` method for a way to get reproducible results.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let M: BitMatrix = BitMatrix::random_symplectic(10);
+    /// assert!(M.is_symplectic());
+    /// ```
+    #[must_use]
+    pub fn random_symplectic(n: usize) -> Self { Self::random_symplectic_seeded(n, 0) }
+
+    /// Returns the vectors `h_1, ..., h_k` of symplectic transvections with `M = T_h_1 . T_h_2 ... T_h_k` or `None` if
+    /// this bit-matrix is not symplectic.
+    ///
+    /// A `2n x 2n` symplectic bit-matrix is a product of at most `4n` transvections. We move the columns to the
+    /// standard basis one at a time with at most two transvections each, using transvections that fix the columns that
+    /// are already in place.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let M: BitMatrix = BitMatrix::random_symplectic(8);
+    /// let hs = M.transvection_decomposition().unwrap();
+    /// assert!(hs.len() <= 32);
+    /// let mut product: BitMatrix = BitMatrix::identity(16);
+    /// for h in &hs {
+    ///     product = &product * &BitMatrix::transvection(h);
+    /// }
+    /// assert_eq!(product, M);
+    /// assert!(BitMatrix::<usize>::ones(4, 4).transvection_decomposition().is_none());
+    /// ```
+    #[must_use]
+    pub fn transvection_decomposition(&self) -> Option<Vec<BitVector<Word>>> {
+        if !self.is_symplectic() {
+            return None;
+        }
+
+        // We work on the columns of the bit-matrix as the rows of its transpose.
+        let n = self.rows() / 2;
+        let mut cols = self.transposed().m_rows;
+
+        // Applying `T_h_k ... T_h_1` from the left takes the bit-matrix to the identity. Transvections are their own
+        // inverses, so the bit-matrix is `T_h_1 ... T_h_k`.
+        let mut result = Vec::new();
+        let mut fixed: Vec<BitVector<Word>> = Vec::new();
+        for i in 0..n {
+            for k in [i, n + i] {
+                let mut target = BitVector::zeros(2 * n);
+                target.set(k, true);
+                for h in Self::transvections_between(&cols[k], &target, &fixed) {
+                    for col in &mut cols {
+                        if col.symplectic_dot(&h) {
+                            *col ^= &h;
+                        }
+                    }
+                    result.push(h);
+                }
+                fixed.push(target);
+            }
+        }
+        Some(result)
+    }
+
+    /// Returns a symplectic basis for the span of `vectors` as hyperbolic pairs and a basis of isotropic vectors.
+    ///
+    /// This is symplectic Gram-Schmidt for any alternating bilinear `form`, for example [`BitStore::symplectic_dot`]
+    /// or the polar form of a quadratic form. Each pair `(e, f)` has `form(e, f) = 1` and is orthogonal to all the
+    /// other pairs and to the isotropic vectors.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn symplectic_pairs(
+        mut vectors: Vec<BitVector<Word>>, form: &impl Fn(&BitVector<Word>, &BitVector<Word>) -> bool,
+    ) -> (Vec<(BitVector<Word>, BitVector<Word>)>, Vec<BitVector<Word>>) {
+        let mut pairs = Vec::new();
+        let mut isotropic = Vec::new();
+        let mut isotropic_span = EchelonRows::new();
+        while let Some(e) = vectors.pop() {
+            if e.none() {
+                continue;
+            }
+
+            // Look for a partner for `e` and if there is none then `e` is orthogonal to the rest of the span.
+            match vectors.iter().position(|v| form(&e, v)) {
+                Some(j) => {
+                    let f = vectors.swap_remove(j);
+                    for v in &mut vectors {
+                        *v = Self::symplectic_project(std::mem::take(v), &e, &f, form);
+                    }
+                    pairs.push((e, f));
+                },
+                None => {
+                    if isotropic_span.insert(e.clone()) {
+                        isotropic.push(e);
+                    }
+                },
+            }
+        }
+        (pairs, isotropic)
+    }
+
+    /// Returns the projection of `v` onto the complement of the hyperbolic pair `(e, f)` with respect to `form`.
+    pub(crate) fn symplectic_project(
+        mut v: BitVector<Word>, e: &BitVector<Word>, f: &BitVector<Word>,
+        form: &impl Fn(&BitVector<Word>, &BitVector<Word>) -> bool,
+    ) -> BitVector<Word> {
+        let (ve, vf) = (form(&v, e), form(&v, f));
+        if vf {
+            v ^= e;
+        }
+        if ve {
+            v ^= f;
+        }
+        v
+    }
+
+    /// Returns at most two transvection vectors that take `x` to `y` while fixing every vector in `fixed`.
+    ///
+    /// This assumes `x` and `y` have the same inner products with each fixed vector, and that `x` and `y` lie in a
+    /// symplectic subspace that contains the partners of any fixed vectors they are not orthogonal to.
+    fn transvections_between(
+        x: &BitVector<Word>, y: &BitVector<Word>, fixed: &[BitVector<Word>],
+    ) -> Vec<BitVector<Word>> {
+        if x == y {
+            return Vec::new();
+        }
+
+        // If `<x, y> = 1` then `h = x + y` works: `T_h.x = x + <x, x + y> (x + y) = y`.
+        if x.symplectic_dot(y) {
+            return vec![x ^ y];
+        }
+
+        // Otherwise go via a `z` with `<x, z> = <y, z> = 1` and with inner products with the fixed vectors chosen so
+        // that both `x + z` and `z + y` are orthogonal to them.
+        let n = x.len() / 2;
+        let swapped = |v: &BitVector<Word>| BitVector::from_fn(2 * n, |j| v[(j + n) % (2 * n)]);
+        let mut rows = vec![swapped(x), swapped(y)];
+        let mut rhs = vec![true, true];
+        for v in fixed {
+            rows.push(swapped(v));
+            rhs.push(v.symplectic_dot(x));
+        }
+        let constraints = Self { m_rows: rows };
+        let b = BitVector::from_fn(rhs.len(), |i| rhs[i]);
+        let z = BitGauss::new(&constraints, &b)
+            .x0()
+            .expect("The constraints on the intermediate vector should be consistent");
+        vec![x ^ &z, &z ^ y]
+    }
+}
+
+/// The flavours of Gaussian elimination that [`BitMatrix::eliminate_in_parallel`] can run.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Elimination {
+    /// Row-echelon form as computed by [`BitMatrix::to_echelon_form`].
+    Echelon,
+
+    /// Reduced row-echelon form as computed by [`BitMatrix::to_reduced_echelon_form`].
+    Reduced,
+
+    /// The packed `L` and `U` factors as computed by [`crate::BitLU::new`].
+    LU,
+}
+
+/// A crate-only helper that incrementally builds a basis for a subspace of bit-vectors in echelon form.
+///
+/// Each stored row has a 1 in its pivot position and a 0 in the pivot positions of all the rows added before it.
+struct EchelonRows<Word: Unsigned> {
+    rows:   Vec<BitVector<Word>>,
+    pivots: Vec<usize>,
+}
+
+impl<Word: Unsigned> EchelonRows<Word> {
+    /// Returns a new empty basis.
+    fn new() -> Self { Self { rows: Vec::new(), pivots: Vec::new() } }
+
+    /// Adds `v` to the span and returns `true` if it was not already in the span.
+    fn insert(&mut self, mut v: BitVector<Word>) -> bool {
+        for (row, &p) in self.rows.iter().zip(&self.pivots) {
+            if v[p] {
+                v ^= row;
+            }
+        }
+        match v.first_set() {
+            Some(p) => {
+                self.rows.push(v);
+                self.pivots.push(p);
+                true
+            },
+            None => false,
+        }
+    }
+}
+
 /// Methods to convert bit-matrices to strings.
 impl<Word: Unsigned> BitMatrix<Word> {
     /// Returns a multi-line binary string representation of the bit-matrix.
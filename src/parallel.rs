@@ -0,0 +1,334 @@
+//! Helpers that share the work of the heavier bit-matrix and bit-store algorithms across several threads.
+//!
+//! Everything here is built on [`std::thread::scope`] so there are no external dependencies. Unless the crate is built
+//! with the `parallel` feature, [`threads_for`] always returns one and every algorithm runs on the calling thread.
+
+// Crate types.
+use crate::{
+    BitStore,
+    Unsigned,
+};
+
+// Standard library imports.
+#[cfg(feature = "parallel")]
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::sync::{
+    Condvar,
+    Mutex,
+};
+
+/// The thread count set by [`set_num_threads`] where zero means "use all the available cores".
+#[cfg(feature = "parallel")]
+static NUM_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// The default for the least amount of work, roughly a count of word operations, worth handing to a thread.
+#[cfg(feature = "parallel")]
+const DEFAULT_PARALLEL_THRESHOLD: usize = 1 << 16;
+
+/// The smallest value that [`set_parallel_threshold`] accepts, which lets [`threads_for`] turn away small jobs without
+/// reading any of the global settings.
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_THRESHOLD: usize = 1 << 10;
+
+/// The least amount of work worth handing to a thread as set by [`set_parallel_threshold`].
+#[cfg(feature = "parallel")]
+static PARALLEL_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_PARALLEL_THRESHOLD);
+
+/// Sets the maximum number of threads that the parallel algorithms in this crate may use.
+///
+/// Passing `0` restores the default which is the number of cores reported by
+/// [`std::thread::available_parallelism`]. Passing `1` makes every algorithm run on the calling thread.
+///
+/// # Note
+/// This function is only available if the crate is built with the `parallel` feature. The setting is global and
+/// only an upper bound --- small problems are always solved on the calling thread because starting threads for them
+/// costs more than it saves (see [`set_parallel_threshold`]). The results never depend on the number of threads.
+///
+/// # Examples
+/// ```
+/// gf2::set_num_threads(4);
+/// assert_eq!(gf2::num_threads(), 4);
+/// gf2::set_num_threads(0);
+/// assert!(gf2::num_threads() >= 1);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn set_num_threads(n: usize) { NUM_THREADS.store(n, Ordering::Relaxed); }
+
+/// Returns the maximum number of threads that the parallel algorithms in this crate may use.
+///
+/// Unless it is changed by [`set_num_threads`], this is the number of cores reported by
+/// [`std::thread::available_parallelism`].
+///
+/// # Note
+/// This function is only available if the crate is built with the `parallel` feature.
+///
+/// # Examples
+/// ```
+/// gf2::set_num_threads(1);
+/// assert_eq!(gf2::num_threads(), 1);
+/// ```
+#[cfg(feature = "parallel")]
+#[must_use]
+pub fn num_threads() -> usize {
+    match NUM_THREADS.load(Ordering::Relaxed) {
+        0 => {
+            // Asking the OS can be slow so we cache the answer unless someone has set a count in the meantime.
+            let n = std::thread::available_parallelism().map_or(1, std::num::NonZero::get);
+            let _ = NUM_THREADS.compare_exchange(0, n, Ordering::Relaxed, Ordering::Relaxed);
+            n
+        },
+        n => n,
+    }
+}
+
+/// Sets the least amount of work that the parallel algorithms in this crate will hand to a thread of its own.
+///
+/// Work is measured as a rough count of the word operations between the points where the threads have to wait for
+/// each other. For example, one step of Gaussian elimination on an `r x c` bit-matrix is about `r * c / Word::BITS`
+/// word operations. Jobs smaller than twice the threshold run on the calling thread. Passing `0` restores the
+/// default of `65536` which is enough to comfortably cover the cost of starting or waking a thread. Values below
+/// `1024` are raised to `1024`.
+///
+/// # Note
+/// This function is only available if the crate is built with the `parallel` feature. The setting is global and the
+/// results never depend on it.
+///
+/// # Examples
+/// ```
+/// gf2::set_parallel_threshold(1 << 20);
+/// assert_eq!(gf2::parallel_threshold(), 1 << 20);
+/// gf2::set_parallel_threshold(0);
+/// assert_eq!(gf2::parallel_threshold(), 1 << 16);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn set_parallel_threshold(work: usize) {
+    let work = if work == 0 { DEFAULT_PARALLEL_THRESHOLD } else { work.max(MIN_PARALLEL_THRESHOLD) };
+    PARALLEL_THRESHOLD.store(work, Ordering::Relaxed);
+}
+
+/// Returns the least amount of work that the parallel algorithms in this crate will hand to a thread of its own.
+///
+/// See [`set_parallel_threshold`] for the details.
+///
+/// # Note
+/// This function is only available if the crate is built with the `parallel` feature.
+#[cfg(feature = "parallel")]
+#[must_use]
+pub fn parallel_threshold() -> usize { PARALLEL_THRESHOLD.load(Ordering::Relaxed) }
+
+/// Returns the number of threads worth using for a job that needs about `work` word operations between the points
+/// where the threads have to wait for each other.
+///
+/// This is called on every bulk bit-store operation so small jobs are turned away before any of the global settings
+/// are read.
+#[cfg(feature = "parallel")]
+#[must_use]
+pub(crate) fn threads_for(work: usize) -> usize {
+    if work < 2 * MIN_PARALLEL_THRESHOLD {
+        return 1;
+    }
+    num_threads().min(work / parallel_threshold()).max(1)
+}
+
+/// Returns the number of threads worth using for a job --- always one without the `parallel` feature.
+#[cfg(not(feature = "parallel"))]
+#[must_use]
+pub(crate) fn threads_for(_work: usize) -> usize { 1 }
+
+/// A barrier for threads that work in lock-step which is torn down if any of those threads panics.
+///
+/// A plain [`std::sync::Barrier`] waits forever for a thread that has panicked, so the other threads would hang rather
+/// than let the panic reach the caller. Each thread here holds the guard from [`LockStep::guard`] and a thread that
+/// panics abandons the barrier as the guard is dropped. Any thread that is waiting, or later arrives, panics in turn.
+pub(crate) struct LockStep {
+    threads: usize,
+    state:   Mutex<LockStepState>,
+    condvar: Condvar,
+}
+
+/// The state shared by the threads waiting at a [`LockStep`] barrier.
+struct LockStepState {
+    waiting:    usize,
+    generation: usize,
+    abandoned:  bool,
+}
+
+impl LockStep {
+    /// Returns a barrier for `threads` threads.
+    pub(crate) fn new(threads: usize) -> Self {
+        let state = LockStepState { waiting: 0, generation: 0, abandoned: false };
+        Self { threads, state: Mutex::new(state), condvar: Condvar::new() }
+    }
+
+    /// Returns a guard that abandons the barrier if it is dropped while the thread holding it is panicking.
+    pub(crate) fn guard(&self) -> LockStepGuard<'_> { LockStepGuard { barrier: self } }
+
+    /// Blocks until all the threads have called `wait`.
+    ///
+    /// # Panics
+    /// Panics if the barrier has been abandoned because another thread panicked.
+    pub(crate) fn wait(&self) {
+        let mut state = self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        assert!(!state.abandoned, "Another thread working in lock-step panicked");
+        state.waiting += 1;
+        if state.waiting == self.threads {
+            state.waiting = 0;
+            state.generation = state.generation.wrapping_add(1);
+            self.condvar.notify_all();
+            return;
+        }
+        let generation = state.generation;
+        while state.generation == generation && !state.abandoned {
+            state = self.condvar.wait(state).unwrap_or_else(std::sync::PoisonError::into_inner);
+        }
+        assert!(!state.abandoned, "Another thread working in lock-step panicked");
+    }
+
+    /// Marks the barrier as abandoned and wakes every waiting thread.
+    fn abandon(&self) {
+        let mut state = self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        state.abandoned = true;
+        self.condvar.notify_all();
+    }
+}
+
+/// The guard returned by [`LockStep::guard`].
+pub(crate) struct LockStepGuard<'a> {
+    barrier: &'a LockStep,
+}
+
+impl Drop for LockStepGuard<'_> {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            self.barrier.abandon();
+        }
+    }
+}
+
+/// Calls `f(start, chunk)` on consecutive chunks of `items` with one chunk per thread, where `start` is the index of
+/// the first element of `chunk` in `items`.
+///
+/// With one thread, this is just `f(0, items)` on the calling thread.
+pub(crate) fn for_each_chunk_mut<T: Send>(items: &mut [T], threads: usize, f: impl Fn(usize, &mut [T]) + Sync) {
+    if threads <= 1 || items.len() < 2 {
+        f(0, items);
+        return;
+    }
+    let chunk_len = items.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let mut chunks = items.chunks_mut(chunk_len);
+        let first = chunks.next().unwrap();
+        for (k, chunk) in chunks.enumerate() {
+            let f = &f;
+            scope.spawn(move || f((k + 1) * chunk_len, chunk));
+        }
+
+        // The calling thread takes the first chunk itself.
+        f(0, first);
+    });
+}
+
+/// Returns the results of calling `f(start, chunk)` on consecutive chunks of `items` with one chunk per thread, where
+/// `start` is the index of the first element of `chunk` in `items`. The results are in chunk order.
+///
+/// With one thread, this is just `vec![f(0, items)]` on the calling thread.
+pub(crate) fn map_chunks<T: Sync, R: Send>(items: &[T], threads: usize, f: impl Fn(usize, &[T]) -> R + Sync) -> Vec<R> {
+    if threads <= 1 || items.len() < 2 {
+        return vec![f(0, items)];
+    }
+    let chunk_len = items.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_len)
+            .enumerate()
+            .skip(1)
+            .map(|(k, chunk)| {
+                let f = &f;
+                scope.spawn(move || f(k * chunk_len, chunk))
+            })
+            .collect();
+
+        // The calling thread takes the first chunk itself.
+        let mut results = vec![f(0, &items[..chunk_len])];
+        results.extend(handles.into_iter().map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e))));
+        results
+    })
+}
+
+/// Sets word `i` of `lhs` to `op(lhs.word(i), rhs.word(i))` for every word in `lhs`.
+///
+/// Large word-aligned stores are split across threads. Other stores, and the final partial word, are handled on the
+/// calling thread through the `word`/`set_word` interface.
+pub(crate) fn zip_words<Word, Lhs, Rhs>(lhs: &mut Lhs, rhs: &Rhs, op: impl Fn(Word, Word) -> Word + Sync)
+where
+    Word: Unsigned,
+    Lhs: BitStore<Word>,
+    Rhs: BitStore<Word>,
+{
+    let mut done = 0;
+    let threads = threads_for(lhs.words());
+    if threads > 1 && lhs.offset() == 0 && rhs.offset() == 0 {
+        // The full words of both stores are real words in the underlying storage so we can work on those directly.
+        done = lhs.len() / Word::UBITS;
+        let src = &rhs.store()[..done];
+        for_each_chunk_mut(&mut lhs.store_mut()[..done], threads, |start, dst| {
+            for (d, &s) in dst.iter_mut().zip(&src[start..]) {
+                *d = op(*d, s);
+            }
+        });
+    }
+    for i in done..lhs.words() {
+        let word = op(lhs.word(i), rhs.word(i));
+        lhs.set_word(i, word);
+    }
+}
+
+/// Sets word `i` of `store` to `op(store.word(i))` for every word in `store`.
+///
+/// Large word-aligned stores are split across threads. The final partial word is passed to `op` with its unused bits
+/// zeroed and any unused bits in the result are ignored.
+pub(crate) fn map_words<Word, Store>(store: &mut Store, op: impl Fn(Word) -> Word + Sync)
+where
+    Word: Unsigned,
+    Store: BitStore<Word>,
+{
+    let mut done = 0;
+    let threads = threads_for(store.words());
+    if threads > 1 && store.offset() == 0 {
+        done = store.len() / Word::UBITS;
+        for_each_chunk_mut(&mut store.store_mut()[..done], threads, |_, dst| {
+            for d in dst {
+                *d = op(*d);
+            }
+        });
+    }
+    for i in done..store.words() {
+        let word = op(store.word(i));
+        store.set_word(i, word);
+    }
+}
+
+/// Returns the sum of `f(store.word(i))` over every word in `store`.
+///
+/// Large word-aligned stores are split across threads.
+pub(crate) fn sum_words<Word, Store>(store: &Store, f: impl Fn(Word) -> usize + Sync) -> usize
+where
+    Word: Unsigned,
+    Store: BitStore<Word>,
+{
+    let mut done = 0;
+    let mut sum = 0;
+    let threads = threads_for(store.words());
+    if threads > 1 && store.offset() == 0 {
+        done = store.len() / Word::UBITS;
+        let sums = map_chunks(&store.store()[..done], threads, |_, src| src.iter().map(|&w| f(w)).sum::<usize>());
+        sum = sums.into_iter().sum();
+    }
+    for i in done..store.words() {
+        sum += f(store.word(i));
+    }
+    sum
+}
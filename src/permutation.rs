@@ -0,0 +1,612 @@
+//! [`BitPermutation`] is a permutation of the indices `0..n` that can act on bit-stores and on the rows or columns of
+//! bit-matrices.
+#![allow(non_snake_case)]
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitStore,
+    BitVector,
+    Unsigned,
+    rng,
+};
+
+// Standard library imports.
+use std::{
+    fmt,
+    ops::{
+        Index,
+        Mul,
+    },
+};
+
+#[doc = include_str!("../docs/permutation.md")]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct BitPermutation {
+    // The permutation in one-line form: applying it fills slot `i` with the element from slot `m_map[i]`.
+    m_map: Vec<usize>,
+}
+
+/// Constructors for permutations.
+impl BitPermutation {
+    /// Returns the identity permutation of `0..n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::identity(4);
+    /// assert_eq!(p.to_vec(), [0, 1, 2, 3]);
+    /// assert!(p.is_identity());
+    /// ```
+    #[must_use]
+    pub fn identity(n: usize) -> Self { Self { m_map: (0..n).collect() } }
+
+    /// Returns the permutation of `0..n` that swaps `i` and `j` and leaves everything else alone.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` is not less than `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::transposition(4, 1, 3);
+    /// assert_eq!(p.to_vec(), [0, 3, 2, 1]);
+    /// ```
+    #[must_use]
+    pub fn transposition(n: usize, i: usize, j: usize) -> Self {
+        assert!(i < n && j < n, "Indices {i} and {j} must be less than {n}");
+        let mut result = Self::identity(n);
+        result.m_map.swap(i, j);
+        result
+    }
+
+    /// Returns the permutation of `0..n` with `p[i] = (i + k) mod n`.
+    ///
+    /// Applying it fills slot `i` with the element from slot `(i + k) mod n`, so the elements move `k` places towards
+    /// index 0 and wrap around from the front to the back.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::rotation(5, 2);
+    /// assert_eq!(p.to_vec(), [2, 3, 4, 0, 1]);
+    /// let v: BitVector = BitVector::from_string("11000").unwrap();
+    /// assert_eq!(p.apply(&v).to_string(), "00011");
+    /// ```
+    #[must_use]
+    pub fn rotation(n: usize, k: usize) -> Self { Self { m_map: (0..n).map(|i| (i + k) % n).collect() } }
+
+    /// Returns the permutation with the one-line form `map` or `None` if `map` is not a permutation of
+    /// `0..map.len()`.
+    ///
+    /// Applying the result fills slot `i` with the element from slot `map[i]`, i.e. the element at `map[i]` moves to
+    /// `i`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::from_vec(vec![0, 2, 1, 4, 3]).unwrap();
+    /// assert_eq!(p[1], 2);
+    /// assert!(BitPermutation::from_vec(vec![0, 2, 2]).is_none());
+    /// assert!(BitPermutation::from_vec(vec![0, 3, 1]).is_none());
+    /// ```
+    #[must_use]
+    pub fn from_vec(map: Vec<usize>) -> Option<Self> {
+        let n = map.len();
+        let mut seen = vec![false; n];
+        for &j in &map {
+            if j >= n || seen[j] {
+                return None;
+            }
+            seen[j] = true;
+        }
+        Some(Self { m_map: map })
+    }
+
+    /// Returns the permutation of `0..n` given as a product of disjoint cycles, or `None` if the cycles are not
+    /// disjoint or mention an index that is not less than `n`.
+    ///
+    /// Each cycle `[a, b, c, ...]` sets `p[a] = b`, `p[b] = c`, and so on, with the last index mapping back to `a`.
+    /// Applying the result moves the element at `b` to `a`, the element at `c` to `b`, and so on.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::from_cycles(5, &[vec![0, 2, 4], vec![1, 3]]).unwrap();
+    /// assert_eq!(p.to_vec(), [2, 3, 4, 1, 0]);
+    /// assert!(BitPermutation::from_cycles(3, &[vec![0, 1], vec![1, 2]]).is_none());
+    /// ```
+    #[must_use]
+    pub fn from_cycles(n: usize, cycles: &[Vec<usize>]) -> Option<Self> {
+        let mut map: Vec<usize> = (0..n).collect();
+        let mut seen = vec![false; n];
+        for cycle in cycles {
+            for (k, &i) in cycle.iter().enumerate() {
+                if i >= n || seen[i] {
+                    return None;
+                }
+                seen[i] = true;
+                map[i] = cycle[(k + 1) % cycle.len()];
+            }
+        }
+        Some(Self { m_map: map })
+    }
+
+    /// Returns the permutation described by the [`LAPACK`] style row swap instructions `swaps`.
+    ///
+    /// The instructions are carried out in order with step `i` swapping row `i` and row `swaps[i]`. These are the
+    /// instructions you get from [`crate::BitLU::swaps`].
+    ///
+    /// # Panics
+    /// Panics if any entry in `swaps` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::from_swaps(&[0, 2, 2, 4, 4]);
+    /// assert_eq!(p.to_vec(), [0, 2, 1, 4, 3]);
+    /// assert_eq!(p.to_swaps(), [0, 2, 2, 4, 4]);
+    /// ```
+    ///
+    /// [`LAPACK`]: https://www.netlib.org/lapack/
+    #[must_use]
+    pub fn from_swaps(swaps: &[usize]) -> Self {
+        let mut map: Vec<usize> = (0..swaps.len()).collect();
+        for (i, &j) in swaps.iter().enumerate() {
+            assert!(j < swaps.len(), "Swap instruction {j} is out of bounds for {} rows", swaps.len());
+            map.swap(i, j);
+        }
+        Self { m_map: map }
+    }
+
+    /// Returns the permutation that goes with a permutation bit-matrix `P` or `None` if `P` is not a permutation
+    /// matrix.
+    ///
+    /// Row `i` of `P` should have its single set element in column `p[i]`, so that `P.v` is the same as
+    /// `p.apply(&v)` for any bit-vector `v`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let P: BitMatrix = BitMatrix::from_string("010 001 100").unwrap();
+    /// let p = BitPermutation::from_matrix(&P).unwrap();
+    /// assert_eq!(p.to_vec(), [1, 2, 0]);
+    /// assert_eq!(p.to_matrix::<usize>(), P);
+    /// assert!(BitPermutation::from_matrix(&BitMatrix::<usize>::ones(3, 3)).is_none());
+    /// ```
+    #[must_use]
+    pub fn from_matrix<Word: Unsigned>(P: &BitMatrix<Word>) -> Option<Self> {
+        if !P.is_square() {
+            return None;
+        }
+        let mut map = Vec::with_capacity(P.rows());
+        for i in 0..P.rows() {
+            if P[i].count_ones() != 1 {
+                return None;
+            }
+            map.push(P[i].first_set()?);
+        }
+        Self::from_vec(map)
+    }
+
+    /// Returns a random permutation of `0..n` where the RNG is seeded to `seed`. A seed of `0` indicates we should
+    /// randomly seed the RNG.
+    ///
+    /// Every permutation is equally likely (we use the Fisher-Yates shuffle).
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::random_seeded(100, 42);
+    /// let q = BitPermutation::random_seeded(100, 42);
+    /// assert_eq!(p, q);
+    /// ```
+    #[must_use]
+    pub fn random_seeded(n: usize, seed: u64) -> Self {
+        // If given a non-zero seed we need to save and restore the old seed.
+        let old_seed = rng::seed();
+        if seed != 0 {
+            rng::set_seed(seed);
+        }
+
+        let mut map: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            #[allow(clippy::cast_possible_truncation)]
+            let j = (rng::u64() % (i as u64 + 1)) as usize;
+            map.swap(i, j);
+        }
+
+        // Restore the old RNG seed.
+        if seed != 0 {
+            rng::set_seed(old_seed);
+        }
+        Self { m_map: map }
+    }
+
+    /// Returns a random permutation of `0..n`.
+    ///
+    /// # Note
+    /// The RNG is seeded with a random value so you will get different outputs for each run.
+    /// See the `random_seeded` method for a way to get reproducible results.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::random(100);
+    /// assert_eq!(p.len(), 100);
+    /// ```
+    #[must_use]
+    pub fn random(n: usize) -> Self { Self::random_seeded(n, 0) }
+}
+
+/// Queries and conversions for permutations.
+impl BitPermutation {
+    /// Returns the number of indices that the permutation acts on.
+    #[must_use]
+    pub fn len(&self) -> usize { self.m_map.len() }
+
+    /// Returns `true` if the permutation acts on no indices.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.m_map.is_empty() }
+
+    /// Returns `true` if this is the identity permutation.
+    #[must_use]
+    pub fn is_identity(&self) -> bool { self.m_map.iter().enumerate().all(|(i, &j)| i == j) }
+
+    /// Returns the permutation in one-line form as a slice whose entry `i` is `p[i]`.
+    #[must_use]
+    pub fn as_slice(&self) -> &[usize] { &self.m_map }
+
+    /// Returns the permutation in one-line form as a new vector whose entry `i` is `p[i]`.
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<usize> { self.m_map.clone() }
+
+    /// Returns the permutation as [`LAPACK`] style row swap instructions that can be carried out in place.
+    ///
+    /// See [`BitPermutation::from_swaps`] for how the instructions are interpreted.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::random(20);
+    /// assert_eq!(BitPermutation::from_swaps(&p.to_swaps()), p);
+    /// ```
+    ///
+    /// [`LAPACK`]: https://www.netlib.org/lapack/
+    #[must_use]
+    pub fn to_swaps(&self) -> Vec<usize> {
+        // `current[k]` is the original index now at position `k` and `position` is its inverse.
+        let n = self.len();
+        let mut current: Vec<usize> = (0..n).collect();
+        let mut position: Vec<usize> = (0..n).collect();
+        let mut swaps = Vec::with_capacity(n);
+        for i in 0..n {
+            let j = position[self.m_map[i]];
+            swaps.push(j);
+            current.swap(i, j);
+            position[current[i]] = i;
+            position[current[j]] = j;
+        }
+        swaps
+    }
+
+    /// Returns the permutation bit-matrix `P` whose row `i` has its single set element in column `p[i]`.
+    ///
+    /// With this convention `P.v` is `p.apply(&v)` and the product of permutation matrices matches
+    /// [`BitPermutation::compose`].
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::from_vec(vec![1, 2, 0]).unwrap();
+    /// let P: BitMatrix = p.to_matrix();
+    /// assert_eq!(P.to_compact_binary_string(), "010 001 100");
+    /// let v: BitVector = BitVector::from_string("110").unwrap();
+    /// assert_eq!(P.dot(&v), p.apply(&v));
+    /// ```
+    #[must_use]
+    pub fn to_matrix<Word: Unsigned>(&self) -> BitMatrix<Word> {
+        let mut result = BitMatrix::zeros(self.len(), self.len());
+        for (i, &j) in self.m_map.iter().enumerate() {
+            result.set(i, j, true);
+        }
+        result
+    }
+}
+
+/// Methods for the group structure of permutations.
+impl BitPermutation {
+    /// Returns the composition `r` of this permutation with `other`, where `r[i] = other[self[i]]`.
+    ///
+    /// The permutation matrix of the result is the product of the permutation matrices, so applying the result is the
+    /// same as applying `other` and then applying `self`. You can also use the `*` operator.
+    ///
+    /// # Panics
+    /// Panics if the permutations act on different numbers of indices.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::random(10);
+    /// let q = BitPermutation::random(10);
+    /// let pq = p.compose(&q);
+    /// assert_eq!(pq.to_matrix::<usize>(), &p.to_matrix::<usize>() * &q.to_matrix::<usize>());
+    /// let v: BitVector = BitVector::random(10);
+    /// assert_eq!(pq.apply(&v), p.apply(&q.apply(&v)));
+    /// ```
+    #[must_use]
+    pub fn compose(&self, other: &BitPermutation) -> Self {
+        assert_eq!(self.len(), other.len(), "Length mismatch {} != {}", self.len(), other.len());
+        Self { m_map: self.m_map.iter().map(|&j| other.m_map[j]).collect() }
+    }
+
+    /// Returns the inverse permutation.
+    ///
+    /// The permutation matrix of the inverse is the transpose of the permutation matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::random(10);
+    /// assert!(p.compose(&p.inverse()).is_identity());
+    /// assert_eq!(p.inverse().to_matrix::<usize>(), p.to_matrix::<usize>().transposed());
+    /// ```
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let mut map = vec![0; self.len()];
+        for (i, &j) in self.m_map.iter().enumerate() {
+            map[j] = i;
+        }
+        Self { m_map: map }
+    }
+
+    /// Returns the cycle decomposition of the permutation, leaving out the fixed points.
+    ///
+    /// Each cycle starts with its smallest index and the cycles are sorted by that index.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::from_vec(vec![2, 3, 4, 1, 0, 5]).unwrap();
+    /// assert_eq!(p.cycles(), [vec![0, 2, 4], vec![1, 3]]);
+    /// ```
+    #[must_use]
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.len()];
+        let mut result = Vec::new();
+        for start in 0..self.len() {
+            if seen[start] || self.m_map[start] == start {
+                continue;
+            }
+            let mut cycle = Vec::new();
+            let mut i = start;
+            while !seen[i] {
+                seen[i] = true;
+                cycle.push(i);
+                i = self.m_map[i];
+            }
+            result.push(cycle);
+        }
+        result
+    }
+
+    /// Returns the sign of the permutation as `1` if it is even and `-1` if it is odd.
+    ///
+    /// A permutation is even if it is a product of an even number of transpositions.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// assert_eq!(BitPermutation::identity(5).sign(), 1);
+    /// assert_eq!(BitPermutation::transposition(5, 0, 3).sign(), -1);
+    /// assert_eq!(BitPermutation::rotation(5, 1).sign(), 1);
+    /// ```
+    #[must_use]
+    pub fn sign(&self) -> i32 {
+        let transpositions: usize = self.cycles().iter().map(|cycle| cycle.len() - 1).sum();
+        if transpositions.is_multiple_of(2) { 1 } else { -1 }
+    }
+
+    /// Returns the order of the permutation, i.e. the smallest `k > 0` such that `p^k` is the identity, or `None` if
+    /// the order does not fit in a `u128`.
+    ///
+    /// The order is the least common multiple of the cycle lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::from_cycles(7, &[vec![0, 1], vec![2, 3, 4], vec![5, 6]]).unwrap();
+    /// assert_eq!(p.order(), Some(6));
+    /// ```
+    #[must_use]
+    pub fn order(&self) -> Option<u128> {
+        let mut result: u128 = 1;
+        for cycle in self.cycles() {
+            result = crate::primes::lcm(result, cycle.len() as u128)?;
+        }
+        Some(result)
+    }
+}
+
+/// Methods that apply a permutation to bit-stores and to the rows or columns of bit-matrices.
+impl BitPermutation {
+    /// Returns a new bit-vector `w` with `w[i] = v[p[i]]` which is the product `P.v` with the permutation matrix.
+    ///
+    /// In words, slot `i` of the result gets the element from slot `p[i]` of `v`.
+    ///
+    /// # Panics
+    /// Panics if `v` does not have one element for each index the permutation acts on.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::from_vec(vec![2, 0, 1]).unwrap();
+    /// let v: BitVector = BitVector::from_string("100").unwrap();
+    /// assert_eq!(p.apply(&v).to_string(), "010");
+    ///
+    /// // w[0] = v[3], w[1] = v[0], w[2] = v[1], w[3] = v[2].
+    /// let p = BitPermutation::from_vec(vec![3, 0, 1, 2]).unwrap();
+    /// let v: BitVector = BitVector::from_string("1101").unwrap();
+    /// assert_eq!(p.apply(&v).to_string(), "1110");
+    /// ```
+    #[must_use]
+    pub fn apply<Word: Unsigned, Src: BitStore<Word>>(&self, v: &Src) -> BitVector<Word> {
+        assert_eq!(v.len(), self.len(), "Length mismatch {} != {}", v.len(), self.len());
+        BitVector::from_fn(self.len(), |i| v.get(self.m_map[i]))
+    }
+
+    /// Permutes the elements of any bit-store in place so that afterwards `v[i]` is the old `v[p[i]]`.
+    ///
+    /// # Panics
+    /// Panics if `v` does not have one element for each index the permutation acts on.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::from_vec(vec![2, 0, 1]).unwrap();
+    /// let mut v: BitVector = BitVector::from_string("100").unwrap();
+    /// p.apply_in_place(&mut v);
+    /// assert_eq!(v.to_string(), "010");
+    /// ```
+    pub fn apply_in_place<Word: Unsigned, Dst: BitStore<Word>>(&self, v: &mut Dst) {
+        assert_eq!(v.len(), self.len(), "Length mismatch {} != {}", v.len(), self.len());
+        for (i, j) in self.to_swaps().into_iter().enumerate() {
+            v.swap(i, j);
+        }
+    }
+
+    /// Returns a new bit-matrix whose row `i` is row `p[i]` of `A`, i.e. the product `P.A`.
+    ///
+    /// # Panics
+    /// Panics if `A` does not have one row for each index the permutation acts on.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::random(8);
+    /// let A: BitMatrix = BitMatrix::random(8, 5);
+    /// assert_eq!(p.permute_rows(&A), &p.to_matrix() * &A);
+    /// ```
+    #[must_use]
+    pub fn permute_rows<Word: Unsigned>(&self, A: &BitMatrix<Word>) -> BitMatrix<Word> {
+        let mut result = A.clone();
+        self.permute_rows_in_place(&mut result);
+        result
+    }
+
+    /// Permutes the rows of `A` in place so that afterwards row `i` is the old row `p[i]`.
+    ///
+    /// # Panics
+    /// Panics if `A` does not have one row for each index the permutation acts on.
+    pub fn permute_rows_in_place<Word: Unsigned>(&self, A: &mut BitMatrix<Word>) {
+        assert_eq!(A.rows(), self.len(), "Row count mismatch {} != {}", A.rows(), self.len());
+        for (i, j) in self.to_swaps().into_iter().enumerate() {
+            A.swap_rows(i, j);
+        }
+    }
+
+    /// Returns a new bit-matrix whose column `j` is column `p[j]` of `A`, i.e. the product `A.P^T`.
+    ///
+    /// # Panics
+    /// Panics if `A` does not have one column for each index the permutation acts on.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p = BitPermutation::random(8);
+    /// let A: BitMatrix = BitMatrix::random(5, 8);
+    /// assert_eq!(p.permute_cols(&A), &A * &p.to_matrix().transposed());
+    /// ```
+    #[must_use]
+    pub fn permute_cols<Word: Unsigned>(&self, A: &BitMatrix<Word>) -> BitMatrix<Word> {
+        assert_eq!(A.cols(), self.len(), "Column count mismatch {} != {}", A.cols(), self.len());
+        let mut result = BitMatrix::zeros(A.rows(), A.cols());
+        for i in 0..A.rows() {
+            result[i] = self.apply(&A[i]);
+        }
+        result
+    }
+
+    /// Permutes the columns of `A` in place so that afterwards column `j` is the old column `p[j]`.
+    ///
+    /// # Panics
+    /// Panics if `A` does not have one column for each index the permutation acts on.
+    pub fn permute_cols_in_place<Word: Unsigned>(&self, A: &mut BitMatrix<Word>) {
+        assert_eq!(A.cols(), self.len(), "Column count mismatch {} != {}", A.cols(), self.len());
+        let swaps = self.to_swaps();
+        for row in A.row_iter_mut() {
+            for (i, &j) in swaps.iter().enumerate() {
+                row.swap(i, j);
+            }
+        }
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// Trait implementations for permutations.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// The `Index` trait implementation for a permutation returns `p[i]`, the slot that applying `p` takes element `i`
+/// from.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let p = BitPermutation::rotation(4, 1);
+/// assert_eq!(p[3], 0);
+/// ```
+impl Index<usize> for BitPermutation {
+    type Output = usize;
+
+    #[inline]
+    fn index(&self, i: usize) -> &usize { &self.m_map[i] }
+}
+
+/// Composes two permutations with `p * q` the same as `p.compose(&q)`.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let p = BitPermutation::random(10);
+/// assert!((&p * &p.inverse()).is_identity());
+/// ```
+impl Mul<&BitPermutation> for &BitPermutation {
+    type Output = BitPermutation;
+
+    fn mul(self, rhs: &BitPermutation) -> BitPermutation { self.compose(rhs) }
+}
+
+/// The `Display` trait implementation for a permutation.
+///
+/// The default output is cycle notation with the fixed points left out, and the identity shows as `()`.
+/// The alternate output is the one-line form.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let p = BitPermutation::from_vec(vec![2, 3, 4, 1, 0, 5]).unwrap();
+/// assert_eq!(format!("{p}"), "(0 2 4)(1 3)");
+/// assert_eq!(format!("{p:#}"), "[2, 3, 4, 1, 0, 5]");
+/// assert_eq!(format!("{}", BitPermutation::identity(3)), "()");
+/// ```
+impl fmt::Display for BitPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:?}", self.m_map);
+        }
+        let cycles = self.cycles();
+        if cycles.is_empty() {
+            return write!(f, "()");
+        }
+        for cycle in cycles {
+            let entries: Vec<String> = cycle.iter().map(ToString::to_string).collect();
+            write!(f, "({})", entries.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The `Debug` trait implementation for a permutation is the one-line form.
+impl fmt::Debug for BitPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{:?}", self.m_map) }
+}
@@ -5,6 +5,7 @@ use crate::{
     BitStore,
     BitVector,
     Unsigned,
+    primes,
 };
 
 use std::{
@@ -1093,6 +1094,425 @@ impl<Word: Unsigned> BitPolynomial<Word> {
     }
 }
 
+/// Division, greatest common divisor, and factorisation methods for bit-polynomials.
+impl<Word: Unsigned> BitPolynomial<Word> {
+    /// Returns the pair `(q, r)` where `q(x)` is the quotient and `r(x)` the remainder of dividing `self` by `divisor`.
+    ///
+    /// If `self` is `p(x)` and `divisor` is `d(x)` then `p(x) = q(x) * d(x) + r(x)` where `degree[r] < degree[d]`.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is the zero polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p: BitPolynomial = BitPolynomial::ones(4);
+    /// let d: BitPolynomial = BitPolynomial::ones(2);
+    /// let (q, r) = p.div_rem(&d);
+    /// assert_eq!(q.to_string(), "x^2");
+    /// assert_eq!(r.to_string(), "1 + x");
+    /// assert_eq!(&(&q * &d) + &r, p);
+    /// ```
+    #[must_use]
+    pub fn div_rem(&self, divisor: &BitPolynomial<Word>) -> (BitPolynomial<Word>, BitPolynomial<Word>) {
+        // Error case: division by the zero polynomial.
+        assert!(divisor.is_non_zero(), "Cannot divide by the zero polynomial");
+
+        // Edge case: the dividend has lower degree than the divisor so the quotient is zero.
+        let d = divisor.degree();
+        if self.is_zero() || self.degree() < d {
+            let mut r = self.clone();
+            r.make_monic();
+            return (Self::zero(), r);
+        }
+
+        // Long division: working down from the top, we cancel each set coefficient with a shifted copy of the divisor.
+        let n = self.degree();
+        let dv: BitVector<Word> = divisor.coeffs.slice(0..d + 1).into();
+        let mut r: BitVector<Word> = self.coeffs.slice(0..n + 1).into();
+        let mut q = BitVector::zeros(n - d + 1);
+        for i in (d..=n).rev() {
+            if r[i] {
+                q.set(i - d, true);
+                r.slice_mut(i - d..i + 1).xor_eq(&dv);
+            }
+        }
+
+        // The remainder has degree less than `d` so we can drop its top coefficients.
+        r.resize(d);
+        let mut r = Self::from_coefficients(r);
+        r.make_monic();
+        (Self::from_coefficients(q), r)
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    ///
+    /// The greatest common divisor of any polynomial and the zero polynomial is the polynomial itself.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p: BitPolynomial = BitPolynomial::ones(1) * BitPolynomial::ones(2);
+    /// let q: BitPolynomial = BitPolynomial::ones(1) * BitPolynomial::x_to_the(5);
+    /// assert_eq!(p.gcd(&q).to_string(), "1 + x");
+    /// assert_eq!(p.gcd(&BitPolynomial::zero()), p);
+    /// ```
+    #[must_use]
+    pub fn gcd(&self, other: &BitPolynomial<Word>) -> BitPolynomial<Word> {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        a.make_monic();
+        b.make_monic();
+        while b.is_non_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Returns the formal derivative of the bit-polynomial.
+    ///
+    /// Over GF(2), the derivative of `x^i` is `x^(i-1)` if `i` is odd and zero if `i` is even.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p: BitPolynomial = BitPolynomial::ones(4);
+    /// assert_eq!(p.derivative().to_string(), "1 + x^2");
+    /// let p: BitPolynomial = BitPolynomial::ones(1).squared();
+    /// assert!(p.derivative().is_zero());
+    /// ```
+    #[must_use]
+    pub fn derivative(&self) -> BitPolynomial<Word> {
+        if self.degree() == 0 {
+            return Self::zero();
+        }
+        let mut result = Self::from_fn(self.degree() - 1, |i| i % 2 == 0 && self.coeffs[i + 1]);
+        result.make_monic();
+        result
+    }
+
+    /// Returns `true` if the bit-polynomial is *irreducible*, i.e. it has positive degree and no non-trivial factors.
+    ///
+    /// # Note
+    /// We use Rabin's test: `p(x)` of degree `d` is irreducible if and only if `p(x)` divides `x^(2^d) - x` and
+    /// `gcd(p(x), x^(2^(d/q)) - x) = 1` for each prime factor `q` of `d`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p: BitPolynomial = BitPolynomial::from_coefficients(BitVector::from_string("1101").unwrap());
+    /// assert!(p.is_irreducible());
+    /// let p: BitPolynomial = BitPolynomial::ones(3);
+    /// assert!(!p.is_irreducible());
+    /// ```
+    #[must_use]
+    pub fn is_irreducible(&self) -> bool {
+        // Constants are not irreducible and polynomials of degree 1 always are.
+        let d = self.degree();
+        if d == 0 {
+            return false;
+        }
+        if d == 1 {
+            return true;
+        }
+
+        // Check that p(x) divides x^(2^d) - x.
+        let x = Self::x_to_the(1);
+        if (&self.reduce_x_to_the_2_to_the(d) + &x).is_non_zero() {
+            return false;
+        }
+
+        // Check that p(x) has no factors in common with x^(2^(d/q)) - x for each prime q dividing d.
+        for (q, _) in primes::factor(d as u128) {
+            let r = &self.reduce_x_to_the_2_to_the(d / q as usize) + &x;
+            if self.gcd(&r).degree() > 0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns the factorisation of the bit-polynomial into irreducible factors as a list of `(factor, multiplicity)`
+    /// pairs.
+    ///
+    /// The factors are sorted by degree and the list for any constant polynomial p(x) := 1 is empty.
+    ///
+    /// # Note
+    /// We first split the polynomial into square-free pieces, then use *distinct-degree* factorisation to split those
+    /// into products of irreducibles of the same degree, and finally use the randomised *Cantor-Zassenhaus* method to
+    /// split those products into individual irreducible factors.
+    ///
+    /// # Panics
+    /// Panics if `self` is the zero polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let x: BitPolynomial = BitPolynomial::x_to_the(1);
+    /// let p: BitPolynomial = BitPolynomial::ones(1);
+    /// let q: BitPolynomial = BitPolynomial::from_coefficients(BitVector::from_string("1101").unwrap());
+    /// let f = &(&x * &p.squared()) * &q;
+    /// let factors = f.factors();
+    /// assert_eq!(factors.len(), 3);
+    /// assert_eq!(factors[0], (x, 1));
+    /// assert_eq!(factors[1], (p, 2));
+    /// assert_eq!(factors[2], (q, 1));
+    /// ```
+    #[must_use]
+    pub fn factors(&self) -> Vec<(BitPolynomial<Word>, usize)> {
+        // Error case: the zero polynomial.
+        assert!(self.is_non_zero(), "The zero polynomial has no factorisation");
+
+        let mut result = Vec::new();
+        for (s, m) in self.square_free_factors() {
+            for (g, d) in s.distinct_degree_factors() {
+                for f in g.equal_degree_factors(d) {
+                    result.push((f, m));
+                }
+            }
+        }
+        result.sort_by(|a, b| a.0.degree().cmp(&b.0.degree()).then_with(|| a.0.cmp(&b.0)));
+        result
+    }
+
+    /// Returns the *order* of the bit-polynomial `p(x)`, i.e. the smallest `e > 0` such that `p(x)` divides `x^e - 1`.
+    ///
+    /// The order of p(x) := 1 is 1. For example, the order of an irreducible polynomial of degree `d` divides `2^d -
+    /// 1`, and the polynomial is *primitive* if the order is exactly `2^d - 1`.
+    ///
+    /// Returns `None` if no such `e` exists (`p(0) = 0` or `p` is zero), or if the order cannot be computed because it
+    /// overflows a `u128` or `p(x)` has an irreducible factor of degree greater than 128.
+    ///
+    /// # Note
+    /// For an irreducible factor of degree `d` we factor `2^d - 1`. Our primality test is deterministic up to `3.3e24`
+    /// but only probabilistic above that, so for factors of degree 82 or more the answer is not proven correct.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let p: BitPolynomial = BitPolynomial::from_coefficients(BitVector::from_string("1101").unwrap());
+    /// assert_eq!(p.order(), Some(7));
+    /// let p: BitPolynomial = BitPolynomial::ones(2);
+    /// assert_eq!(p.order(), Some(3));
+    /// let p: BitPolynomial = BitPolynomial::ones(4);
+    /// assert_eq!(p.order(), Some(5));
+    /// let p: BitPolynomial = BitPolynomial::ones(1).squared();
+    /// assert_eq!(p.order(), Some(2));
+    /// let p: BitPolynomial = BitPolynomial::x_to_the(3);
+    /// assert_eq!(p.order(), None);
+    /// ```
+    #[must_use]
+    pub fn order(&self) -> Option<u128> {
+        // No power of x is 1 mod p(x) if x divides p(x).
+        if self.is_zero() || !self.coeff(0) {
+            return None;
+        }
+
+        // The order of q(x)^e for irreducible q(x) is ord(q) * 2^t where t is the smallest integer with 2^t >= e.
+        // The order of the product of coprime factors is then the lcm of the orders of those factors.
+        let mut result: u128 = 1;
+        for (q, e) in self.factors() {
+            let order = q.irreducible_order()?.checked_mul(e.next_power_of_two() as u128)?;
+            result = primes::lcm(result, order)?;
+        }
+        Some(result)
+    }
+}
+
+/// Methods to find the minimal polynomial of a linearly recurrent sequence of bits.
+impl<Word: Unsigned> BitPolynomial<Word> {
+    /// Returns the *minimal polynomial* of the bit-sequence `s_0, s_1, ...` held in the bit-store `seq`.
+    ///
+    /// This is the monic polynomial `f(x) = x^L + f_(L-1) x^(L-1) + ... + f_0` of least degree such that
+    /// `s_(i+L) = f_(L-1) s_(i+L-1) + ... + f_0 s_i` for all `i` where both sides are in range. The degree `L` is the
+    /// *linear complexity* of the sequence, i.e. the length of the shortest linear feedback shift register that
+    /// generates it. The minimal polynomial of an all-zero sequence is `1`.
+    ///
+    /// If the sequence is known to satisfy a recurrence of degree at most `L`, then its first `2L` terms are enough to
+    /// recover that recurrence.
+    ///
+    /// # Note
+    /// We use the [Berlekamp-Massey algorithm](https://en.wikipedia.org/wiki/Berlekamp%E2%80%93Massey_algorithm)
+    /// which takes `O(n^2)` operations for a sequence of length `n`. The algorithm finds the *connection polynomial*
+    /// `C(x) = 1 + c_1 x + ... + c_L x^L` and the minimal polynomial is its reciprocal `x^L C(1/x)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let seq: BitVector = BitVector::from_string("1001110100111010").unwrap();
+    /// let f: BitPolynomial = BitPolynomial::minimal_polynomial_of_sequence(&seq);
+    /// assert_eq!(f.to_string(), "1 + x^2 + x^3");
+    /// let seq: BitVector = BitVector::zeros(10);
+    /// let f: BitPolynomial = BitPolynomial::minimal_polynomial_of_sequence(&seq);
+    /// assert!(f.is_one());
+    /// let seq: BitVector = BitVector::ones(10);
+    /// let f: BitPolynomial = BitPolynomial::minimal_polynomial_of_sequence(&seq);
+    /// assert_eq!(f.to_string(), "1 + x");
+    /// ```
+    #[must_use]
+    pub fn minimal_polynomial_of_sequence<Src: BitStore<Word>>(seq: &Src) -> Self {
+        let n = seq.len();
+
+        // The current connection polynomial `c` and the one from before the last length change `b`.
+        let mut c: BitVector<Word> = BitVector::unit(0, n + 1);
+        let mut b: BitVector<Word> = BitVector::unit(0, n + 1);
+        let mut l = 0;
+        let mut m = 1;
+        for i in 0..n {
+            // The discrepancy between the next term and the term that the current recurrence predicts.
+            let mut d = seq.get(i);
+            for j in 1..=l {
+                d ^= c[j] && seq.get(i - j);
+            }
+            if !d {
+                m += 1;
+                continue;
+            }
+
+            // Fix the discrepancy by adding x^m b(x) to the connection polynomial, growing the recurrence if needed.
+            let previous = c.clone();
+            for j in b.set_bits() {
+                if j + m <= n {
+                    c.flip(j + m);
+                }
+            }
+            if 2 * l <= i {
+                l = i + 1 - l;
+                b = previous;
+                m = 1;
+            }
+            else {
+                m += 1;
+            }
+        }
+        Self::from_fn(l, |k| c[l - k])
+    }
+}
+
+/// Private helper methods for factoring bit-polynomials.
+impl<Word: Unsigned> BitPolynomial<Word> {
+    /// Returns the square-free decomposition of `self` as a list of `(factor, multiplicity)` pairs where each factor is
+    /// square-free and the factors are pairwise coprime.
+    fn square_free_factors(&self) -> Vec<(BitPolynomial<Word>, usize)> {
+        let mut result = Vec::new();
+        let mut c = self.gcd(&self.derivative());
+        let mut w = self.div_rem(&c).0;
+        let mut i = 1;
+        while w.degree() > 0 {
+            let y = w.gcd(&c);
+            let f = w.div_rem(&y).0;
+            if f.degree() > 0 {
+                result.push((f, i));
+            }
+            c = c.div_rem(&y).0;
+            w = y;
+            i += 1;
+        }
+
+        // Whatever is left is a perfect square in GF(2), so take its square root and recurse.
+        if c.degree() > 0 {
+            let root = Self::from_fn(c.degree() / 2, |i| c.coeffs[2 * i]);
+            for (f, m) in root.square_free_factors() {
+                result.push((f, 2 * m));
+            }
+        }
+        result
+    }
+
+    /// Splits a square-free `self` into a list of `(g, d)` pairs where `g` is the product of all the irreducible
+    /// factors of `self` with degree `d`.
+    fn distinct_degree_factors(&self) -> Vec<(BitPolynomial<Word>, usize)> {
+        let mut result = Vec::new();
+        let x = Self::x_to_the(1);
+        let mut f = self.clone();
+        f.make_monic();
+
+        // At step `d`, h(x) = x^(2^d) mod f(x) and x^(2^d) - x is the product of all irreducibles of degree dividing d.
+        let mut h = x.clone();
+        let mut d = 1;
+        while f.degree() >= 2 * d {
+            h = h.squared().div_rem(&f).1;
+            let g = f.gcd(&(&h + &x));
+            if g.degree() > 0 {
+                f = f.div_rem(&g).0;
+                h = h.div_rem(&f).1;
+                result.push((g, d));
+            }
+            d += 1;
+        }
+        if f.degree() > 0 {
+            let d = f.degree();
+            result.push((f, d));
+        }
+        result
+    }
+
+    /// Splits `self`, a square-free product of irreducible factors all of degree `d`, into those factors.
+    fn equal_degree_factors(&self, d: usize) -> Vec<BitPolynomial<Word>> {
+        let n = self.degree();
+        if n <= d {
+            return vec![self.clone()];
+        }
+
+        // For random a(x), the trace T(a) = a + a^2 + ... + a^(2^(d-1)) mod f(x) is 0 or 1 modulo each irreducible
+        // factor with equal probability. So gcd(f, T(a)) splits f(x) half the time.
+        // We use our own sequence of seeds so that factoring does not disturb the shared random number generator.
+        let mut seed = n as u64;
+        loop {
+            seed += 1;
+            let a = Self::random_seeded(n - 1, seed);
+            let mut s = a.clone();
+            let mut t = a.clone();
+            for _ in 1..d {
+                s = s.squared().div_rem(self).1;
+                t += &s;
+            }
+            let g = self.gcd(&t);
+            if g.degree() > 0 && g.degree() < n {
+                let h = self.div_rem(&g).0;
+                let mut result = g.equal_degree_factors(d);
+                result.extend(h.equal_degree_factors(d));
+                return result;
+            }
+        }
+    }
+
+    /// Returns the order of an irreducible polynomial `q(x)` with q(0) = 1 or `None` if its degree is more than 128.
+    fn irreducible_order(&self) -> Option<u128> {
+        // The order divides 2^d - 1 so we start there and remove prime factors for as long as we can.
+        let d = self.degree();
+        if d > 128 {
+            return None;
+        }
+        let n = if d == 128 { u128::MAX } else { (1u128 << d) - 1 };
+        let mut result = n;
+        for (p, _) in primes::factor(n) {
+            while result.is_multiple_of(p) && self.reduce_x_to_the_u128(result / p).is_one() {
+                result /= p;
+            }
+        }
+        Some(result)
+    }
+
+    /// Returns x^e mod `self` for an exponent `e` that may be larger than a `usize`.
+    fn reduce_x_to_the_u128(&self, e: u128) -> BitPolynomial<Word> {
+        if let Ok(e) = usize::try_from(e) {
+            return self.reduce_x_to_the(e);
+        }
+        let mut result = Self::one();
+        for i in (0..128 - e.leading_zeros()).rev() {
+            result = result.squared().div_rem(self).1;
+            if (e >> i) & 1 == 1 {
+                result.times_x_to_the(1);
+                result = result.div_rem(self).1;
+            }
+        }
+        result
+    }
+}
+
 // --------------------------------------------------------------------------------------------------------------------
 // The `Default` trait implementation for the `BitPolynomial` type.
 // --------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,184 @@
+//! [`PrimaryComponent`] is one piece of the primary decomposition of the space that a square bit-matrix acts on.
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitPolynomial,
+    BitVector,
+    Unsigned,
+};
+
+#[doc = include_str!("../docs/primary.md")]
+#[derive(Clone)]
+pub struct PrimaryComponent<Word: Unsigned = usize> {
+    // The irreducible factor p(x) of the characteristic polynomial that this component belongs to.
+    factor: BitPolynomial<Word>,
+
+    // The multiplicity of p(x) in the characteristic polynomial.
+    multiplicity: usize,
+
+    // The cyclic pieces as (e, u) pairs sorted by decreasing e. The minimal polynomial of A on the piece is p(x)^e.
+    cyclic: Vec<(usize, BitVector<Word>)>,
+
+    // A basis for the component as the rows of a bit-matrix. Each cyclic piece contributes a consecutive chain of
+    // rows.
+    basis: BitMatrix<Word>,
+}
+
+impl<Word: Unsigned> PrimaryComponent<Word> {
+    /// Crate-only constructor used by [`BitMatrix::primary_decomposition`] which does all the real work.
+    pub(crate) fn new(
+        factor: BitPolynomial<Word>, multiplicity: usize, cyclic: Vec<(usize, BitVector<Word>)>, basis: BitMatrix<Word>,
+    ) -> Self {
+        Self { factor, multiplicity, cyclic, basis }
+    }
+
+    /// Returns the irreducible factor `p(x)` of the characteristic polynomial that this component belongs to.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// let components = m.primary_decomposition();
+    /// assert_eq!(components[0].factor().to_string(), "1 + x");
+    /// ```
+    #[must_use]
+    pub fn factor(&self) -> &BitPolynomial<Word> { &self.factor }
+
+    /// Returns the multiplicity `e` of the irreducible factor `p(x)` in the characteristic polynomial.
+    ///
+    /// The component is the kernel of `p(A)^e` and has dimension `e * deg p(x)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// let components = m.primary_decomposition();
+    /// assert_eq!(components[0].multiplicity(), 3);
+    /// ```
+    #[must_use]
+    pub fn multiplicity(&self) -> usize { self.multiplicity }
+
+    /// Returns the multiplicity of the irreducible factor `p(x)` in the *minimal* polynomial of the bit-matrix.
+    ///
+    /// This is the smallest `k` such that the kernel of `p(A)^k` is the whole component.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// let components = m.primary_decomposition();
+    /// assert_eq!(components[0].exponent(), 1);
+    /// let m: BitMatrix = BitMatrix::from_string("110 011 001").unwrap();
+    /// let components = m.primary_decomposition();
+    /// assert_eq!(components[0].exponent(), 3);
+    /// ```
+    #[must_use]
+    pub fn exponent(&self) -> usize { self.cyclic.first().map_or(0, |(e, _)| *e) }
+
+    /// Returns the dimension of the component.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let top_row: BitVector = BitVector::from_string("0101").unwrap();
+    /// let m: BitMatrix = BitMatrix::companion(&top_row);
+    /// let components = m.primary_decomposition();
+    /// assert_eq!(components.len(), 1);
+    /// assert_eq!(components[0].dim(), 4);
+    /// ```
+    #[must_use]
+    pub fn dim(&self) -> usize { self.basis.rows() }
+
+    /// Returns a basis for the component as the rows of a bit-matrix.
+    ///
+    /// The basis is made up of one chain of vectors for each cyclic piece (see [`PrimaryComponent::cyclic_vectors`]).
+    /// If `P` is the transpose of the basis bit-matrix then `A.P = P.F` where `F` is the Frobenius matrix you get
+    /// from [`PrimaryComponent::top_rows`]. In other words, in this basis the bit-matrix acts on the component as a
+    /// block diagonal matrix of companion matrices for the elementary divisors.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: BitMatrix = BitMatrix::random(20, 20);
+    /// for component in A.primary_decomposition() {
+    ///     let P = component.basis().transposed();
+    ///     let F = BitMatrix::frobenius(&component.top_rows());
+    ///     assert_eq!(&A * &P, &P * &F);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn basis(&self) -> &BitMatrix<Word> { &self.basis }
+
+    /// Returns the cyclic pieces of the component as a list of `(e, u)` pairs sorted by decreasing `e`.
+    ///
+    /// The vector `u` is a *cyclic vector* for its piece, i.e. the piece is spanned by `u, A.u, A^2.u, ...`, and the
+    /// minimal polynomial of the bit-matrix on that piece is `p(x)^e`. The component is the direct sum of the pieces.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// let components = m.primary_decomposition();
+    /// let pieces = components[0].cyclic_vectors();
+    /// assert_eq!(pieces.len(), 3);
+    /// assert!(pieces.iter().all(|(e, _)| *e == 1));
+    /// ```
+    #[must_use]
+    pub fn cyclic_vectors(&self) -> &[(usize, BitVector<Word>)] { &self.cyclic }
+
+    /// Returns the *elementary divisors* `p(x)^e` for the cyclic pieces of the component in decreasing order.
+    ///
+    /// The product of the elementary divisors is `p(x)^m` where `m` is the multiplicity of `p(x)` in the
+    /// characteristic polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("110 010 001").unwrap();
+    /// let components = m.primary_decomposition();
+    /// let divisors = components[0].elementary_divisors();
+    /// assert_eq!(divisors.len(), 2);
+    /// assert_eq!(divisors[0].to_string(), "1 + x^2");
+    /// assert_eq!(divisors[1].to_string(), "1 + x");
+    /// ```
+    #[must_use]
+    pub fn elementary_divisors(&self) -> Vec<BitPolynomial<Word>> {
+        self.cyclic
+            .iter()
+            .map(|(e, _)| {
+                let mut divisor = BitPolynomial::one();
+                for _ in 0..*e {
+                    divisor = divisor.convolved_with(&self.factor);
+                }
+                divisor
+            })
+            .collect()
+    }
+
+    /// Returns the action of the bit-matrix on the component as a list of companion matrices in compact top-row form.
+    ///
+    /// There is one companion matrix for each elementary divisor. You can expand them into a full bit-matrix using
+    /// [`BitMatrix::frobenius`].
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("110 010 001").unwrap();
+    /// let components = m.primary_decomposition();
+    /// let top_rows = components[0].top_rows();
+    /// assert_eq!(top_rows.len(), 2);
+    /// assert_eq!(top_rows[0].to_string(), "01");
+    /// assert_eq!(top_rows[1].to_string(), "1");
+    /// ```
+    #[must_use]
+    pub fn top_rows(&self) -> Vec<BitVector<Word>> {
+        self.elementary_divisors()
+            .iter()
+            .map(|f| {
+                let d = f.degree();
+                BitVector::from_fn(d, |j| f.coeff(d - 1 - j))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,227 @@
+//! The `primes` module.
+//!
+//! Some simple number theory for `u128` values: greatest common divisors, primality testing, and factorisation.
+//! We need these to compute the multiplicative orders of bit-polynomials and bit-matrices. Those orders are divisors of
+//! numbers like `2^d - 1` which are comfortably handled by the methods here for any `d <= 128`.
+//! This module is private to the crate and is not exported.
+
+/// Crate-only function that returns the greatest common divisor of two integers where `gcd(0, 0) = 0`.
+pub(crate) fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Crate-only function that returns the least common multiple of two integers or `None` on overflow.
+pub(crate) fn lcm(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Crate-only function that returns the prime factorisation of `n > 0` as a sorted list of `(prime, exponent)` pairs.
+///
+/// The factorisation of 1 is the empty list.
+pub(crate) fn factor(n: u128) -> Vec<(u128, u32)> {
+    assert!(n > 0, "Cannot factor zero");
+
+    // Collect the prime factors with repeats, starting with trial division by some small primes.
+    let mut primes = Vec::new();
+    let mut n = n;
+    for p in SMALL_PRIMES {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+
+    // Anything left over is split using Pollard's rho method until we get down to prime pieces.
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            primes.push(m);
+            continue;
+        }
+        let d = pollard_rho(m);
+        stack.push(d);
+        stack.push(m / d);
+    }
+
+    // Gather the repeats into exponents.
+    primes.sort_unstable();
+    let mut result: Vec<(u128, u32)> = Vec::new();
+    for p in primes {
+        match result.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => result.push((p, 1)),
+        }
+    }
+    result
+}
+
+/// Crate-only function that returns `true` if `n` is a prime number.
+///
+/// We use the Miller-Rabin test with the first twenty primes as bases. That is deterministic for all `n < 3.3e24`.
+/// Above that bound the test is only probabilistic: a composite could in principle pass for all twenty bases, though
+/// none is known to do so.
+pub(crate) fn is_prime(n: u128) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in SMALL_PRIMES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    // Write n - 1 = d * 2^s where d is odd and then work in Montgomery form (n is odd at this point).
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mont = Montgomery::new(n);
+    let one = mont.to(1);
+    let minus_one = mont.to(n - 1);
+    'bases: for &a in &SMALL_PRIMES[..20] {
+        let mut x = mont.pow(mont.to(a), d);
+        if x == one || x == minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = mont.mul(x, x);
+            if x == minus_one {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Some small primes that we use for trial division and as Miller-Rabin bases.
+const SMALL_PRIMES: [u128; 25] =
+    [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+
+/// Returns a non-trivial divisor of the odd composite `n` using Brent's variant of Pollard's rho method.
+fn pollard_rho(n: u128) -> u128 {
+    // We batch up the differences and only take a gcd after every `BATCH` steps.
+    const BATCH: u64 = 128;
+
+    // The iteration is x -> x^2 + c mod n. If a choice of `c` fails we just try the next one.
+    // We work in Montgomery form throughout which is fine as multiplying by the unit R^-1 does not change any gcd.
+    let mont = Montgomery::new(n);
+    for c in 1.. {
+        let f = |x: u128| add_mod(mont.mul(x, x), c, n);
+        let (mut x, mut y, mut ys) = (2, 2, 2);
+        let (mut g, mut q, mut r) = (1, 1, 1);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mont.mul(q, x.abs_diff(y));
+                }
+                g = gcd(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+
+        // The batch may have overshot so backtrack one step at a time if needed.
+        if g == n {
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!("Pollard's rho method always finds a divisor eventually")
+}
+
+/// Returns `(a + b) mod m` for `a, b < m` without overflow.
+fn add_mod(a: u128, b: u128, m: u128) -> u128 { if a >= m - b { a - (m - b) } else { a + b } }
+
+/// Returns the full 256-bit product of `a` and `b` as a `(hi, lo)` pair of 128-bit words.
+fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a0, a1, b0, b1) = (a & MASK, a >> 64, b & MASK, b >> 64);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Montgomery arithmetic modulo an odd `m` with `R = 2^128`.
+///
+/// The Montgomery form of `a` is `a * R mod m` and the product of two numbers in that form is computed without any
+/// expensive 256-bit divisions.
+struct Montgomery {
+    m:     u128,
+    m_inv: u128,
+    r2:    u128,
+}
+
+impl Montgomery {
+    /// Returns the Montgomery arithmetic context for the odd modulus `m`.
+    fn new(m: u128) -> Self {
+        // Newton's iteration doubles the number of correct low bits in `inv` where m * inv = 1 mod 2^128 each time.
+        let mut inv = m;
+        for _ in 0..7 {
+            inv = inv.wrapping_mul(2u128.wrapping_sub(m.wrapping_mul(inv)));
+        }
+
+        // R mod m and then R^2 mod m by doubling 128 times.
+        let mut r2 = (u128::MAX % m + 1) % m;
+        for _ in 0..128 {
+            r2 = add_mod(r2, r2, m);
+        }
+        Self { m, m_inv: inv.wrapping_neg(), r2 }
+    }
+
+    /// Returns `(hi * 2^128 + lo) / R mod m` where we require `hi < m`.
+    fn reduce(&self, hi: u128, lo: u128) -> u128 {
+        let t = lo.wrapping_mul(self.m_inv);
+        let (t_hi, t_lo) = mul_wide(t, self.m);
+        let (_, carry) = lo.overflowing_add(t_lo);
+        let (s, c1) = hi.overflowing_add(t_hi);
+        let (s, c2) = s.overflowing_add(u128::from(carry));
+        if c1 || c2 || s >= self.m { s.wrapping_sub(self.m) } else { s }
+    }
+
+    /// Returns the Montgomery form of `a`.
+    fn to(&self, a: u128) -> u128 { self.mul(a % self.m, self.r2) }
+
+    /// Returns the product of two numbers in Montgomery form.
+    fn mul(&self, a: u128, b: u128) -> u128 {
+        let (hi, lo) = mul_wide(a, b);
+        self.reduce(hi, lo)
+    }
+
+    /// Returns `b^e` where `b` and the result are in Montgomery form.
+    fn pow(&self, mut b: u128, mut e: u128) -> u128 {
+        let mut result = self.to(1);
+        while e > 0 {
+            if e & 1 == 1 {
+                result = self.mul(result, b);
+            }
+            b = self.mul(b, b);
+            e >>= 1;
+        }
+        result
+    }
+}
@@ -0,0 +1,434 @@
+//! [`QuadraticForm`] is a quadratic form over GF(2) in `n` variables.
+#![allow(non_snake_case)]
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitStore,
+    BitVector,
+    Unsigned,
+};
+
+// Standard library imports.
+use std::fmt;
+
+#[doc = include_str!("../docs/quadratic.md")]
+#[derive(PartialEq, Eq, Clone)]
+pub struct QuadraticForm<Word: Unsigned = usize> {
+    // The upper triangular bit-matrix `U` with `q(x) = x^T.U.x`.
+    upper: BitMatrix<Word>,
+}
+
+// The pieces of a quadratic form in a basis that puts it into canonical form.
+struct Reduction<Word: Unsigned> {
+    // Hyperbolic pairs `(e, f)` with `q(e) = q(f) = 0` and `B(e, f) = 1`.
+    hyperbolic: Vec<(BitVector<Word>, BitVector<Word>)>,
+
+    // At most one anisotropic pair `(e, f)` with `q(e) = q(f) = 1` and `B(e, f) = 1`.
+    anisotropic: Option<(BitVector<Word>, BitVector<Word>)>,
+
+    // At most one vector `d` in the radical of `B` with `q(d) = 1`.
+    defect: Option<BitVector<Word>>,
+
+    // The rest of a basis for the radical of `B`, all with `q = 0`.
+    singular: Vec<BitVector<Word>>,
+}
+
+/// Constructors for quadratic forms.
+impl<Word: Unsigned> QuadraticForm<Word> {
+    /// Returns the quadratic form `q(x) = x^T.Q.x` for a square bit-matrix `Q`.
+    ///
+    /// Different bit-matrices can give the same form because `x_i x_j = x_j x_i`, so we store the unique upper
+    /// triangular bit-matrix `U` with `x^T.U.x = x^T.Q.x`. For `i < j`, element `U[i][j]` is the coefficient of
+    /// `x_i x_j`, and the diagonal element `U[i][i]` is the coefficient of `x_i^2`.
+    ///
+    /// # Panics
+    /// Panics if `Q` is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let Q: BitMatrix = BitMatrix::from_string("11 10").unwrap();
+    /// let q = QuadraticForm::new(&Q);
+    /// assert_eq!(q.to_string(), "x0^2");
+    /// assert_eq!(q.matrix().to_compact_binary_string(), "10 00");
+    /// ```
+    #[must_use]
+    pub fn new(Q: &BitMatrix<Word>) -> Self {
+        assert_eq!(Q.rows(), Q.cols(), "A quadratic form needs a square bit-matrix not {} x {}", Q.rows(), Q.cols());
+        let n = Q.rows();
+        let upper = BitMatrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Less => Q.get(i, j) ^ Q.get(j, i),
+            std::cmp::Ordering::Equal => Q.get(i, i),
+            std::cmp::Ordering::Greater => false,
+        });
+        Self { upper }
+    }
+
+    /// Returns the zero quadratic form in `n` variables.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let q: QuadraticForm = QuadraticForm::zero(4);
+    /// assert_eq!(q.dim(), 4);
+    /// assert_eq!(q.to_string(), "0");
+    /// ```
+    #[must_use]
+    pub fn zero(n: usize) -> Self { Self { upper: BitMatrix::zeros(n, n) } }
+
+    /// Returns a uniformly random quadratic form in `n` variables where the RNG is seeded to `seed`. A seed of `0`
+    /// indicates we should randomly seed the RNG.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let q1: QuadraticForm = QuadraticForm::random_seeded(10, 42);
+    /// let q2: QuadraticForm = QuadraticForm::random_seeded(10, 42);
+    /// assert_eq!(q1, q2);
+    /// ```
+    #[must_use]
+    pub fn random_seeded(n: usize, seed: u64) -> Self { Self { upper: BitMatrix::random_seeded(n, n, seed).upper() } }
+
+    /// Returns a uniformly random quadratic form in `n` variables.
+    ///
+    /// See the `random_seeded` method for a way to get reproducible results.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let q: QuadraticForm = QuadraticForm::random(10);
+    /// assert_eq!(q.dim(), 10);
+    /// ```
+    #[must_use]
+    pub fn random(n: usize) -> Self { Self::random_seeded(n, 0) }
+}
+
+/// Queries and evaluation for quadratic forms.
+impl<Word: Unsigned> QuadraticForm<Word> {
+    /// Returns the number of variables of the quadratic form.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let q: QuadraticForm = QuadraticForm::zero(4);
+    /// assert_eq!(q.dim(), 4);
+    /// ```
+    #[must_use]
+    pub fn dim(&self) -> usize { self.upper.rows() }
+
+    /// Returns a reference to the upper triangular bit-matrix `U` with `q(x) = x^T.U.x`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let q: QuadraticForm = QuadraticForm::random(10);
+    /// assert!(q.matrix().strictly_lower().none());
+    /// ```
+    #[must_use]
+    pub fn matrix(&self) -> &BitMatrix<Word> { &self.upper }
+
+    /// Returns the value `q(x)` of the quadratic form at a bit-store `x`.
+    ///
+    /// # Panics
+    /// Panics if `x` does not have one element for each variable.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let Q: BitMatrix = BitMatrix::from_string("110 001 001").unwrap();
+    /// let q = QuadraticForm::new(&Q);
+    /// assert_eq!(q.to_string(), "x0^2 + x0 x1 + x1 x2 + x2^2");
+    /// let x: BitVector = BitVector::from_string("111").unwrap();
+    /// assert_eq!(q.eval(&x), false);
+    /// let x: BitVector = BitVector::from_string("100").unwrap();
+    /// assert_eq!(q.eval(&x), true);
+    /// ```
+    #[must_use]
+    pub fn eval<Src: BitStore<Word>>(&self, x: &Src) -> bool {
+        assert_eq!(x.len(), self.dim(), "Length mismatch {} != {}", x.len(), self.dim());
+        let mut result = false;
+        for i in x.set_bits() {
+            result ^= self.upper[i].dot(x);
+        }
+        result
+    }
+
+    /// Returns the bit-matrix `B = U + U^T` of the alternating bilinear form associated with the quadratic form.
+    ///
+    /// This is the *polar form* `B(x, y) = q(x + y) + q(x) + q(y) = x^T.B.y`. It is symmetric with a zero diagonal.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let q: QuadraticForm = QuadraticForm::random(10);
+    /// let B = q.bilinear_form();
+    /// assert!(B.is_alternating());
+    /// let x: BitVector = BitVector::random(10);
+    /// let y: BitVector = BitVector::random(10);
+    /// assert_eq!(x.dot(&B.dot(&y)), q.eval(&(&x ^ &y)) ^ q.eval(&x) ^ q.eval(&y));
+    /// ```
+    #[must_use]
+    pub fn bilinear_form(&self) -> BitMatrix<Word> { &self.upper + &self.upper.transposed() }
+
+    /// Returns a basis for the *radical* of the associated bilinear form as the rows of a bit-matrix.
+    ///
+    /// The radical is the set of vectors `x` with `B(x, y) = 0` for every `y`. The quadratic form is additive on the
+    /// radical so it vanishes on a subspace of the radical of dimension at least one less.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let Q: BitMatrix = BitMatrix::from_string("010 000 001").unwrap();
+    /// let q = QuadraticForm::new(&Q);
+    /// assert_eq!(q.radical().to_compact_binary_string(), "001");
+    /// ```
+    #[must_use]
+    pub fn radical(&self) -> BitMatrix<Word> {
+        if self.dim() == 0 {
+            return BitMatrix::new();
+        }
+        self.bilinear_form().null_space()
+    }
+
+    /// Returns the rank of the quadratic form.
+    ///
+    /// This is the smallest number of variables you need to write the form in after a change of basis. It is the rank
+    /// of the bilinear form (which is always even) plus one if the quadratic form does not vanish on the radical.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let Q: BitMatrix = BitMatrix::from_string("010 000 001").unwrap();
+    /// let q = QuadraticForm::new(&Q);
+    /// assert_eq!(q.rank(), 3);
+    /// let Q: BitMatrix = BitMatrix::from_string("010 000 000").unwrap();
+    /// let q = QuadraticForm::new(&Q);
+    /// assert_eq!(q.rank(), 2);
+    /// ```
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        let reduction = self.reduce();
+        2 * (reduction.hyperbolic.len() + usize::from(reduction.anisotropic.is_some()))
+            + usize::from(reduction.defect.is_some())
+    }
+
+    /// Returns the *Arf invariant* of the quadratic form or `None` if the form does not vanish on the radical of its
+    /// bilinear form, in which case the Arf invariant is not defined.
+    ///
+    /// The Arf invariant is the value that the form takes most often on a complement of the radical. Two forms in the
+    /// same number of variables are equivalent under a change of basis if and only if they have the same rank and the
+    /// same Arf invariant.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// // x0 x1
+    /// let q = QuadraticForm::new(&BitMatrix::<usize>::from_string("01 00").unwrap());
+    /// assert_eq!(q.arf_invariant(), Some(false));
+    /// // x0^2 + x0 x1 + x1^2
+    /// let q = QuadraticForm::new(&BitMatrix::<usize>::from_string("11 01").unwrap());
+    /// assert_eq!(q.arf_invariant(), Some(true));
+    /// // x0^2
+    /// let q = QuadraticForm::new(&BitMatrix::<usize>::from_string("10 00").unwrap());
+    /// assert_eq!(q.arf_invariant(), None);
+    /// ```
+    #[must_use]
+    pub fn arf_invariant(&self) -> Option<bool> {
+        let reduction = self.reduce();
+        if reduction.defect.is_some() {
+            return None;
+        }
+        Some(reduction.anisotropic.is_some())
+    }
+
+    /// Returns `true` if this form is equivalent to `other` under an invertible change of variables.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let q: QuadraticForm = QuadraticForm::random(12);
+    /// let P: BitMatrix = BitMatrix::random_invertible(12);
+    /// assert!(q.is_equivalent_to(&q.transformed(&P)));
+    /// ```
+    #[must_use]
+    pub fn is_equivalent_to(&self, other: &QuadraticForm<Word>) -> bool {
+        self.dim() == other.dim() && self.rank() == other.rank() && self.arf_invariant() == other.arf_invariant()
+    }
+}
+
+/// Methods for changes of variables and the canonical form of a quadratic form.
+impl<Word: Unsigned> QuadraticForm<Word> {
+    /// Returns the quadratic form `y -> q(P.y)` that you get from the change of variables `x = P.y`.
+    ///
+    /// # Panics
+    /// Panics if `P` is not `n x n` where `n` is the number of variables.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let q: QuadraticForm = QuadraticForm::random(10);
+    /// let P: BitMatrix = BitMatrix::random(10, 10);
+    /// let y: BitVector = BitVector::random(10);
+    /// assert_eq!(q.transformed(&P).eval(&y), q.eval(&P.dot(&y)));
+    /// ```
+    #[must_use]
+    pub fn transformed(&self, P: &BitMatrix<Word>) -> Self {
+        assert_eq!(P.rows(), self.dim(), "Change of basis must have {} rows not {}", self.dim(), P.rows());
+        Self::new(&(&(&P.transposed() * &self.upper) * P))
+    }
+
+    /// Returns the canonical form of the quadratic form along with the change of basis that takes it there.
+    ///
+    /// By Dickson's theorem, every quadratic form is equivalent to exactly one of:
+    /// - `x0 x1 + x2 x3 + ... + x(2h-2) x(2h-1)` (a sum of *hyperbolic planes* with Arf invariant `0`).
+    /// - The same plus `x(2h-2)^2 + x(2h-1)^2` (the last plane is *anisotropic* and the Arf invariant is `1`).
+    /// - `x0 x1 + ... + x(2h-2) x(2h-1) + x(2h)^2` (the form does not vanish on the radical).
+    ///
+    /// The method returns that canonical form `c` and an invertible bit-matrix `P` with `q(P.y) = c(y)` for all `y`.
+    /// The columns of `P` are the new basis vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let q: QuadraticForm = QuadraticForm::random(12);
+    /// let (c, P) = q.canonical_form();
+    /// assert_eq!(q.transformed(&P), c);
+    /// assert!(P.inverse().is_some());
+    /// ```
+    #[must_use]
+    pub fn canonical_form(&self) -> (Self, BitMatrix<Word>) {
+        let n = self.dim();
+        let reduction = self.reduce();
+        let mut canonical = BitMatrix::zeros(n, n);
+        let mut basis = Vec::with_capacity(n);
+        for (e, f) in reduction.hyperbolic.into_iter().chain(reduction.anisotropic.clone()) {
+            canonical.set(basis.len(), basis.len() + 1, true);
+            basis.push(e);
+            basis.push(f);
+        }
+        if reduction.anisotropic.is_some() {
+            let k = basis.len();
+            canonical.set(k - 2, k - 2, true);
+            canonical.set(k - 1, k - 1, true);
+        }
+        if let Some(d) = reduction.defect {
+            canonical.set(basis.len(), basis.len(), true);
+            basis.push(d);
+        }
+        basis.extend(reduction.singular);
+
+        let P = BitMatrix::from_fn(n, n, |i, j| basis[j][i]);
+        (Self { upper: canonical }, P)
+    }
+
+    /// Returns a basis that splits the form into hyperbolic planes, at most one anisotropic plane, and the radical.
+    fn reduce(&self) -> Reduction<Word> {
+        let n = self.dim();
+        let B = self.bilinear_form();
+        let polar = |x: &BitVector<Word>, y: &BitVector<Word>| x.dot(&B.dot(y));
+
+        // A basis for the radical and the unit vectors for the non-pivot columns of its reduced echelon form which span
+        // a complement of it.
+        let radical = self.radical();
+        let mut is_pivot = vec![false; n];
+        for row in radical.row_space().row_iter() {
+            if let Some(p) = row.first_set() {
+                is_pivot[p] = true;
+            }
+        }
+        let complement: Vec<BitVector<Word>> = (0..n)
+            .filter(|&j| !is_pivot[j])
+            .map(|j| {
+                let mut v = BitVector::zeros(n);
+                v.set(j, true);
+                v
+            })
+            .collect();
+
+        // Symplectic Gram-Schmidt for the bilinear form on the complement where it is non-degenerate.
+        let (pairs, isotropic) = BitMatrix::symplectic_pairs(complement, &polar);
+        debug_assert!(isotropic.is_empty(), "The form is non-degenerate on the complement");
+
+        // The form is additive on the radical so it vanishes on all but at most one basis vector.
+        let mut defect: Option<BitVector<Word>> = None;
+        let mut singular = Vec::new();
+        for r in radical.row_iter() {
+            if !self.eval(r) {
+                singular.push(r.clone());
+            }
+            else if let Some(d) = &defect {
+                singular.push(r ^ d);
+            }
+            else {
+                defect = Some(r.clone());
+            }
+        }
+
+        // Make as many pairs hyperbolic as we can.
+        let mut hyperbolic = Vec::with_capacity(pairs.len());
+        let mut anisotropic: Option<(BitVector<Word>, BitVector<Word>)> = None;
+        for (e, f) in pairs {
+            match (self.eval(&e), self.eval(&f)) {
+                (false, false) => hyperbolic.push((e, f)),
+                (false, true) => hyperbolic.push((e.clone(), &f ^ &e)),
+                (true, false) => hyperbolic.push((&e ^ &f, f)),
+                (true, true) => {
+                    if let Some(d) = &defect {
+                        // Adding the defect vector fixes an anisotropic plane without changing the bilinear form.
+                        hyperbolic.push((&e ^ d, &f ^ d));
+                    }
+                    else if let Some((e1, f1)) = anisotropic.take() {
+                        // Two anisotropic planes together are two hyperbolic planes.
+                        let u = &e1 ^ &e;
+                        let w = &f1 ^ &f;
+                        hyperbolic.push((u.clone(), &u ^ &f1));
+                        hyperbolic.push((w.clone(), &w ^ &e));
+                    }
+                    else {
+                        anisotropic = Some((e, f));
+                    }
+                },
+            }
+        }
+
+        Reduction { hyperbolic, anisotropic, defect, singular }
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// Trait implementations for quadratic forms.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// The `Display` trait implementation for a quadratic form shows it as a sum of monomials like `x0 x1 + x2^2`.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let q = QuadraticForm::new(&BitMatrix::<usize>::from_string("110 001 000").unwrap());
+/// assert_eq!(format!("{q}"), "x0^2 + x0 x1 + x1 x2");
+/// ```
+impl<Word: Unsigned> fmt::Display for QuadraticForm<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut terms = Vec::new();
+        for i in 0..self.dim() {
+            for j in self.upper[i].set_bits() {
+                if i == j {
+                    terms.push(format!("x{i}^2"));
+                }
+                else {
+                    terms.push(format!("x{i} x{j}"));
+                }
+            }
+        }
+        if terms.is_empty() {
+            return write!(f, "0");
+        }
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+/// The `Debug` trait implementation for a quadratic form is the same as the `Display` implementation.
+impl<Word: Unsigned> fmt::Debug for QuadraticForm<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{self}") }
+}
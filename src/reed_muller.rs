@@ -0,0 +1,252 @@
+//! [`ReedMuller`] is the Reed–Muller code `RM(r, m)` with Reed's majority-logic decoder and a fast Hadamard decoder.
+#![allow(non_snake_case)]
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitStore,
+    BitVector,
+    LinearCode,
+    Unsigned,
+};
+
+// Standard library imports.
+use std::fmt;
+
+#[doc = include_str!("../docs/reed_muller.md")]
+#[derive(Clone)]
+pub struct ReedMuller<Word: Unsigned = usize> {
+    // The largest degree of a monomial in the code.
+    r: usize,
+
+    // The number of variables, so the codewords have `2^m` elements.
+    m: usize,
+
+    // The monomials that label the rows of the generator matrix as bit-masks of their variables.
+    monomials: Vec<usize>,
+
+    // The code itself with the evaluations of the monomials as its generator matrix.
+    code: LinearCode<Word>,
+}
+
+/// Constructors for Reed–Muller codes.
+impl<Word: Unsigned> ReedMuller<Word> {
+    /// Returns the Reed–Muller code `RM(r, m)` whose codewords are the evaluations of the polynomials of degree at
+    /// most `r` in `m` variables.
+    ///
+    /// Element `j` of a codeword is the value of the polynomial at the point `x` with `x_i` the bit `i` of `j`. The
+    /// rows of the generator matrix are the evaluations of the monomials in order of degree and then in increasing
+    /// order of their variable bit-masks, so for `RM(1, m)` the rows are `1, x_0, ..., x_{m-1}`.
+    ///
+    /// # Panics
+    /// Panics if `r > m` or if `m` is too large for the codewords to be indexed by a `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let rm: ReedMuller = ReedMuller::new(1, 3);
+    /// assert_eq!((rm.n(), rm.k(), rm.d()), (8, 4, 4));
+    /// assert_eq!(rm.generator().to_compact_binary_string(), "11111111 01010101 00110011 00001111");
+    /// ```
+    #[must_use]
+    pub fn new(r: usize, m: usize) -> Self {
+        assert!(r <= m, "The degree {r} of a Reed–Muller code cannot be more than the number of variables {m}");
+        assert!(m < usize::BITS as usize, "A Reed–Muller code with {m} variables is too long");
+        let n = 1 << m;
+        let mut monomials = Vec::new();
+        for degree in 0..=r {
+            monomials.extend((0..n).filter(|s: &usize| s.count_ones() as usize == degree));
+        }
+        let G = BitMatrix::from_fn(monomials.len(), n, |i, j| j & monomials[i] == monomials[i]);
+        Self { r, m, monomials, code: LinearCode::from_generator(&G) }
+    }
+}
+
+/// Methods to query a Reed–Muller code.
+impl<Word: Unsigned> ReedMuller<Word> {
+    /// Returns the largest degree `r` of a monomial in the code.
+    #[must_use]
+    pub fn r(&self) -> usize { self.r }
+
+    /// Returns the number of variables `m`.
+    #[must_use]
+    pub fn m(&self) -> usize { self.m }
+
+    /// Returns the length `n = 2^m` of the codewords.
+    #[must_use]
+    pub fn n(&self) -> usize { 1 << self.m }
+
+    /// Returns the dimension `k` of the code, which is the number of monomials of degree at most `r`.
+    #[must_use]
+    pub fn k(&self) -> usize { self.monomials.len() }
+
+    /// Returns the minimum distance `d = 2^(m - r)` of the code.
+    #[must_use]
+    pub fn d(&self) -> usize { 1 << (self.m - self.r) }
+
+    /// Returns the monomials that label the rows of the generator matrix as bit-masks of their variables.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let rm: ReedMuller = ReedMuller::new(2, 3);
+    /// assert_eq!(rm.monomials(), &[0b000, 0b001, 0b010, 0b100, 0b011, 0b101, 0b110]);
+    /// ```
+    #[must_use]
+    pub fn monomials(&self) -> &[usize] { &self.monomials }
+
+    /// Returns the generator matrix whose rows are the evaluations of the monomials.
+    #[must_use]
+    pub fn generator(&self) -> &BitMatrix<Word> { self.code.generator() }
+
+    /// Returns the code as a [`LinearCode`].
+    #[must_use]
+    pub fn code(&self) -> &LinearCode<Word> { &self.code }
+
+    /// Returns the codeword for a message `u` with `k` elements, which is the evaluation of the polynomial with
+    /// coefficient `u[i]` for monomial `i`.
+    ///
+    /// # Panics
+    /// Panics if `u` does not have `k` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let rm: ReedMuller = ReedMuller::new(1, 3);
+    /// let c = rm.encode(&BitVector::<usize>::from_string("1010").unwrap());
+    /// assert_eq!(c.to_string(), "11001100");
+    /// ```
+    #[must_use]
+    pub fn encode<Src: BitStore<Word>>(&self, u: &Src) -> BitVector<Word> { self.code.encode(u) }
+}
+
+/// Methods to decode a Reed–Muller code.
+impl<Word: Unsigned> ReedMuller<Word> {
+    /// Decodes a received word with Reed's majority-logic algorithm and returns the message.
+    ///
+    /// The coefficients are found from the monomials of degree `r` down to degree zero. The coefficient of a monomial
+    /// `S` of degree `s` is the sum of the received word over any of the `2^(m - s)` sub-cubes where the variables not
+    /// in `S` are fixed, so each sub-cube casts a vote and the majority wins with ties going to zero. Once all the
+    /// coefficients of one degree are known their contribution is removed from the received word. Every error pattern
+    /// of weight less than `d / 2` is corrected.
+    ///
+    /// # Panics
+    /// Panics if `received` does not have `n` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let rm: ReedMuller = ReedMuller::new(2, 5);
+    /// let u: BitVector = BitVector::random_seeded(rm.k(), 42);
+    /// let mut received = rm.encode(&u);
+    /// received.flip(3);
+    /// received.flip(20);
+    /// received.flip(30);
+    /// assert_eq!(rm.majority_decode(&received), u);
+    /// ```
+    #[must_use]
+    pub fn majority_decode<Src: BitStore<Word>>(&self, received: &Src) -> BitVector<Word> {
+        let n = self.n();
+        assert_eq!(received.len(), n, "Word has {} elements but the code has length {n}", received.len());
+        let mut y = BitVector::from_store(received);
+        let mut u = BitVector::zeros(self.k());
+        let mut end = self.k();
+        for degree in (0..=self.r).rev() {
+            // The monomials of one degree are contiguous and come after all those of lower degree.
+            let start = self.monomials[..end].partition_point(|s| (s.count_ones() as usize) < degree);
+            for i in start..end {
+                let S = self.monomials[i];
+                let votes = (0..n).filter(|&b| b & S == 0).filter(|&b| sub_cube_sum(&y, b, S)).count();
+                u.set(i, 2 * votes > n >> degree);
+            }
+
+            // Remove the contribution of this degree so the next one sees a codeword of degree `degree - 1`.
+            for i in start..end {
+                if u[i] {
+                    y ^= &self.generator()[i];
+                }
+            }
+            end = start;
+        }
+        u
+    }
+
+    /// Decodes a received word for a first-order code `RM(1, m)` with the fast Walsh–Hadamard transform and returns
+    /// the message.
+    ///
+    /// The codewords of `RM(1, m)` are the affine functions `a + u.x` so the transform of `(-1)^y` at `u` measures the
+    /// agreement of the received word `y` with the codewords for `u` and `1 + u`. The largest transform value in
+    /// absolute size picks out a nearest codeword, which makes this a maximum-likelihood decoder. It takes
+    /// `O(n log n)` operations, and ties go to the smallest `u`.
+    ///
+    /// # Panics
+    /// Panics if the code is not first-order or if `received` does not have `n` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let rm: ReedMuller = ReedMuller::new(1, 5);
+    /// let u: BitVector = BitVector::from_string("110101").unwrap();
+    /// let mut received = rm.encode(&u);
+    /// for j in [1, 4, 9, 16, 17, 25, 31] {
+    ///     received.flip(j);
+    /// }
+    /// assert_eq!(rm.hadamard_decode(&received), u);
+    /// ```
+    #[must_use]
+    pub fn hadamard_decode<Src: BitStore<Word>>(&self, received: &Src) -> BitVector<Word> {
+        assert_eq!(self.r, 1, "The Hadamard decoder is for first-order codes not RM({}, {})", self.r, self.m);
+        let n = self.n();
+        assert_eq!(received.len(), n, "Word has {} elements but the code has length {n}", received.len());
+
+        // The in-place butterfly version of the transform.
+        let mut F: Vec<i64> = (0..n).map(|j| if received.get(j) { -1 } else { 1 }).collect();
+        let mut h = 1;
+        while h < n {
+            for start in (0..n).step_by(2 * h) {
+                for j in start..start + h {
+                    (F[j], F[j + h]) = (F[j] + F[j + h], F[j] - F[j + h]);
+                }
+            }
+            h *= 2;
+        }
+
+        // The message is the constant term followed by the bits of `u`.
+        let best = (0..n).fold(0, |best, j| if F[j].abs() > F[best].abs() { j } else { best });
+        BitVector::from_fn(self.k(), |i| if i == 0 { F[best] < 0 } else { best >> (i - 1) & 1 == 1 })
+    }
+}
+
+/// Returns the sum of `y` over the sub-cube of points `b + x` where `x` runs over the subsets of the bit-mask `S`.
+fn sub_cube_sum<Word: Unsigned>(y: &BitVector<Word>, b: usize, S: usize) -> bool {
+    let mut sum = y[b];
+    let mut x = S;
+    while x != 0 {
+        sum ^= y[b | x];
+        x = (x - 1) & S;
+    }
+    sum
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// Trait implementations for Reed–Muller codes.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// The `Display` trait implementation for a Reed–Muller code shows its name and parameters.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let rm: ReedMuller = ReedMuller::new(1, 5);
+/// assert_eq!(rm.to_string(), "RM(1, 5) [32, 6, 16] code");
+/// ```
+impl<Word: Unsigned> fmt::Display for ReedMuller<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RM({}, {}) [{}, {}, {}] code", self.r, self.m, self.n(), self.k(), self.d())
+    }
+}
+
+/// The `Debug` trait implementation for a Reed–Muller code is the same as the `Display` implementation.
+impl<Word: Unsigned> fmt::Debug for ReedMuller<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{self}") }
+}
@@ -0,0 +1,716 @@
+//! [`SparseBitMatrix`] is a bit-matrix that only stores the positions of its set elements.
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitStore,
+    BitVector,
+    StructuredGauss,
+    Unsigned,
+    rng,
+};
+
+// Standard library imports.
+use std::marker::PhantomData;
+
+#[doc = include_str!("../docs/sparse.md")]
+#[derive(PartialEq, Eq, Clone)]
+pub struct SparseBitMatrix<Word: Unsigned = usize> {
+    // The number of columns. The number of rows is one less than the length of `m_row_starts`.
+    m_cols: usize,
+
+    // Compressed sparse row storage: the set columns in row `i` are
+    // `m_col_indices[m_row_starts[i]..m_row_starts[i+1]]` and they are kept sorted.
+    m_row_starts:  Vec<usize>,
+    m_col_indices: Vec<usize>,
+
+    // The word type is only used for the dense bit-vectors and bit-matrices that we interact with.
+    m_word: PhantomData<Word>,
+}
+
+/// Constructors for sparse bit-matrices.
+impl<Word: Unsigned> SparseBitMatrix<Word> {
+    /// Constructs an empty sparse bit-matrix with no rows or columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::new();
+    /// assert_eq!(m.rows(), 0);
+    /// assert_eq!(m.cols(), 0);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self { Self::zeros(0, 0) }
+
+    /// Constructs a sparse bit-matrix with `r` rows and `c` columns and no set elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::zeros(3, 4);
+    /// assert_eq!(m.rows(), 3);
+    /// assert_eq!(m.cols(), 4);
+    /// assert_eq!(m.count_ones(), 0);
+    /// ```
+    #[must_use]
+    pub fn zeros(r: usize, c: usize) -> Self {
+        Self { m_cols: c, m_row_starts: vec![0; r + 1], m_col_indices: Vec::new(), m_word: PhantomData }
+    }
+
+    /// Constructs the `n x n` identity as a sparse bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::identity(3);
+    /// assert_eq!(m.to_bit_matrix(), BitMatrix::identity(3));
+    /// ```
+    #[must_use]
+    pub fn identity(n: usize) -> Self {
+        Self {
+            m_cols:        n,
+            m_row_starts:  (0..=n).collect(),
+            m_col_indices: (0..n).collect(),
+            m_word:        PhantomData,
+        }
+    }
+
+    /// Constructs an `r x c` sparse bit-matrix from a list of `(row, col)` positions of the set elements.
+    ///
+    /// Positions can be given in any order. A position that appears more than once is still just set.
+    ///
+    /// # Panics
+    /// Panics if any position is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::from_positions(2, 3, &[(1, 2), (0, 0), (1, 0), (1, 2)]);
+    /// assert_eq!(m.count_ones(), 3);
+    /// assert_eq!(m.to_bit_matrix().to_compact_binary_string(), "100 101");
+    /// ```
+    #[must_use]
+    pub fn from_positions(r: usize, c: usize, positions: &[(usize, usize)]) -> Self {
+        let mut rows = vec![Vec::new(); r];
+        for &(i, j) in positions {
+            assert!(i < r && j < c, "Position ({i}, {j}) is out of bounds for a {r}x{c} sparse bit-matrix");
+            rows[i].push(j);
+        }
+        Self::from_row_lists(c, rows)
+    }
+
+    /// Constructs a sparse bit-matrix from a dense bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let dense: BitMatrix = BitMatrix::random(10, 20);
+    /// let m: SparseBitMatrix = SparseBitMatrix::from_bit_matrix(&dense);
+    /// assert_eq!(m.count_ones(), dense.count_ones());
+    /// assert_eq!(m.to_bit_matrix(), dense);
+    /// ```
+    #[must_use]
+    pub fn from_bit_matrix(mat: &BitMatrix<Word>) -> Self {
+        let rows = (0..mat.rows()).map(|i| mat.row(i).set_bits().collect()).collect();
+        Self::from_row_lists(mat.cols(), rows)
+    }
+
+    /// Constructs a random `r x c` sparse bit-matrix with `k` set elements in each row at uniformly random positions,
+    /// where the RNG is seeded to `seed`. A seed of `0` indicates we should randomly seed the RNG.
+    ///
+    /// If `k > c` then every element in each row is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m1: SparseBitMatrix = SparseBitMatrix::random_seeded(1000, 1200, 20, 42);
+    /// let m2: SparseBitMatrix = SparseBitMatrix::random_seeded(1000, 1200, 20, 42);
+    /// assert_eq!(m1.count_ones(), 20_000);
+    /// assert!(m1 == m2);
+    /// ```
+    #[must_use]
+    pub fn random_seeded(r: usize, c: usize, k: usize, seed: u64) -> Self {
+        // If given a non-zero seed we need to save and restore the old seed.
+        let old_seed = rng::seed();
+        if seed != 0 {
+            rng::set_seed(seed);
+        }
+
+        // Rejection sampling is fine as `k` is normally much smaller than `c`.
+        let k = k.min(c);
+        let mut rows = Vec::with_capacity(r);
+        let mut seen = BitVector::<u64>::zeros(c);
+        for _ in 0..r {
+            let mut row = Vec::with_capacity(k);
+            while row.len() < k {
+                #[allow(clippy::cast_possible_truncation)]
+                let j = (rng::u64() % c as u64) as usize;
+                if !seen[j] {
+                    seen.set(j, true);
+                    row.push(j);
+                }
+            }
+            for &j in &row {
+                seen.set(j, false);
+            }
+            rows.push(row);
+        }
+
+        // Restore the old RNG seed.
+        if seed != 0 {
+            rng::set_seed(old_seed);
+        }
+        Self::from_row_lists(c, rows)
+    }
+
+    /// Constructs a random `r x c` sparse bit-matrix with `k` set elements in each row at uniformly random positions.
+    ///
+    /// The random number generator is seeded on first use with a scrambled version of the current time so you get
+    /// different outputs for each run. See the `random_seeded` method for a way to get reproducible results.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::random(100, 200, 5);
+    /// assert_eq!(m.count_ones(), 500);
+    /// ```
+    #[must_use]
+    pub fn random(r: usize, c: usize, k: usize) -> Self { Self::random_seeded(r, c, k, 0) }
+
+    /// Constructs a sparse bit-matrix with `c` columns from a list of the set columns in each row.
+    fn from_row_lists(c: usize, rows: Vec<Vec<usize>>) -> Self {
+        let mut row_starts = Vec::with_capacity(rows.len() + 1);
+        let mut col_indices = Vec::new();
+        row_starts.push(0);
+        for mut row in rows {
+            row.sort_unstable();
+            row.dedup();
+            col_indices.extend(row);
+            row_starts.push(col_indices.len());
+        }
+        Self { m_cols: c, m_row_starts: row_starts, m_col_indices: col_indices, m_word: PhantomData }
+    }
+}
+
+/// Queries and conversions for sparse bit-matrices.
+impl<Word: Unsigned> SparseBitMatrix<Word> {
+    /// Returns the number of rows in the sparse bit-matrix.
+    #[must_use]
+    pub fn rows(&self) -> usize { self.m_row_starts.len() - 1 }
+
+    /// Returns the number of columns in the sparse bit-matrix.
+    #[must_use]
+    pub fn cols(&self) -> usize { self.m_cols }
+
+    /// Returns the number of set elements in the sparse bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::identity(5);
+    /// assert_eq!(m.count_ones(), 5);
+    /// ```
+    #[must_use]
+    pub fn count_ones(&self) -> usize { self.m_col_indices.len() }
+
+    /// Returns the sorted column indices of the set elements in row `i`.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::from_positions(2, 5, &[(1, 4), (1, 2)]);
+    /// assert!(m.row(0).is_empty());
+    /// assert_eq!(m.row(1), &[2, 4]);
+    /// ```
+    #[must_use]
+    pub fn row(&self, i: usize) -> &[usize] {
+        assert!(i < self.rows(), "Row index {i} is out of bounds for a sparse bit-matrix with {} rows", self.rows());
+        &self.m_col_indices[self.m_row_starts[i]..self.m_row_starts[i + 1]]
+    }
+
+    /// Returns the element at row `i` and column `j`.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::from_positions(2, 5, &[(1, 4), (1, 2)]);
+    /// assert!(m.get(1, 4));
+    /// assert!(!m.get(0, 4));
+    /// ```
+    #[must_use]
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        assert!(j < self.cols(), "Column index {j} is out of bounds for a sparse bit-matrix with {} cols", self.cols());
+        self.row(i).binary_search(&j).is_ok()
+    }
+
+    /// Returns the transpose of the sparse bit-matrix.
+    ///
+    /// The compressed rows of the transpose are the compressed columns of the original.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::random(30, 50, 4);
+    /// assert_eq!(m.transposed().to_bit_matrix(), m.to_bit_matrix().transposed());
+    /// ```
+    #[must_use]
+    pub fn transposed(&self) -> Self {
+        let mut rows = vec![Vec::new(); self.cols()];
+        for i in 0..self.rows() {
+            for &j in self.row(i) {
+                rows[j].push(i);
+            }
+        }
+        Self::from_row_lists(self.rows(), rows)
+    }
+
+    /// Returns the sparse bit-matrix as a dense bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::from_positions(2, 2, &[(0, 1), (1, 0)]);
+    /// assert_eq!(m.to_bit_matrix().to_compact_binary_string(), "01 10");
+    /// ```
+    #[must_use]
+    pub fn to_bit_matrix(&self) -> BitMatrix<Word> {
+        let mut result = BitMatrix::zeros(self.rows(), self.cols());
+        for i in 0..self.rows() {
+            for &j in self.row(i) {
+                result.set(i, j, true);
+            }
+        }
+        result
+    }
+}
+
+/// Products of sparse bit-matrices with dense bit-vectors.
+impl<Word: Unsigned> SparseBitMatrix<Word> {
+    /// Returns the bit-vector `A.v` where `A` is this sparse bit-matrix.
+    ///
+    /// The cost is proportional to the number of set elements in `A`.
+    ///
+    /// # Panics
+    /// Panics if the length of `v` does not match the number of columns in `A`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::random(30, 50, 4);
+    /// let v: BitVector = BitVector::random(50);
+    /// assert_eq!(m.dot(&v), m.to_bit_matrix().dot(&v));
+    /// ```
+    #[must_use]
+    pub fn dot<Src: BitStore<Word>>(&self, v: &Src) -> BitVector<Word> {
+        assert_eq!(v.len(), self.cols(), "Length mismatch {} != {}", v.len(), self.cols());
+        BitVector::from_fn(self.rows(), |i| self.row(i).iter().fold(false, |sum, &j| sum ^ v.get(j)))
+    }
+
+    /// Returns the bit-vector `A^T.v` (equivalently `v.A`) where `A` is this sparse bit-matrix.
+    ///
+    /// The cost is proportional to the number of set elements in `A` and there is no need to form the transpose.
+    ///
+    /// # Panics
+    /// Panics if the length of `v` does not match the number of rows in `A`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::random(30, 50, 4);
+    /// let v: BitVector = BitVector::random(30);
+    /// assert_eq!(m.transpose_dot(&v), m.to_bit_matrix().transposed().dot(&v));
+    /// ```
+    #[must_use]
+    pub fn transpose_dot<Src: BitStore<Word>>(&self, v: &Src) -> BitVector<Word> {
+        assert_eq!(v.len(), self.rows(), "Length mismatch {} != {}", v.len(), self.rows());
+        let mut result = BitVector::zeros(self.cols());
+        for i in v.set_bits() {
+            for &j in self.row(i) {
+                result.flip(j);
+            }
+        }
+        result
+    }
+
+    /// Returns the block product `A.V` where `V` is a tall block of 64 column vectors with one word per row.
+    fn block_dot(&self, v: &[u64]) -> Vec<u64> {
+        (0..self.rows()).map(|i| self.row(i).iter().fold(0, |sum, &j| sum ^ v[j])).collect()
+    }
+
+    /// Returns the block product `A^T.A.V` where `V` is a tall block of 64 column vectors with one word per row.
+    ///
+    /// Row `i` of `A` contributes `(A.V)_i` to each of the rows of the result listed in row `i` so we need just one
+    /// pass through the set elements.
+    fn block_gram_dot(&self, v: &[u64]) -> Vec<u64> {
+        let mut result = vec![0; self.cols()];
+        for i in 0..self.rows() {
+            let row = self.row(i);
+            let w = row.iter().fold(0, |sum, &j| sum ^ v[j]);
+            for &j in row {
+                result[j] ^= w;
+            }
+        }
+        result
+    }
+}
+
+/// Methods to find null vectors of a sparse bit-matrix and to solve sparse linear systems.
+impl<Word: Unsigned> SparseBitMatrix<Word> {
+    /// Returns some linearly independent vectors in the null space of this sparse bit-matrix `A` as the rows of a
+    /// bit-matrix, i.e. vectors `x` with `A.x = 0`.
+    ///
+    /// For large matrices we use Montgomery's *block Lanczos* algorithm which only needs products of `A` and `A^T` with
+    /// blocks of 64 vectors at a time. It finds up to 64 null vectors per run, which is usually far fewer than the full
+    /// nullity. That is exactly what is needed for factoring and index calculus where any few dependencies will do.
+    /// The result can be empty if the null space is trivial or, very rarely, if the algorithm fails to converge.
+    ///
+    /// For small matrices with at most a few hundred columns we just use dense Gaussian elimination and the result is
+    /// a complete basis for the null space.
+    ///
+    /// A matrix with no rows has every vector as a null vector. We return the first few unit vectors in that case: all
+    /// of them if there are at most a few hundred columns and otherwise the first 64.
+    ///
+    /// # Note
+    /// If `A` is `m x n` with `w` set elements then each iteration costs `O(w + 64 n)` word operations and there are
+    /// about `n / 63` iterations. Memory use is a handful of `n`-word blocks on top of the storage for `A` itself.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::random_seeded(2000, 2100, 10, 42);
+    /// let null = m.null_vectors();
+    /// assert!(null.rows() > 0);
+    /// for i in 0..null.rows() {
+    ///     assert!(m.dot(&null[i]).none());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn null_vectors(&self) -> BitMatrix<Word> {
+        // With no equations at all every vector is a null vector, so we return a bounded number of unit vectors.
+        if self.rows() == 0 {
+            let k = if self.cols() <= DENSE_LIMIT { self.cols() } else { 64 };
+            return BitMatrix::from_fn(k, self.cols(), |i, j| i == j);
+        }
+
+        // Small problems are best handled by dense elimination.
+        if self.cols() <= DENSE_LIMIT {
+            return self.to_bit_matrix().null_space();
+        }
+
+        // Block Lanczos can fail on an unlucky random start so we give it a few goes.
+        for _ in 0..ATTEMPTS {
+            if let Some((x, v)) = self.block_lanczos() {
+                return self.null_vectors_from(&x, &v);
+            }
+        }
+        BitMatrix::new()
+    }
+
+    /// Returns a solution `x` to the system `A.x = b` where `A` is this sparse bit-matrix, or `None` if we cannot find
+    /// one.
+    ///
+    /// We look for a null vector of the augmented matrix `[A | b]` that has a 1 in its final slot. For small matrices
+    /// this is exact and `None` means the system is inconsistent. For large matrices `None` almost always means the
+    /// system is inconsistent, but the block Lanczos method can, with small probability, miss a solution.
+    ///
+    /// # Panics
+    /// Panics if the length of `b` does not match the number of rows in `A`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::random_seeded(1500, 1500, 10, 42);
+    /// let x0: BitVector = BitVector::random(1500);
+    /// let b = m.dot(&x0);
+    /// let x = m.solve(&b).unwrap();
+    /// assert_eq!(m.dot(&x), b);
+    /// ```
+    #[must_use]
+    pub fn solve<Src: BitStore<Word>>(&self, b: &Src) -> Option<BitVector<Word>> {
+        assert_eq!(b.len(), self.rows(), "Length mismatch {} != {}", b.len(), self.rows());
+
+        // With no equations at all anything is a solution.
+        if self.rows() == 0 {
+            return Some(BitVector::zeros(self.cols()));
+        }
+
+        // Append `b` as an extra column.
+        let n = self.cols();
+        let mut col_indices = Vec::with_capacity(self.count_ones() + b.count_ones());
+        let mut row_starts = Vec::with_capacity(self.rows() + 1);
+        row_starts.push(0);
+        for i in 0..self.rows() {
+            col_indices.extend_from_slice(self.row(i));
+            if b.get(i) {
+                col_indices.push(n);
+            }
+            row_starts.push(col_indices.len());
+        }
+        let augmented: Self = Self {
+            m_cols:        n + 1,
+            m_row_starts:  row_starts,
+            m_col_indices: col_indices,
+            m_word:        PhantomData,
+        };
+
+        // Any null vector with a 1 in the last slot gives a solution.
+        let null = augmented.null_vectors();
+        (0..null.rows()).find(|&i| null[i][n]).map(|i| BitVector::from_fn(n, |j| null[i][j]))
+    }
+
+    /// Returns the structured Gaussian elimination solver for this sparse bit-matrix and the passed r.h.s. vector `b`.
+    ///
+    /// The solver shrinks the sparse system to a much smaller dense core which it hands to
+    /// [`BitGauss`](crate::BitGauss). Unlike [`SparseBitMatrix::solve`] the result is exact for any size of problem
+    /// as long as the core fits in memory.
+    ///
+    /// # Panics
+    /// Panics if the length of `b` does not match the number of rows in `A`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = SparseBitMatrix::random_seeded(1500, 1500, 5, 42);
+    /// let b = m.dot(&BitVector::random(1500));
+    /// let solver = m.structured_solver_for(&b);
+    /// assert!(solver.core().rows() < 1500);
+    /// assert_eq!(m.dot(&solver.x().unwrap()), b);
+    /// ```
+    #[must_use]
+    pub fn structured_solver_for<Src: BitStore<Word>>(&self, b: &Src) -> StructuredGauss<Word> {
+        StructuredGauss::new(self, b)
+    }
+
+    /// Runs Montgomery's block Lanczos iteration on the symmetric matrix `B = A^T.A`.
+    ///
+    /// We start with a random block `Y`, set `V_0 = B.Y` and then build a sequence of blocks `V_i` that are mutually
+    /// `B`-orthogonal, accumulating `X = Y + sum V_i.W_i^-1.V_i^T.V_0` as we go. When the iteration stops `B.X` is in
+    /// the span of the final block `V_m`. We return `X` and `V_m` or `None` if the iteration broke down.
+    ///
+    /// See P. L. Montgomery, "A Block Lanczos Algorithm for Finding Dependencies over GF(2)", EUROCRYPT 1995.
+    fn block_lanczos(&self) -> Option<(Vec<u64>, Vec<u64>)> {
+        let n = self.cols();
+        let identity: Block = std::array::from_fn(|i| 1 << i);
+
+        // Index 0 holds the current values, indices 1 & 2 the values from one and two steps back.
+        let mut x: Vec<u64> = (0..n).map(|_| rng::u64()).collect();
+        let v0 = self.block_gram_dot(&x);
+        let mut v = [v0.clone(), vec![0; n], vec![0; n]];
+        let mut w_inv: [Block; 3] = [[0; 64]; 3];
+        let mut vt_a_v: [Block; 2] = [[0; 64]; 2];
+        let mut vt_a2_v: [Block; 2] = [[0; 64]; 2];
+        let mut last_s: Vec<usize> = (0..64).collect();
+        let mut last_mask = u64::MAX;
+
+        // Each step uses up close to 64 dimensions so this is a generous upper bound on the number of steps.
+        for _ in 0..n / 32 + 32 {
+            let mut v_next = self.block_gram_dot(&v[0]);
+            vt_a_v[0] = inner_product(&v[0], &v_next);
+            vt_a2_v[0] = inner_product(&v_next, &v_next);
+            if vt_a_v[0].iter().all(|&w| w == 0) {
+                let [v_last, ..] = v;
+                return Some((x, v_last));
+            }
+
+            // Pick the columns S_i of V_i that we keep and the inverse W_i^-1 of the non-singular part of V_i^T.B.V_i.
+            let (w, s) = select_columns(&vt_a_v[0], &last_s)?;
+            if s.is_empty() {
+                return None;
+            }
+            w_inv[0] = w;
+            let mask = s.iter().fold(0u64, |m, &c| m | 1 << c);
+
+            // D = I - W_i^-1 (V_i^T.B^2.V_i S_i S_i^T + V_i^T.B.V_i).
+            let d: Block = std::array::from_fn(|i| (vt_a2_v[0][i] & mask) ^ vt_a_v[0][i]);
+            let mut d = block_mul(&w_inv[0], &d);
+            d.iter_mut().zip(&identity).for_each(|(a, b)| *a ^= b);
+
+            // E = -W_(i-1)^-1 V_i^T.B.V_i S_i S_i^T.
+            let mut e = block_mul(&w_inv[1], &vt_a_v[0]);
+            e.iter_mut().for_each(|a| *a &= mask);
+
+            // F = -W_(i-2)^-1 (I - V_(i-1)^T.B.V_(i-1) W_(i-1)^-1)
+            //                 (V_(i-1)^T.B^2.V_(i-1) S_(i-1) S_(i-1)^T + V_(i-1)^T.B.V_(i-1)) S_i S_i^T.
+            let mut f = block_mul(&vt_a_v[1], &w_inv[1]);
+            f.iter_mut().zip(&identity).for_each(|(a, b)| *a ^= b);
+            let f = block_mul(&w_inv[2], &f);
+            let g: Block = std::array::from_fn(|i| ((vt_a2_v[1][i] & last_mask) ^ vt_a_v[1][i]) & mask);
+            let f = block_mul(&f, &g);
+
+            // V_(i+1) = B.V_i S_i S_i^T + V_i D + V_(i-1) E + V_(i-2) F.
+            v_next.iter_mut().for_each(|a| *a &= mask);
+            mul_add(&v[0], &d, &mut v_next);
+            mul_add(&v[1], &e, &mut v_next);
+            mul_add(&v[2], &f, &mut v_next);
+
+            // X += V_i W_i^-1 V_i^T V_0.
+            let t = block_mul(&w_inv[0], &inner_product(&v[0], &v0));
+            mul_add(&v[0], &t, &mut x);
+
+            // Shift everything along one step.
+            v.rotate_right(1);
+            v[0] = v_next;
+            w_inv[2] = w_inv[1];
+            w_inv[1] = w_inv[0];
+            vt_a_v[1] = vt_a_v[0];
+            vt_a2_v[1] = vt_a2_v[0];
+            last_s = s;
+            last_mask = mask;
+        }
+        None
+    }
+
+    /// Returns the linearly independent vectors in the span of the 128 columns of `X` and `V` that `A` sends to zero.
+    fn null_vectors_from(&self, x: &[u64], v: &[u64]) -> BitMatrix<Word> {
+        // Find the combinations of the columns of `[A.X | A.V]` that vanish by eliminating on the rows of
+        // `[(A.X | A.V)^T | I]`. Rows that end up zero on the left carry the combinations on the right.
+        let m = self.rows();
+        let ax = self.block_dot(x);
+        let av = self.block_dot(v);
+        let mut work: BitMatrix<u64> = BitMatrix::from_fn(128, m + 128, |j, i| match i {
+            i if i < m && j < 64 => (ax[i] >> j) & 1 == 1,
+            i if i < m => (av[i] >> (j - 64)) & 1 == 1,
+            i => i - m == j,
+        });
+        let _ = work.to_echelon_form();
+
+        // Apply each combination to the columns of `[X | V]`.
+        let mut rows = Vec::new();
+        for j in 0..128 {
+            if work[j].first_set().is_some_and(|p| p >= m) {
+                let lo = (0..64).fold(0u64, |tag, k| tag | u64::from(work[j][m + k]) << k);
+                let hi = (0..64).fold(0u64, |tag, k| tag | u64::from(work[j][m + 64 + k]) << k);
+                rows.push(BitVector::from_fn(x.len(), |k| ((x[k] & lo) ^ (v[k] & hi)).count_ones() % 2 == 1));
+            }
+        }
+        let mut candidates = BitMatrix::zeros(rows.len(), x.len());
+        for (i, row) in rows.into_iter().enumerate() {
+            candidates[i] = row;
+        }
+        candidates.row_space()
+    }
+}
+
+// ---------------------------------------------------------------------------------------------------------------------
+// The `Default` trait implementation for a sparse bit-matrix.
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// The `Default` trait implementation for a sparse bit-matrix.
+impl<Word: Unsigned> Default for SparseBitMatrix<Word> {
+    /// The default constructor creates an empty sparse bit-matrix with no rows or columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: SparseBitMatrix = Default::default();
+    /// assert_eq!(m.rows(), 0);
+    /// ```
+    fn default() -> Self { Self::new() }
+}
+
+// ---------------------------------------------------------------------------------------------------------------------
+// Private helpers for the block Lanczos method.
+// ---------------------------------------------------------------------------------------------------------------------
+
+/// Matrices with at most this many columns are handled with dense Gaussian elimination.
+const DENSE_LIMIT: usize = 256;
+
+/// The number of times we restart block Lanczos from a fresh random block before giving up.
+const ATTEMPTS: usize = 5;
+
+/// A 64 x 64 bit-matrix where bit `j` of word `i` is the element in row `i` and column `j`.
+type Block = [u64; 64];
+
+/// Returns the row vector `x` times the 64 x 64 bit-matrix `m`.
+fn row_times(mut x: u64, m: &Block) -> u64 {
+    let mut result = 0;
+    while x != 0 {
+        result ^= m[x.trailing_zeros() as usize];
+        x &= x - 1;
+    }
+    result
+}
+
+/// Returns the product of two 64 x 64 bit-matrices.
+fn block_mul(a: &Block, b: &Block) -> Block { std::array::from_fn(|i| row_times(a[i], b)) }
+
+/// Returns the 64 x 64 bit-matrix `V^T.W` for two tall blocks `V` and `W`.
+///
+/// Rather than handle the bits of each word of `V` one at a time, we bucket the words of `W` by each byte of `V`.
+fn inner_product(v: &[u64], w: &[u64]) -> Block {
+    let mut buckets = [[0u64; 256]; 8];
+    for (&a, &b) in v.iter().zip(w) {
+        for (k, bucket) in buckets.iter_mut().enumerate() {
+            bucket[((a >> (8 * k)) & 0xFF) as usize] ^= b;
+        }
+    }
+    std::array::from_fn(|i| {
+        let (k, bit) = (i / 8, i % 8);
+        (0..256).filter(|x| x & (1 << bit) != 0).fold(0, |sum, x| sum ^ buckets[k][x])
+    })
+}
+
+/// Adds the product of the tall block `V` and the 64 x 64 bit-matrix `m` to the tall block `out`.
+///
+/// We precompute the products of `m` with all 256 values of each byte so each word of `V` needs just eight lookups.
+fn mul_add(v: &[u64], m: &Block, out: &mut [u64]) {
+    let mut tables = [[0u64; 256]; 8];
+    for (k, table) in tables.iter_mut().enumerate() {
+        for x in 1..256 {
+            table[x] = table[x & (x - 1)] ^ m[8 * k + x.trailing_zeros() as usize];
+        }
+    }
+    for (o, &a) in out.iter_mut().zip(v) {
+        *o ^= tables.iter().enumerate().fold(0, |sum, (k, table)| sum ^ table[((a >> (8 * k)) & 0xFF) as usize]);
+    }
+}
+
+/// Returns the columns `S_i` to keep at the current step of block Lanczos along with `W_i^-1`.
+///
+/// We invert as much of `T = V_i^T.B.V_i` as we can by Gauss-Jordan elimination on `[T | I]`, where columns that were
+/// not kept at the previous step are tried first, as Montgomery requires. Returns `None` if the iteration has broken
+/// down.
+fn select_columns(t: &Block, last_s: &[usize]) -> Option<(Block, Vec<usize>)> {
+    let mut m: [[u64; 2]; 64] = std::array::from_fn(|i| [t[i], 1 << i]);
+    let last_mask = last_s.iter().fold(0u64, |mask, &c| mask | 1 << c);
+    let order: Vec<usize> = (0..64).filter(|&c| last_mask & (1 << c) == 0).chain(last_s.iter().copied()).collect();
+
+    let mut s = Vec::with_capacity(64);
+    for i in 0..64 {
+        let c = order[i];
+        let mask = 1u64 << c;
+
+        // Look for a pivot in column `c` of `T` and if there is one clear the rest of that column.
+        if let Some(j) = (i..64).find(|&j| m[order[j]][0] & mask != 0) {
+            m.swap(c, order[j]);
+            let pivot = m[c];
+            for &r in &order {
+                if r != c && m[r][0] & mask != 0 {
+                    m[r][0] ^= pivot[0];
+                    m[r][1] ^= pivot[1];
+                }
+            }
+            s.push(c);
+            continue;
+        }
+
+        // Otherwise use the right half to clear column `c` of the inverse and then drop the pivot row entirely.
+        let j = (i..64).find(|&j| m[order[j]][1] & mask != 0)?;
+        m.swap(c, order[j]);
+        let pivot = m[c];
+        for &r in &order {
+            if r != c && m[r][1] & mask != 0 {
+                m[r][0] ^= pivot[0];
+                m[r][1] ^= pivot[1];
+            }
+        }
+        m[c] = [0, 0];
+    }
+    Some((std::array::from_fn(|i| m[i][1]), s))
+}
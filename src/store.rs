@@ -7,6 +7,7 @@ use crate::{
     UnsetBits,
     Unsigned,
     Words,
+    parallel,
     rng,
 };
 
@@ -401,9 +402,7 @@ pub trait BitStore<Word: Unsigned>: Sized {
     /// assert_eq!(v.to_string(), "0000000000");
     /// ```
     fn flip_all(&mut self) -> &mut Self {
-        for word_index in 0..self.words() {
-            self.set_word(word_index, !self.word(word_index));
-        }
+        parallel::map_words(self, |word: Word| !word);
         self
     }
 
@@ -750,13 +749,7 @@ pub trait BitStore<Word: Unsigned>: Sized {
     /// v.set_all(true);
     /// assert_eq!(v.count_ones(), 12);
     /// ```
-    fn count_ones(&self) -> usize {
-        let mut count = 0;
-        for i in 0..self.words() {
-            count += self.word(i).count_ones() as usize;
-        }
-        count
-    }
+    fn count_ones(&self) -> usize { parallel::sum_words(self, |word: Word| word.count_ones() as usize) }
 
     /// Returns the number of unset bits in the store.
     ///
@@ -807,6 +800,8 @@ pub trait BitStore<Word: Unsigned>: Sized {
     /// assert_eq!(v.trailing_zeros(), 27);
     /// v.set(0, true);
     /// assert_eq!(v.trailing_zeros(), 26);
+    /// let v: BitVector<u8> = BitVector::unit(0, 16);
+    /// assert_eq!(v.trailing_zeros(), 15);
     /// ```
     fn trailing_zeros(&self) -> usize {
         if self.is_empty() {
@@ -814,7 +809,7 @@ pub trait BitStore<Word: Unsigned>: Sized {
         }
         // The last occupied word may have some unused bits that we need to subtract.
         let last_word = self.words() - 1;
-        let unused_bits = Word::UBITS - (self.len() % Word::UBITS);
+        let unused_bits = (Word::UBITS - self.len() % Word::UBITS) % Word::UBITS;
         for i in (0..=last_word).rev() {
             if self.word(i) != Word::ZERO {
                 return (last_word - i) * Word::UBITS + self.word(i).leading_zeros() as usize - unused_bits;
@@ -1467,6 +1462,38 @@ pub trait BitStore<Word: Unsigned>: Sized {
         sum.count_ones() % 2 == 1
     }
 
+    /// Returns the symplectic inner product of this bit-store with another bit-store of the same even length.
+    ///
+    /// A bit-store of length `2n` is split into halves `(x|z)`, where `x` is the first `n` elements and `z` is the last
+    /// `n` elements, and the symplectic inner product is:
+    /// ```text
+    /// <u, v> = u_x * v_z + u_z * v_x
+    /// ```
+    /// This is the form preserved by the symplectic bit-matrices (see [`crate::BitMatrix::is_symplectic`]). In quantum
+    /// computing `(x|z)` is the binary representation of a Pauli operator and the symplectic inner product is `0` if
+    /// and only if two Pauli operators commute.
+    ///
+    /// # Panics
+    /// Panics if the lengths of `self` and `rhs` do not match or if the length is odd.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let u: BitVector = BitVector::from_string("10 01").unwrap();
+    /// let v: BitVector = BitVector::from_string("11 00").unwrap();
+    /// assert_eq!(u.symplectic_dot(&v), true);
+    /// assert_eq!(u.symplectic_dot(&u), false);
+    /// ```
+    fn symplectic_dot<Rhs: BitStore<Word>>(&self, rhs: &Rhs) -> bool {
+        assert_eq!(self.len(), rhs.len(), "Length mismatch {} != {}", self.len(), rhs.len());
+        assert!(self.len().is_multiple_of(2), "The symplectic inner product needs an even length not {}", self.len());
+        let n = self.len() / 2;
+        if n == 0 {
+            return false;
+        }
+        self.slice(0..n).dot(&rhs.slice(n..2 * n)) ^ self.slice(n..2 * n).dot(&rhs.slice(0..n))
+    }
+
     /// Returns the convolution of this bit-store and another bit-store as a new bit-vector.
     ///
     /// The *convolution* of any two vector-like objects `u` & `v` is defined as:
@@ -1896,6 +1923,53 @@ pub trait BitStore<Word: Unsigned>: Sized {
         result
     }
 
+    // ----------------------------------------------------------------------------------------------------------------
+    // Associated methods for Gray codes.
+    // ----------------------------------------------------------------------------------------------------------------
+
+    /// Returns a new bit-vector that is the reflected binary Gray code of this bit-store.
+    ///
+    /// The bit-store is read as the binary number `x = v[0] + 2 v[1] + 4 v[2] + ...` (the same order that
+    /// [`BitVector::from_unsigned`] uses), and the result is the number `x ^ (x >> 1)` as a bit-vector of the same
+    /// length. In vector-order, element `i` of the result is `v[i] ^ v[i+1]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// for x in 0..16_u8 {
+    ///     let v: BitVector<u8> = BitVector::from_unsigned(x);
+    ///     assert_eq!(v.to_gray(), BitVector::from_unsigned(x ^ (x >> 1)));
+    ///     assert_eq!(v.to_gray().from_gray(), v);
+    /// }
+    /// ```
+    #[must_use]
+    fn to_gray(&self) -> BitVector<Word> { self.xor(&self.left_shifted(1)) }
+
+    /// Returns a new bit-vector that inverts [`BitStore::to_gray`] so it decodes a reflected binary Gray code.
+    ///
+    /// In vector-order, element `i` of the result is the XOR of the elements `v[i], v[i+1], ..., v[n-1]`. We compute
+    /// those suffix sums a word at a time with `log2(n)` shift and XOR passes.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let g: BitVector = BitVector::from_string("0001").unwrap();
+    /// assert_eq!(g.from_gray().to_string(), "1111");
+    /// let v: BitVector = BitVector::random_seeded(100, 42);
+    /// assert_eq!(v.to_gray().from_gray(), v);
+    /// ```
+    #[must_use]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_gray(&self) -> BitVector<Word> {
+        let mut result: BitVector<Word> = BitVector::from_store(self);
+        let mut shift = 1;
+        while shift < result.len() {
+            result ^= &result.left_shifted(shift);
+            shift *= 2;
+        }
+        result
+    }
+
     // ----------------------------------------------------------------------------------------------------------------
     // Associated methods to perform bitwise operations between stores.
     // ----------------------------------------------------------------------------------------------------------------
@@ -1916,10 +1990,7 @@ pub trait BitStore<Word: Unsigned>: Sized {
     /// ```
     fn xor_eq<Rhs: BitStore<Word>>(&mut self, rhs: &Rhs) {
         assert_eq!(self.len(), rhs.len(), "Length mismatch {} != {}", self.len(), rhs.len());
-        for i in 0..self.words() {
-            let word = self.word(i) ^ rhs.word(i);
-            self.set_word(i, word);
-        }
+        parallel::zip_words(self, rhs, |a: Word, b: Word| a ^ b);
     }
 
     /// Returns a new bit-vector that is the result of XOR'ing this bit-store with another.
@@ -1959,10 +2030,7 @@ pub trait BitStore<Word: Unsigned>: Sized {
     /// ```
     fn and_eq<Rhs: BitStore<Word>>(&mut self, rhs: &Rhs) {
         assert_eq!(self.len(), rhs.len(), "Length mismatch {} != {}", self.len(), rhs.len());
-        for i in 0..self.words() {
-            let word = self.word(i) & rhs.word(i);
-            self.set_word(i, word);
-        }
+        parallel::zip_words(self, rhs, |a: Word, b: Word| a & b);
     }
 
     /// Returns a new bit-vector that is the result of AND'ing this bit-store with another.
@@ -2002,10 +2070,7 @@ pub trait BitStore<Word: Unsigned>: Sized {
     /// ```
     fn or_eq<Rhs: BitStore<Word>>(&mut self, rhs: &Rhs) {
         assert_eq!(self.len(), rhs.len(), "Length mismatch {} != {}", self.len(), rhs.len());
-        for i in 0..self.words() {
-            let word = self.word(i) | rhs.word(i);
-            self.set_word(i, word);
-        }
+        parallel::zip_words(self, rhs, |a: Word, b: Word| a | b);
     }
 
     /// Returns a new bit-vector that is the result of OR'ing this bit-store with another.
@@ -0,0 +1,354 @@
+//! [`StructuredGauss`] reduces a large sparse linear system over GF(2) to a much smaller dense one.
+
+#![allow(non_snake_case)]
+
+// Crate types.
+use crate::{
+    BitGauss,
+    BitMatrix,
+    BitStore,
+    BitVector,
+    SparseBitMatrix,
+    Unsigned,
+};
+
+// Standard library imports.
+use std::{
+    cmp::Reverse,
+    collections::{
+        BTreeSet,
+        BinaryHeap,
+    },
+};
+
+#[doc = include_str!("../docs/structured.md")]
+pub struct StructuredGauss<Word: Unsigned = usize> {
+    // The number of unknowns in the full system.
+    cols: usize,
+
+    // The dense system that is left after all the sparse eliminations.
+    core:     BitMatrix<Word>,
+    core_rhs: BitVector<Word>,
+
+    // The columns of the full system that survive into the core, in core order.
+    core_cols: Vec<usize>,
+
+    // The eliminations in the order they were made. Each is a column `j`, the other columns in the pivot row at the
+    // time, and the right-hand side of the pivot row at the time. The unknown `x_j` is the sum of the rest.
+    pivots: Vec<(usize, Vec<usize>, bool)>,
+}
+
+/// Constructors.
+impl<Word: Unsigned> StructuredGauss<Word> {
+    /// Performs structured Gaussian elimination on the sparse system `A.x = b` using a default Markowitz cost limit.
+    ///
+    /// See [`StructuredGauss::with_max_cost`] for the details.
+    ///
+    /// # Panics
+    /// Panics if `A` and `b` have a different number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: SparseBitMatrix = SparseBitMatrix::random_seeded(2000, 2000, 5, 42);
+    /// let b = A.dot(&BitVector::random(2000));
+    /// let sge: StructuredGauss = StructuredGauss::new(&A, &b);
+    /// assert!(sge.core().rows() < 1000);
+    /// let x = sge.x().unwrap();
+    /// assert_eq!(A.dot(&x), b);
+    /// ```
+    #[must_use]
+    pub fn new<Src: BitStore<Word>>(A: &SparseBitMatrix<Word>, b: &Src) -> Self {
+        Self::with_max_cost(A, b, DEFAULT_MAX_COST)
+    }
+
+    /// Performs structured Gaussian elimination on the sparse system `A.x = b` and keeps what is left as a dense core.
+    ///
+    /// At each step we pick a pivot `(r, j)` with the smallest *Markowitz cost* `(w_r - 1) * (w_j - 1)` where `w_r` is
+    /// the number of set elements in row `r` and `w_j` the number in column `j`. The unknown `x_j` is then written
+    /// in terms of the others using equation `r` and eliminated from every other equation. That covers the classic
+    /// steps in turn:
+    ///
+    /// - A column with a single set element costs nothing to remove along with its row.
+    /// - A row with a single set element fixes its unknown, which costs nothing to substitute everywhere else.
+    /// - A column with two set elements merges its two rows into one at a cost of the weight of the pivot row.
+    ///
+    /// We stop once the cheapest pivot costs more than `max_cost` and the remaining rows and columns become the dense
+    /// core system. Larger values of `max_cost` give smaller but denser cores.
+    ///
+    /// # Panics
+    /// Panics if `A` and `b` have a different number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: SparseBitMatrix = SparseBitMatrix::random_seeded(500, 500, 6, 42);
+    /// let b: BitVector = BitVector::zeros(500);
+    /// let sge0: StructuredGauss = StructuredGauss::with_max_cost(&A, &b, 0);
+    /// let sge1: StructuredGauss = StructuredGauss::with_max_cost(&A, &b, 50);
+    /// assert!(sge1.core().rows() <= sge0.core().rows());
+    /// ```
+    #[must_use]
+    pub fn with_max_cost<Src: BitStore<Word>>(A: &SparseBitMatrix<Word>, b: &Src, max_cost: usize) -> Self {
+        assert_eq!(A.rows(), b.len(), "The matrix and vector must have the same number of rows");
+
+        // Working copies of the rows (as sorted column lists), the columns (as sets of rows), and the right-hand side.
+        let mut rows: Vec<Vec<usize>> = (0..A.rows()).map(|i| A.row(i).to_vec()).collect();
+        let mut cols: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); A.cols()];
+        for (i, row) in rows.iter().enumerate() {
+            for &j in row {
+                cols[j].insert(i);
+            }
+        }
+        let mut rhs: Vec<bool> = (0..A.rows()).map(|i| b.get(i)).collect();
+        let mut row_done = vec![false; A.rows()];
+        let mut col_done = vec![false; A.cols()];
+
+        // A min-heap of candidate columns keyed on their Markowitz cost. Costs change as we go so entries can be stale.
+        // We push a fresh entry whenever a column might have got cheaper and re-check an entry when it is popped.
+        let mut heap = BinaryHeap::new();
+        for j in 0..A.cols() {
+            push_candidate(&mut heap, &rows, &cols, j, max_cost);
+        }
+        for row in &rows {
+            push_singleton(&mut heap, row);
+        }
+
+        let mut pivots = Vec::new();
+        while let Some(Reverse((cost, j))) = heap.pop() {
+            if cost > max_cost {
+                break;
+            }
+            if col_done[j] {
+                continue;
+            }
+            let Some((current, r)) = cheapest_pivot(&rows, &cols[j])
+            else {
+                continue;
+            };
+            if current > cost {
+                heap.push(Reverse((current, j)));
+                continue;
+            }
+
+            // Retire the pivot row and then eliminate column `j` from every other row using it.
+            let pivot_row = std::mem::take(&mut rows[r]);
+            for &k in &pivot_row {
+                cols[k].remove(&r);
+            }
+            let others: Vec<usize> = cols[j].iter().copied().collect();
+            let mut touched = BTreeSet::from_iter(pivot_row.iter().copied());
+            for s in others {
+                rows[s] = symmetric_difference(&rows[s], &pivot_row);
+                for &k in &pivot_row {
+                    if !cols[k].remove(&s) {
+                        cols[k].insert(s);
+                    }
+                }
+                rhs[s] ^= rhs[r];
+                touched.extend(rows[s].iter().copied());
+                push_singleton(&mut heap, &rows[s]);
+            }
+            for k in touched {
+                push_candidate(&mut heap, &rows, &cols, k, max_cost);
+            }
+            row_done[r] = true;
+            col_done[j] = true;
+            pivots.push((j, pivot_row.into_iter().filter(|&k| k != j).collect(), rhs[r]));
+        }
+
+        // What is left is the core. Rows that are now `0 = 0` carry no information and are dropped.
+        let core_cols: Vec<usize> = (0..A.cols()).filter(|&j| !col_done[j]).collect();
+        let core_rows: Vec<usize> =
+            (0..A.rows()).filter(|&i| !row_done[i] && (!rows[i].is_empty() || rhs[i])).collect();
+        let mut index = vec![usize::MAX; A.cols()];
+        for (c, &j) in core_cols.iter().enumerate() {
+            index[j] = c;
+        }
+        let mut core = BitMatrix::zeros(core_rows.len(), core_cols.len());
+        for (c, &i) in core_rows.iter().enumerate() {
+            for &j in &rows[i] {
+                core.set(c, index[j], true);
+            }
+        }
+        let core_rhs = BitVector::from_fn(core_rows.len(), |c| rhs[core_rows[c]]);
+        Self { cols: A.cols(), core, core_rhs, core_cols, pivots }
+    }
+}
+
+/// Queries and solutions.
+impl<Word: Unsigned> StructuredGauss<Word> {
+    /// Returns the dense core bit-matrix that is left after the sparse eliminations.
+    ///
+    /// Column `c` of the core is column `core_cols()[c]` of the original system. If every equation gets eliminated the
+    /// core is empty and the unknowns in `core_cols()` are all free.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: SparseBitMatrix = SparseBitMatrix::identity(100);
+    /// let b: BitVector = BitVector::ones(100);
+    /// let sge: StructuredGauss = StructuredGauss::new(&A, &b);
+    /// assert_eq!(sge.core().rows(), 0);
+    /// assert_eq!(sge.eliminated_count(), 100);
+    /// ```
+    #[must_use]
+    pub fn core(&self) -> &BitMatrix<Word> { &self.core }
+
+    /// Returns the right-hand side of the dense core system.
+    #[must_use]
+    pub fn core_rhs(&self) -> &BitVector<Word> { &self.core_rhs }
+
+    /// Returns the columns of the original system that make up the columns of the dense core.
+    #[must_use]
+    pub fn core_cols(&self) -> &[usize] { &self.core_cols }
+
+    /// Returns the number of unknowns that were eliminated by the sparse steps.
+    #[must_use]
+    pub fn eliminated_count(&self) -> usize { self.pivots.len() }
+
+    /// Returns the solution `x` of the full system that goes with a solution `y` of the core system.
+    ///
+    /// The core unknowns are copied over and then the eliminated unknowns are recovered in reverse order.
+    ///
+    /// # Panics
+    /// Panics if `y` does not have one element for each column of the core.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: SparseBitMatrix = SparseBitMatrix::random_seeded(300, 320, 5, 42);
+    /// let b = A.dot(&BitVector::random(320));
+    /// let sge: StructuredGauss = StructuredGauss::new(&A, &b);
+    /// let y = BitGauss::new(sge.core(), sge.core_rhs()).x().unwrap();
+    /// assert_eq!(A.dot(&sge.lift(&y)), b);
+    /// ```
+    #[must_use]
+    pub fn lift(&self, y: &BitVector<Word>) -> BitVector<Word> { self.lift_with(y, true) }
+
+    /// Returns a random solution to the full system `A.x = b`, or `None` if the system is inconsistent.
+    ///
+    /// The dense core is solved with [`BitGauss`] and the solution is lifted back to the full system.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: SparseBitMatrix = SparseBitMatrix::from_positions(2, 2, &[(0, 0), (1, 0)]);
+    /// let b: BitVector = BitVector::from_string("10").unwrap();
+    /// let sge: StructuredGauss = StructuredGauss::new(&A, &b);
+    /// assert!(sge.x().is_none());
+    /// ```
+    #[must_use]
+    pub fn x(&self) -> Option<BitVector<Word>> {
+        // `BitGauss` wants a non-empty matrix so we handle the degenerate cores here.
+        let y = match (self.core.rows(), self.core_cols.len()) {
+            (0, n) => BitVector::random(n),
+            (_, 0) if self.core_rhs.any() => return None,
+            (_, 0) => BitVector::new(),
+            _ => BitGauss::new(&self.core, &self.core_rhs).x()?,
+        };
+        Some(self.lift(&y))
+    }
+
+    /// Returns a basis for the null space of the full matrix `A` as the rows of a bit-matrix.
+    ///
+    /// The null space of `A` is the lift of the null space of the core so this is complete, unlike the handful of
+    /// null vectors you get from [`SparseBitMatrix::null_vectors`] for large problems.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let A: SparseBitMatrix = SparseBitMatrix::random_seeded(300, 340, 5, 42);
+    /// let b: BitVector = BitVector::zeros(300);
+    /// let sge: StructuredGauss = StructuredGauss::new(&A, &b);
+    /// let kernel = sge.kernel_basis();
+    /// assert_eq!(kernel.rows(), A.to_bit_matrix().nullity());
+    /// for i in 0..kernel.rows() {
+    ///     assert!(A.dot(&kernel[i]).none());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn kernel_basis(&self) -> BitMatrix<Word> {
+        let core_kernel = match (self.core.rows(), self.core_cols.len()) {
+            (_, 0) => return BitMatrix::new(),
+            (0, n) => BitMatrix::identity(n),
+            _ => self.core.null_space(),
+        };
+        let mut result = BitMatrix::zeros(core_kernel.rows(), self.cols);
+        for i in 0..core_kernel.rows() {
+            result[i] = self.lift_with(&core_kernel[i], false);
+        }
+        result
+    }
+
+    /// Lifts a core solution `y` to the full system, using the right-hand side or treating it as zero.
+    fn lift_with(&self, y: &BitVector<Word>, use_rhs: bool) -> BitVector<Word> {
+        assert_eq!(y.len(), self.core_cols.len(), "Core solution has length {} not {}", y.len(), self.core_cols.len());
+        let mut x = BitVector::zeros(self.cols);
+        for (c, &j) in self.core_cols.iter().enumerate() {
+            x.set(j, y[c]);
+        }
+        for (j, others, rhs) in self.pivots.iter().rev() {
+            let value = others.iter().fold(use_rhs && *rhs, |sum, &k| sum ^ x[k]);
+            x.set(*j, value);
+        }
+        x
+    }
+}
+
+/// The default limit on the Markowitz cost of the pivots that we are prepared to make.
+const DEFAULT_MAX_COST: usize = 256;
+
+/// Pushes column `j` onto the heap of candidate pivot columns if it might have a pivot that is cheap enough.
+///
+/// Any pivot in a column with `w` set elements costs at least `w - 1` unless the pivot row is a singleton, so heavy
+/// columns are skipped here and we rely on [`push_singleton`] to catch their singleton rows. That saves rescanning the
+/// heavy columns every time one of their rows changes.
+fn push_candidate(
+    heap: &mut BinaryHeap<Reverse<(usize, usize)>>, rows: &[Vec<usize>], cols: &[BTreeSet<usize>], j: usize,
+    max_cost: usize,
+) {
+    if cols[j].len() <= max_cost + 1
+        && let Some((cost, _)) = cheapest_pivot(rows, &cols[j])
+    {
+        heap.push(Reverse((cost, j)));
+    }
+}
+
+/// Pushes the column of a row with a single set element onto the heap of candidate pivot columns at zero cost.
+fn push_singleton(heap: &mut BinaryHeap<Reverse<(usize, usize)>>, row: &[usize]) {
+    if let [j] = row {
+        heap.push(Reverse((0, *j)));
+    }
+}
+
+/// Returns the cheapest pivot in a column as a `(Markowitz cost, row)` pair, or `None` if the column is empty.
+fn cheapest_pivot(rows: &[Vec<usize>], col: &BTreeSet<usize>) -> Option<(usize, usize)> {
+    let r = col.iter().copied().min_by_key(|&r| rows[r].len())?;
+    Some(((rows[r].len() - 1) * (col.len() - 1), r))
+}
+
+/// Returns the sorted symmetric difference of two sorted lists, which is their sum as sparse bit-vectors.
+fn symmetric_difference(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => {
+                result.push(a[i]);
+                i += 1;
+            },
+            std::cmp::Ordering::Greater => {
+                result.push(b[j]);
+                j += 1;
+            },
+            std::cmp::Ordering::Equal => {
+                i += 1;
+                j += 1;
+            },
+        }
+    }
+    result.extend_from_slice(&a[i..]);
+    result.extend_from_slice(&b[j..]);
+    result
+}
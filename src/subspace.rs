@@ -0,0 +1,613 @@
+//! [`BitSubspace`] is a subspace of the bit-vectors of some fixed length and [`SubspaceElements`] iterates over its
+//! elements or cosets.
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitStore,
+    BitVector,
+    GrayCode,
+    Unsigned,
+};
+
+// Standard library imports.
+use std::fmt;
+
+#[doc = include_str!("../docs/subspace.md")]
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct BitSubspace<Word: Unsigned = usize> {
+    // The number of elements in each of the bit-vectors.
+    len: usize,
+
+    // The basis vectors in reduced row echelon form sorted by increasing pivot, where a pivot is the first set
+    // element.
+    rows: Vec<BitVector<Word>>,
+
+    // The pivot of each basis vector.
+    pivots: Vec<usize>,
+}
+
+/// Constructors for subspaces.
+impl<Word: Unsigned> BitSubspace<Word> {
+    /// Returns the zero subspace of the bit-vectors with `len` elements --- the subspace that is just the zero vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::zero(5);
+    /// assert_eq!(s.vector_len(), 5);
+    /// assert_eq!(s.dim(), 0);
+    /// ```
+    #[must_use]
+    pub fn zero(len: usize) -> Self { Self { len, rows: Vec::new(), pivots: Vec::new() } }
+
+    /// Returns the whole space of the bit-vectors with `len` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::full(5);
+    /// assert_eq!(s.dim(), 5);
+    /// assert!(s.contains(&BitVector::ones(5)));
+    /// ```
+    #[must_use]
+    pub fn full(len: usize) -> Self {
+        Self { len, rows: (0..len).map(|i| BitVector::unit(i, len)).collect(), pivots: (0..len).collect() }
+    }
+
+    /// Returns the subspace spanned by the rows of a bit-matrix.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix has no rows, as it then has no columns either and the length of the bit-vectors is
+    /// lost. Use [`BitSubspace::zero`] or [`BitSubspace::from_vectors`] for those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("1100 0110 1010").unwrap();
+    /// let s = BitSubspace::from_matrix(&m);
+    /// assert_eq!(s.dim(), 2);
+    /// assert_eq!(s.to_matrix().to_compact_binary_string(), "1010 0110");
+    /// ```
+    #[must_use]
+    pub fn from_matrix(m: &BitMatrix<Word>) -> Self {
+        assert!(m.rows() > 0, "Bit-matrix must have at least one row to fix the length of the bit-vectors");
+        Self::from_rows(m.cols(), m.row_iter().cloned().collect())
+    }
+
+    /// Returns the subspace spanned by some bit-vectors with `len` elements.
+    ///
+    /// # Panics
+    /// Panics if any of the bit-vectors has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let u: BitVector = BitVector::from_string("1100").unwrap();
+    /// let v: BitVector = BitVector::from_string("0110").unwrap();
+    /// let s = BitSubspace::from_vectors(4, &[u, v]);
+    /// assert_eq!(s.dim(), 2);
+    /// assert!(s.contains(&BitVector::from_string("1010").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn from_vectors(len: usize, vectors: &[BitVector<Word>]) -> Self {
+        for v in vectors {
+            assert_eq!(v.len(), len, "Bit-vector has {} elements but the subspace is for length {}", v.len(), len);
+        }
+        Self::from_rows(len, vectors.to_vec())
+    }
+
+    /// Returns the kernel of a bit-matrix `M`, i.e., the subspace of bit-vectors `x` where `M.x = 0`.
+    ///
+    /// This is the orthogonal complement of the subspace spanned by the rows of `M`.
+    ///
+    /// # Panics
+    /// Panics if the bit-matrix has no rows. The kernel is then the whole space which is [`BitSubspace::full`].
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("1100 0110").unwrap();
+    /// let s = BitSubspace::kernel_of(&m);
+    /// assert_eq!(s.dim(), 2);
+    /// assert!(s.vectors().iter().all(|x| m.dot(x).none()));
+    /// ```
+    #[must_use]
+    pub fn kernel_of(m: &BitMatrix<Word>) -> Self { Self::from_matrix(m).orthogonal_complement() }
+
+    /// Returns the subspace spanned by `rows` after putting them in reduced row echelon form.
+    fn from_rows(len: usize, rows: Vec<BitVector<Word>>) -> Self {
+        if rows.is_empty() || len == 0 {
+            return Self::zero(len);
+        }
+        let mut m = BitMatrix::zeros(rows.len(), len);
+        for (dst, src) in m.row_iter_mut().zip(rows) {
+            *dst = src;
+        }
+
+        // The non-zero rows of the reduced echelon form are the canonical basis and they come first.
+        let pivots: Vec<usize> = m.to_reduced_echelon_form().set_bits().collect();
+        let rows = m.row_iter().take(pivots.len()).cloned().collect();
+        Self { len, rows, pivots }
+    }
+}
+
+/// Methods to query a subspace.
+impl<Word: Unsigned> BitSubspace<Word> {
+    /// Returns the number of elements in each of the bit-vectors in the subspace.
+    #[must_use]
+    pub fn vector_len(&self) -> usize { self.len }
+
+    /// Returns the dimension of the subspace.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("1100 0110 1010").unwrap();
+    /// assert_eq!(BitSubspace::from_matrix(&m).dim(), 2);
+    /// ```
+    #[must_use]
+    pub fn dim(&self) -> usize { self.rows.len() }
+
+    /// Returns the codimension of the subspace, i.e., `vector_len() - dim()`.
+    ///
+    /// This is the dimension of the orthogonal complement and of the quotient space.
+    #[must_use]
+    pub fn codim(&self) -> usize { self.len - self.dim() }
+
+    /// Returns `true` if this is the zero subspace.
+    #[must_use]
+    pub fn is_zero(&self) -> bool { self.rows.is_empty() }
+
+    /// Returns `true` if this is the whole space.
+    #[must_use]
+    pub fn is_full(&self) -> bool { self.rows.len() == self.len }
+
+    /// Returns the canonical basis for the subspace, which is in reduced row echelon form.
+    ///
+    /// The *pivot* of a basis vector is its first set element. The vectors are sorted by increasing pivot and each
+    /// pivot is set in just one of the vectors. Every subspace has exactly one basis like this.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("1100 1110").unwrap();
+    /// let s = BitSubspace::from_matrix(&m);
+    /// assert_eq!(s.vectors()[0].to_string(), "1100");
+    /// assert_eq!(s.vectors()[1].to_string(), "0010");
+    /// assert_eq!(s.pivots(), [0, 2]);
+    /// ```
+    #[must_use]
+    pub fn vectors(&self) -> &[BitVector<Word>] { &self.rows }
+
+    /// Returns the pivot of each canonical basis vector which is the index of its first set element.
+    #[must_use]
+    pub fn pivots(&self) -> &[usize] { &self.pivots }
+
+    /// Returns the canonical basis vectors as the rows of a new bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("1100 0110 1010").unwrap();
+    /// let s = BitSubspace::from_matrix(&m);
+    /// assert_eq!(s.to_matrix(), m.row_space());
+    /// ```
+    #[must_use]
+    pub fn to_matrix(&self) -> BitMatrix<Word> { BitMatrix::from_fn(self.dim(), self.len, |i, j| self.rows[i][j]) }
+
+    /// Returns `true` if the bit-store `v` is in the subspace.
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// assert!(s.contains(&BitVector::<usize>::from_string("1010").unwrap()));
+    /// assert!(!s.contains(&BitVector::<usize>::from_string("0001").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn contains<Src: BitStore<Word>>(&self, v: &Src) -> bool { self.coset_representative(v).none() }
+
+    /// Returns `true` if this subspace is contained in `other`.
+    ///
+    /// # Panics
+    /// Panics if the subspaces are for bit-vectors of different lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// let t: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1010").unwrap());
+    /// assert!(t.is_subspace_of(&s));
+    /// assert!(!s.is_subspace_of(&t));
+    /// ```
+    #[must_use]
+    pub fn is_subspace_of(&self, other: &BitSubspace<Word>) -> bool {
+        assert_eq!(self.len, other.len, "Subspaces are for lengths {} and {}", self.len, other.len);
+        self.dim() <= other.dim() && self.rows.iter().all(|row| other.contains(row))
+    }
+
+    /// Returns the coordinates of the bit-store `v` with respect to the canonical basis or `None` if `v` is not in the
+    /// subspace.
+    ///
+    /// The basis is in reduced row echelon form, so coordinate `i` is just the element of `v` at the `i`'th pivot.
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// let v: BitVector = BitVector::from_string("1010").unwrap();
+    /// let c = s.coordinates(&v).unwrap();
+    /// assert_eq!(c.to_string(), "10");
+    /// assert_eq!(s.element(&c), v);
+    /// assert_eq!(s.coordinates(&BitVector::<usize>::from_string("0001").unwrap()), None);
+    /// ```
+    #[must_use]
+    pub fn coordinates<Src: BitStore<Word>>(&self, v: &Src) -> Option<BitVector<Word>> {
+        if self.contains(v) { Some(BitVector::from_fn(self.dim(), |i| v.get(self.pivots[i]))) } else { None }
+    }
+
+    /// Returns the element of the subspace with the given coordinates with respect to the canonical basis.
+    ///
+    /// # Panics
+    /// Panics if `c` does not have `dim()` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// assert_eq!(s.element(&BitVector::<usize>::from_string("01").unwrap()).to_string(), "0110");
+    /// ```
+    #[must_use]
+    pub fn element<Src: BitStore<Word>>(&self, c: &Src) -> BitVector<Word> {
+        assert_eq!(c.len(), self.dim(), "Coordinates have {} elements but the dimension is {}", c.len(), self.dim());
+        let mut result = BitVector::zeros(self.len);
+        for i in c.set_bits() {
+            result ^= &self.rows[i];
+        }
+        result
+    }
+}
+
+/// Methods that combine subspaces.
+impl<Word: Unsigned> BitSubspace<Word> {
+    /// Returns the sum `U + W` of two subspaces, which is the smallest subspace that contains both.
+    ///
+    /// # Panics
+    /// Panics if the subspaces are for bit-vectors of different lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let u: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// let w: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1010 0011").unwrap());
+    /// assert_eq!(u.sum(&w).dim(), 3);
+    /// ```
+    #[must_use]
+    pub fn sum(&self, other: &BitSubspace<Word>) -> BitSubspace<Word> {
+        assert_eq!(self.len, other.len, "Subspaces are for lengths {} and {}", self.len, other.len);
+        Self::from_rows(self.len, self.rows.iter().chain(&other.rows).cloned().collect())
+    }
+
+    /// Returns the intersection of two subspaces.
+    ///
+    /// This uses the Zassenhaus algorithm: the rows `(u, u)` for the basis vectors `u` of this subspace and `(w, 0)`
+    /// for the basis vectors `w` of `other` are put in echelon form. The rows with a zero left half then have right
+    /// halves that span the intersection.
+    ///
+    /// # Panics
+    /// Panics if the subspaces are for bit-vectors of different lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let u: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// let w: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1010 0011").unwrap());
+    /// let i = u.intersection(&w);
+    /// assert_eq!(i.dim(), 1);
+    /// assert_eq!(i.vectors()[0].to_string(), "1010");
+    /// assert_eq!(u.dim() + w.dim(), u.sum(&w).dim() + i.dim());
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &BitSubspace<Word>) -> BitSubspace<Word> {
+        assert_eq!(self.len, other.len, "Subspaces are for lengths {} and {}", self.len, other.len);
+        let n = self.len;
+        let mut rows = Vec::with_capacity(self.dim() + other.dim());
+        for u in &self.rows {
+            let mut row = u.clone();
+            row.append_store(u);
+            rows.push(row);
+        }
+        for w in &other.rows {
+            let mut row = w.clone();
+            row.append_store(&BitVector::<Word>::zeros(n));
+            rows.push(row);
+        }
+        let echelon = Self::from_rows(2 * n, rows);
+        let right_halves = echelon
+            .rows
+            .into_iter()
+            .zip(echelon.pivots)
+            .filter(|&(_, p)| p >= n)
+            .map(|(mut row, _)| row.split_off(n))
+            .collect();
+        Self::from_rows(n, right_halves)
+    }
+
+    /// Returns the orthogonal complement of the subspace, i.e., the bit-vectors `x` where `u.x = 0` for every `u` in
+    /// the subspace.
+    ///
+    /// Over GF(2) a subspace can meet its orthogonal complement, so this is not a complement in the sense of
+    /// direct sums. Use [`BitSubspace::coset_representatives`] for that.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0011").unwrap());
+    /// let c = s.orthogonal_complement();
+    /// assert_eq!(c.dim(), 2);
+    /// assert_eq!(c, s);
+    /// assert_eq!(c.orthogonal_complement(), s);
+    /// ```
+    #[must_use]
+    pub fn orthogonal_complement(&self) -> BitSubspace<Word> {
+        // Each free column `f` gives a basis vector with a 1 in slot `f` and the pivot slots solved for from the rows.
+        let mut rows = Vec::with_capacity(self.codim());
+        for f in self.free_columns() {
+            let mut x = BitVector::unit(f, self.len);
+            for (row, &p) in self.rows.iter().zip(&self.pivots) {
+                if row[f] {
+                    x.set(p, true);
+                }
+            }
+            rows.push(x);
+        }
+        Self::from_rows(self.len, rows)
+    }
+
+    /// Returns the indices of the elements that are not pivots of the canonical basis in increasing order.
+    fn free_columns(&self) -> impl Iterator<Item = usize> + '_ {
+        let mut pivots = self.pivots.iter().peekable();
+        (0..self.len).filter(move |&j| {
+            if pivots.peek() == Some(&&j) {
+                pivots.next();
+                false
+            }
+            else {
+                true
+            }
+        })
+    }
+}
+
+/// Methods for the quotient space by a subspace.
+impl<Word: Unsigned> BitSubspace<Word> {
+    /// Returns the canonical representative of the coset `v + S` where `S` is this subspace.
+    ///
+    /// This is `v` with every pivot element cleared by adding canonical basis vectors, so two bit-stores give the same
+    /// representative exactly when their difference is in the subspace.
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// let u: BitVector = BitVector::from_string("1011").unwrap();
+    /// let v: BitVector = BitVector::from_string("0111").unwrap();
+    /// assert_eq!(s.coset_representative(&u).to_string(), "0001");
+    /// assert_eq!(s.coset_representative(&u), s.coset_representative(&v));
+    /// ```
+    #[must_use]
+    pub fn coset_representative<Src: BitStore<Word>>(&self, v: &Src) -> BitVector<Word> {
+        assert_eq!(v.len(), self.len, "Bit-store has {} elements but the subspace is for length {}", v.len(), self.len);
+        let mut result = BitVector::from_store(v);
+        for (row, &p) in self.rows.iter().zip(&self.pivots) {
+            if result[p] {
+                result ^= row;
+            }
+        }
+        result
+    }
+
+    /// Returns the coordinates of the coset `v + S` in the quotient space `GF(2)^n / S` where `S` is this subspace.
+    ///
+    /// The quotient space has dimension `codim()` and the basis we use is the cosets `e_f + S` for the free columns `f`
+    /// that are not pivots of the canonical basis, in increasing order. The coordinates are the elements of the
+    /// canonical coset representative at the free columns.
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// let v: BitVector = BitVector::from_string("0111").unwrap();
+    /// let c = s.quotient_coordinates(&v);
+    /// assert_eq!(c.to_string(), "01");
+    /// assert_eq!(s.quotient_element(&c), s.coset_representative(&v));
+    /// ```
+    #[must_use]
+    pub fn quotient_coordinates<Src: BitStore<Word>>(&self, v: &Src) -> BitVector<Word> {
+        let r = self.coset_representative(v);
+        let free: Vec<usize> = self.free_columns().collect();
+        BitVector::from_fn(free.len(), |i| r[free[i]])
+    }
+
+    /// Returns the canonical representative of the coset with the given coordinates in the quotient space.
+    ///
+    /// See [`BitSubspace::quotient_coordinates`] for the basis that we use.
+    ///
+    /// # Panics
+    /// Panics if `c` does not have `codim()` elements.
+    #[must_use]
+    pub fn quotient_element<Src: BitStore<Word>>(&self, c: &Src) -> BitVector<Word> {
+        let codim = self.codim();
+        assert_eq!(c.len(), codim, "Coordinates have {} elements but the codimension is {codim}", c.len());
+        let mut result = BitVector::zeros(self.len);
+        for (i, f) in self.free_columns().enumerate() {
+            result.set(f, c.get(i));
+        }
+        result
+    }
+}
+
+/// Methods to enumerate the elements and cosets of a subspace.
+impl<Word: Unsigned> BitSubspace<Word> {
+    /// Returns an iterator over all `2^dim()` elements of the subspace.
+    ///
+    /// The elements come in Gray code order, so each one differs from the one before by a single canonical basis
+    /// vector. The first element is zero.
+    ///
+    /// # Panics
+    /// Panics if `dim()` is at least the number of bits in a `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// let elements: Vec<String> = s.elements().map(|v| v.to_string()).collect();
+    /// assert_eq!(elements, ["0000", "1010", "1100", "0110"]);
+    /// ```
+    #[must_use]
+    pub fn elements(&self) -> SubspaceElements<Word> { SubspaceElements::new(BitVector::zeros(self.len), &self.rows) }
+
+    /// Returns an iterator over all `2^dim()` elements of the coset `v + S` where `S` is this subspace.
+    ///
+    /// The elements come in the same order as [`BitSubspace::elements`] starting with `v`.
+    ///
+    /// # Panics
+    /// Panics if `v` has the wrong length or if `dim()` is at least the number of bits in a `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100").unwrap());
+    /// let v: BitVector = BitVector::from_string("0001").unwrap();
+    /// let coset: Vec<String> = s.coset(&v).map(|v| v.to_string()).collect();
+    /// assert_eq!(coset, ["0001", "1101"]);
+    /// ```
+    #[must_use]
+    pub fn coset<Src: BitStore<Word>>(&self, v: &Src) -> SubspaceElements<Word> {
+        assert_eq!(v.len(), self.len, "Bit-store has {} elements but the subspace is for length {}", v.len(), self.len);
+        SubspaceElements::new(BitVector::from_store(v), &self.rows)
+    }
+
+    /// Returns an iterator over the canonical representatives of all `2^codim()` cosets of the subspace.
+    ///
+    /// The representatives are the bit-vectors that are zero at every pivot of the canonical basis (see
+    /// [`BitSubspace::coset_representative`]). Together they form a subspace that is a direct sum complement of this
+    /// one. They come in Gray code order starting with zero.
+    ///
+    /// # Panics
+    /// Panics if `codim()` is at least the number of bits in a `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+    /// let reps: Vec<String> = s.coset_representatives().map(|v| v.to_string()).collect();
+    /// assert_eq!(reps, ["0000", "0010", "0011", "0001"]);
+    /// ```
+    #[must_use]
+    pub fn coset_representatives(&self) -> SubspaceElements<Word> {
+        let units: Vec<_> = self.free_columns().map(|f| BitVector::unit(f, self.len)).collect();
+        SubspaceElements::new(BitVector::zeros(self.len), &units)
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// Trait implementations for subspaces.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// The `Display` trait implementation for a subspace shows its canonical basis as the rows of a bit-matrix.
+///
+/// The alternate form `{:#}` shows them on one line like `1010 0110`.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let s: BitSubspace = BitSubspace::from_matrix(&BitMatrix::from_string("1100 0110").unwrap());
+/// assert_eq!(format!("{s:#}"), "1010 0110");
+/// ```
+impl<Word: Unsigned> fmt::Display for BitSubspace<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Display::fmt(&self.to_matrix(), f) }
+}
+
+/// The `Debug` trait implementation for a subspace is the same as the alternate `Display` implementation.
+impl<Word: Unsigned> fmt::Debug for BitSubspace<Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{self:#}") }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// The `SubspaceElements` iterator.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// An iterator over the elements of a coset `v + span(b_0, b_1, ...)` in Gray code order.
+///
+/// Element `k` is `v` plus the sum of the `b_i` where bit `i` of the Gray code `k ^ (k >> 1)` is set. The steps come
+/// from a [`GrayCode`] iterator over the coefficients, so each one costs a single bit-vector addition.
+///
+/// This is returned by [`BitSubspace::elements`], [`BitSubspace::coset`], and [`BitSubspace::coset_representatives`].
+pub struct SubspaceElements<Word: Unsigned = usize> {
+    current:   BitVector<Word>,
+    steps:     Vec<BitVector<Word>>,
+    gray:      GrayCode<Word>,
+    remaining: usize,
+}
+
+/// Construct a `SubspaceElements` iterator.
+impl<Word: Unsigned> SubspaceElements<Word> {
+    /// Creates an iterator over `start` plus the span of `steps`.
+    fn new(start: BitVector<Word>, steps: &[BitVector<Word>]) -> Self {
+        assert!(steps.len() < usize::BITS as usize, "Cannot enumerate a space of dimension {}", steps.len());
+        Self {
+            current:   start,
+            steps:     steps.to_vec(),
+            gray:      GrayCode::new(steps.len()),
+            remaining: 1 << steps.len(),
+        }
+    }
+}
+
+/// Implement the `Iterator` trait for `SubspaceElements`.
+impl<Word: Unsigned> Iterator for SubspaceElements<Word> {
+    type Item = BitVector<Word>;
+
+    /// Returns the next element of the coset.
+    fn next(&mut self) -> Option<Self::Item> {
+        // The Gray code flips one coefficient at a time and we add the matching step to keep up.
+        let (_, flipped) = self.gray.next()?;
+        if let Some(i) = flipped {
+            self.current ^= &self.steps[i];
+        }
+        self.remaining -= 1;
+        Some(self.current.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.len(), Some(self.len())) }
+}
+
+/// Implement the `ExactSizeIterator` trait for `SubspaceElements`.
+impl<Word: Unsigned> ExactSizeIterator for SubspaceElements<Word> {
+    /// Returns the number of elements that have not been iterated over.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let s: BitSubspace = BitSubspace::full(3);
+    /// let mut iter = s.elements();
+    /// assert_eq!(iter.len(), 8);
+    /// iter.next();
+    /// assert_eq!(iter.len(), 7);
+    /// ```
+    fn len(&self) -> usize { self.remaining }
+}
@@ -0,0 +1,748 @@
+//! [`BitMatrixView`] and [`BitMatrixViewMut`] are non-owning views of a rectangular window in a bit-matrix and
+//! [`BitColumn`] and [`BitColumnMut`] are non-owning views of a column of a bit-matrix.
+
+// Crate types.
+use crate::{
+    BitMatrix,
+    BitSlice,
+    BitStore,
+    BitVector,
+    Unsigned,
+};
+
+// Standard library imports.
+use std::fmt;
+
+// --------------------------------------------------------------------------------------------------------------------
+// The `BitMatrixView` type.
+// --------------------------------------------------------------------------------------------------------------------
+
+#[doc = include_str!("../docs/view.md")]
+#[derive(Clone, Copy)]
+pub struct BitMatrixView<'a, Word: Unsigned = usize> {
+    // The rows of the bit-matrix that the window covers.
+    m_rows: &'a [BitVector<Word>],
+
+    // The window covers columns `m_left..m_left + m_cols` of those rows.
+    m_left: usize,
+    m_cols: usize,
+}
+
+/// Constructors and core queries for a read-only view of a bit-matrix.
+impl<'a, Word: Unsigned> BitMatrixView<'a, Word> {
+    /// Crate-only constructor used by [`BitMatrix::view`] which checks the window is valid.
+    pub(crate) fn new(rows: &'a [BitVector<Word>], left: usize, cols: usize) -> Self {
+        Self { m_rows: rows, m_left: left, m_cols: cols }
+    }
+
+    /// Returns the number of rows in the view.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// assert_eq!(m.view(1..3, 0..4).rows(), 2);
+    /// ```
+    #[must_use]
+    pub fn rows(&self) -> usize { self.m_rows.len() }
+
+    /// Returns the number of columns in the view.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// assert_eq!(m.view(1..3, 0..4).cols(), 4);
+    /// ```
+    #[must_use]
+    pub fn cols(&self) -> usize { self.m_cols }
+
+    /// Returns `true` if the view has no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.rows() == 0 || self.cols() == 0 }
+
+    /// Returns the element at row `r` and column `c` of the view.
+    ///
+    /// # Panics
+    /// In debug mode, panics if `r` or `c` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// let view = m.view(1..3, 1..3);
+    /// assert!(view.get(0, 0));
+    /// assert!(!view.get(0, 1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, r: usize, c: usize) -> bool {
+        debug_assert!(c < self.cols(), "Column {c} is not in bounds [0, {})", self.cols());
+        self.m_rows[r].get(self.m_left + c)
+    }
+
+    /// Returns row `i` of the view as a bit-slice into the underlying bit-matrix.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// let view = m.view(1..3, 1..4);
+    /// assert_eq!(view.row(0).to_string(), "100");
+    /// assert_eq!(view.row(1).to_string(), "010");
+    /// ```
+    #[must_use]
+    pub fn row(&self, i: usize) -> BitSlice<'a, Word> {
+        let rows: &'a [BitVector<Word>] = self.m_rows;
+        rows[i].slice(self.m_left..self.m_left + self.m_cols)
+    }
+
+    /// Returns column `j` of the view as a strided [`BitColumn`] view.
+    ///
+    /// # Panics
+    /// Panics if `j` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// let view = m.view(1..4, 1..4);
+    /// assert_eq!(view.col(1).to_string(), "010");
+    /// ```
+    #[must_use]
+    pub fn col(&self, j: usize) -> BitColumn<'a, Word> {
+        assert!(j < self.cols(), "Column {j} is not in bounds [0, {})", self.cols());
+        BitColumn::new(self.m_rows, self.m_left + j)
+    }
+
+    /// Returns an iterator over the rows of the view as bit-slices.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(4);
+    /// let view = m.view(0..4, 2..4);
+    /// let rows: Vec<String> = view.row_iter().map(|row| row.to_string()).collect();
+    /// assert_eq!(rows, ["00", "00", "10", "01"]);
+    /// ```
+    pub fn row_iter(&self) -> impl ExactSizeIterator<Item = BitSlice<'a, Word>> + use<'a, Word> {
+        let view = *self;
+        (0..self.rows()).map(move |i| view.row(i))
+    }
+
+    /// Returns an iterator over the columns of the view as [`BitColumn`] views.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(4);
+    /// let view = m.view(0..2, 0..4);
+    /// let cols: Vec<String> = view.col_iter().map(|col| col.to_string()).collect();
+    /// assert_eq!(cols, ["10", "01", "00", "00"]);
+    /// ```
+    pub fn col_iter(&self) -> impl ExactSizeIterator<Item = BitColumn<'a, Word>> + use<'a, Word> {
+        let view = *self;
+        (0..self.cols()).map(move |j| view.col(j))
+    }
+}
+
+/// Read-only algorithms for a view of a bit-matrix.
+impl<Word: Unsigned> BitMatrixView<'_, Word> {
+    /// Returns the number of set elements in the view.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::ones(5, 5);
+    /// assert_eq!(m.view(1..3, 0..4).count_ones(), 8);
+    /// ```
+    #[must_use]
+    pub fn count_ones(&self) -> usize { self.row_iter().map(|row| row.count_ones()).sum() }
+
+    /// Returns `true` if no element of the view is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// assert!(m.view(0..2, 2..5).none());
+    /// assert!(!m.view(0..3, 2..5).none());
+    /// ```
+    #[must_use]
+    pub fn none(&self) -> bool { self.count_ones() == 0 }
+
+    /// Returns the bit-vector `V.v` where `V` is the view and `v` is any bit-store with one element per column.
+    ///
+    /// # Panics
+    /// Panics if the length of `v` does not match the number of columns in the view.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("1100 0110 0011").unwrap();
+    /// let v: BitVector = BitVector::ones(2);
+    /// assert_eq!(m.view(0..3, 1..3).dot(&v).to_string(), "101");
+    /// ```
+    #[must_use]
+    pub fn dot<Src: BitStore<Word>>(&self, v: &Src) -> BitVector<Word> {
+        assert_eq!(v.len(), self.cols(), "Length mismatch {} != {}", v.len(), self.cols());
+        BitVector::from_fn(self.rows(), |i| self.row(i).dot(v))
+    }
+
+    /// Returns the bit-matrix product `V.M` where `V` is the view and `M` is a bit-matrix.
+    ///
+    /// # Panics
+    /// Panics if the number of rows in `M` does not match the number of columns in the view.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::random(6, 6);
+    /// let b: BitMatrix = BitMatrix::random(3, 4);
+    /// assert_eq!(m.view(1..5, 2..5).dot_matrix(&b), &m.sub_matrix(1..5, 2..5) * &b);
+    /// ```
+    #[must_use]
+    pub fn dot_matrix(&self, rhs: &BitMatrix<Word>) -> BitMatrix<Word> {
+        assert_eq!(rhs.rows(), self.cols(), "Dimension mismatch {} != {}", rhs.rows(), self.cols());
+        let mut result = BitMatrix::zeros(self.rows(), rhs.cols());
+        for i in 0..self.rows() {
+            for j in 0..self.cols() {
+                if self.get(i, j) {
+                    result[i] ^= &rhs[j];
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns an independent copy of the view as a new bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// assert_eq!(m.view(1..4, 1..4).to_bit_matrix(), BitMatrix::identity(3));
+    /// ```
+    #[must_use]
+    pub fn to_bit_matrix(&self) -> BitMatrix<Word> {
+        let mut result = BitMatrix::zeros(self.rows(), self.cols());
+        for (i, row) in self.row_iter().enumerate() {
+            result[i].copy_store(&row);
+        }
+        result
+    }
+
+    /// Returns a compact "binary" string representation of the view with the rows separated by a space.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// assert_eq!(m.view(0..2, 0..3).to_compact_binary_string(), "100 010");
+    /// ```
+    #[must_use]
+    pub fn to_compact_binary_string(&self) -> String { self.to_custom_binary_string(" ", "", "", "") }
+
+    /// Returns a customised binary string representation of the view.
+    ///
+    /// See [`BitMatrix::to_custom_binary_string`] for the meaning of the parameters.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(5);
+    /// assert_eq!(m.view(0..2, 0..3).to_custom_binary_string("\n", "", "[", "]"), "[100]\n[010]");
+    /// ```
+    #[must_use]
+    pub fn to_custom_binary_string(&self, row_separator: &str, separator: &str, left: &str, right: &str) -> String {
+        (0..self.rows())
+            .map(|i| {
+                let bits: Vec<&str> = (0..self.cols()).map(|j| if self.get(i, j) { "1" } else { "0" }).collect();
+                format!("{left}{}{right}", bits.join(separator))
+            })
+            .collect::<Vec<_>>()
+            .join(row_separator)
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// The `BitMatrixViewMut` type.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// A non-owning *mutable* view of a rectangular window in a [`BitMatrix`].
+///
+/// You get one from [`BitMatrix::view_mut`]. Changes made through the view go straight into the bit-matrix.
+/// Use [`BitMatrixViewMut::as_view`] to run any of the read-only [`BitMatrixView`] algorithms on the window.
+pub struct BitMatrixViewMut<'a, Word: Unsigned = usize> {
+    // The rows of the bit-matrix that the window covers.
+    m_rows: &'a mut [BitVector<Word>],
+
+    // The window covers columns `m_left..m_left + m_cols` of those rows.
+    m_left: usize,
+    m_cols: usize,
+}
+
+/// Constructors and core queries for a mutable view of a bit-matrix.
+impl<'a, Word: Unsigned> BitMatrixViewMut<'a, Word> {
+    /// Crate-only constructor used by [`BitMatrix::view_mut`] which checks the window is valid.
+    pub(crate) fn new(rows: &'a mut [BitVector<Word>], left: usize, cols: usize) -> Self {
+        Self { m_rows: rows, m_left: left, m_cols: cols }
+    }
+
+    /// Returns the number of rows in the view.
+    #[must_use]
+    pub fn rows(&self) -> usize { self.m_rows.len() }
+
+    /// Returns the number of columns in the view.
+    #[must_use]
+    pub fn cols(&self) -> usize { self.m_cols }
+
+    /// Returns a read-only view of the same window.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::identity(5);
+    /// let view = m.view_mut(1..3, 1..4);
+    /// assert_eq!(view.as_view().to_compact_binary_string(), "100 010");
+    /// ```
+    #[must_use]
+    pub fn as_view(&self) -> BitMatrixView<'_, Word> { BitMatrixView::new(self.m_rows, self.m_left, self.m_cols) }
+
+    /// Returns the element at row `r` and column `c` of the view.
+    ///
+    /// # Panics
+    /// In debug mode, panics if `r` or `c` is out of bounds.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, r: usize, c: usize) -> bool { self.as_view().get(r, c) }
+
+    /// Sets the element at row `r` and column `c` of the view to `val`.
+    ///
+    /// # Panics
+    /// In debug mode, panics if `r` or `c` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::zeros(3, 3);
+    /// m.view_mut(1..3, 1..3).set(1, 1, true);
+    /// assert_eq!(m.to_compact_binary_string(), "000 000 001");
+    /// ```
+    #[inline]
+    pub fn set(&mut self, r: usize, c: usize, val: bool) -> &mut Self {
+        debug_assert!(c < self.cols(), "Column {c} is not in bounds [0, {})", self.cols());
+        self.m_rows[r].set(self.m_left + c, val);
+        self
+    }
+
+    /// Returns row `i` of the view as a mutable bit-slice into the underlying bit-matrix.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::zeros(3, 4);
+    /// m.view_mut(0..3, 1..3).row_mut(2).set_all(true);
+    /// assert_eq!(m.to_compact_binary_string(), "0000 0000 0110");
+    /// ```
+    pub fn row_mut(&mut self, i: usize) -> BitSlice<'_, Word> {
+        self.m_rows[i].slice_mut(self.m_left..self.m_left + self.m_cols)
+    }
+
+    /// Returns column `j` of the view as a mutable [`BitColumnMut`] view.
+    ///
+    /// # Panics
+    /// Panics if `j` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::zeros(3, 3);
+    /// let mut view = m.view_mut(1..3, 0..3);
+    /// view.col_mut(2).set_all(true);
+    /// assert_eq!(m.to_compact_binary_string(), "000 001 001");
+    /// ```
+    pub fn col_mut(&mut self, j: usize) -> BitColumnMut<'_, Word> {
+        assert!(j < self.cols(), "Column {j} is not in bounds [0, {})", self.cols());
+        BitColumnMut::new(self.m_rows, self.m_left + j)
+    }
+
+    /// Returns an iterator over the rows of the view as mutable bit-slices.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::zeros(3, 4);
+    /// for mut row in m.view_mut(1..3, 2..4).row_iter_mut() {
+    ///     row.set(0, true);
+    /// }
+    /// assert_eq!(m.to_compact_binary_string(), "0000 0010 0010");
+    /// ```
+    pub fn row_iter_mut(&mut self) -> impl ExactSizeIterator<Item = BitSlice<'_, Word>> {
+        let (left, cols) = (self.m_left, self.m_cols);
+        self.m_rows.iter_mut().map(move |row| row.slice_mut(left..left + cols))
+    }
+
+    /// Sets all the elements in the view to `val`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::zeros(3, 3);
+    /// m.view_mut(0..2, 1..3).set_all(true);
+    /// assert_eq!(m.to_compact_binary_string(), "011 011 000");
+    /// ```
+    pub fn set_all(&mut self, val: bool) -> &mut Self {
+        for mut row in self.row_iter_mut() {
+            row.set_all(val);
+        }
+        self
+    }
+
+    /// Copies the elements of `src` into the window. The source can be a bit-matrix or a view of one.
+    ///
+    /// # Panics
+    /// Panics if `src` does not have the same shape as the view.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::zeros(4, 4);
+    /// let src: BitMatrix = BitMatrix::identity(2);
+    /// m.view_mut(1..3, 2..4).copy_from(&src);
+    /// assert_eq!(m.to_compact_binary_string(), "0000 0010 0001 0000");
+    /// ```
+    pub fn copy_from<'b>(&mut self, src: impl Into<BitMatrixView<'b, Word>>) -> &mut Self
+    where Word: 'b {
+        let src = src.into();
+        self.check_shape(&src);
+        for (mut row, src_row) in self.row_iter_mut().zip(src.row_iter()) {
+            row.copy_store(&src_row);
+        }
+        self
+    }
+
+    /// XORs the elements of `src` into the window in place. The source can be a bit-matrix or a view of one.
+    ///
+    /// # Panics
+    /// Panics if `src` does not have the same shape as the view.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let mut m: BitMatrix = BitMatrix::ones(3, 3);
+    /// let src: BitMatrix = BitMatrix::identity(2);
+    /// m.view_mut(0..2, 0..2).xor_eq(&src);
+    /// assert_eq!(m.to_compact_binary_string(), "011 101 111");
+    /// ```
+    pub fn xor_eq<'b>(&mut self, src: impl Into<BitMatrixView<'b, Word>>) -> &mut Self
+    where Word: 'b {
+        let src = src.into();
+        self.check_shape(&src);
+        for (mut row, src_row) in self.row_iter_mut().zip(src.row_iter()) {
+            row.xor_eq(&src_row);
+        }
+        self
+    }
+
+    /// Panics if `src` has a different shape from the view.
+    fn check_shape(&self, src: &BitMatrixView<'_, Word>) {
+        assert_eq!(src.rows(), self.rows(), "Row count mismatch {} != {}", src.rows(), self.rows());
+        assert_eq!(src.cols(), self.cols(), "Column count mismatch {} != {}", src.cols(), self.cols());
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// The `BitColumn` type.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// A read-only non-owning view of one column of a [`BitMatrix`].
+///
+/// Bit-matrices are stored by rows so the bits in a column are strided across the rows. A column view reads those
+/// bits in place without first copying the column into a [`BitVector`].
+///
+/// # Note
+/// A column has no contiguous words underneath it so a read-only column view is *not* a [`BitStore`]. It has the
+/// read-only subset of the bit-store methods instead, and [`BitColumn::to_vector`] copies the column into a
+/// bit-vector when you need the rest of the bit-store API. Use [`BitMatrix::col_view_mut`] for a [`BitColumnMut`]
+/// that can be written to.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let m: BitMatrix = BitMatrix::identity(4);
+/// assert_eq!(m.col_view(1).to_string(), "0100");
+/// assert_eq!(m.col_view(1).first_set(), Some(1));
+/// ```
+///
+/// Writing through a read-only column view does not compile:
+/// ```compile_fail
+/// use gf2::*;
+/// let mut m: BitMatrix = BitMatrix::identity(4);
+/// m.col_view(1).set(0, true);
+/// ```
+#[derive(Clone, Copy)]
+pub struct BitColumn<'a, Word: Unsigned = usize> {
+    // The rows that the column runs down.
+    m_rows: &'a [BitVector<Word>],
+
+    // The index of the column in each of those rows.
+    m_col: usize,
+}
+
+/// Constructors for read-only column views.
+impl<'a, Word: Unsigned> BitColumn<'a, Word> {
+    /// Crate-only constructor for a read-only view of column `col` of the given rows.
+    pub(crate) fn new(rows: &'a [BitVector<Word>], col: usize) -> Self { Self { m_rows: rows, m_col: col } }
+}
+
+/// Core queries and element access for a read-only column view.
+impl<Word: Unsigned> BitColumn<'_, Word> {
+    /// Returns the number of bits in the column, which is the number of rows in the bit-matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::zeros(3, 5);
+    /// assert_eq!(m.col_view(4).len(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize { self.m_rows.len() }
+
+    /// Returns `true` if the column has no elements.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns element `i` of the column.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::identity(3);
+    /// assert!(m.col_view(1).get(1));
+    /// assert!(!m.col_view(1).get(2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get(&self, i: usize) -> bool { self.m_rows[i].get(self.m_col) }
+
+    /// Returns an iterator over the elements of the column from the top row down.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("10 11 01").unwrap();
+    /// let bits: Vec<bool> = m.col_view(1).iter().collect();
+    /// assert_eq!(bits, [false, true, true]);
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = bool> + '_ { self.m_rows.iter().map(|row| row.get(self.m_col)) }
+
+    /// Returns an independent copy of the column as a new bit-vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("10 11 01").unwrap();
+    /// assert_eq!(m.col_view(1).to_vector(), BitVector::<usize>::from_string("011").unwrap());
+    /// ```
+    #[must_use]
+    pub fn to_vector(&self) -> BitVector<Word> { gather(self.m_rows, self.m_col) }
+}
+
+/// Read-only algorithms for a column view.
+impl<Word: Unsigned> BitColumn<'_, Word> {
+    /// Returns the number of set elements in the column.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("10 11 01").unwrap();
+    /// assert_eq!(m.col_view(0).count_ones(), 2);
+    /// ```
+    #[must_use]
+    pub fn count_ones(&self) -> usize { self.iter().filter(|&bit| bit).count() }
+
+    /// Returns `true` if no element of the column is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("10 10 00").unwrap();
+    /// assert!(m.col_view(1).none());
+    /// assert!(!m.col_view(0).none());
+    /// ```
+    #[must_use]
+    pub fn none(&self) -> bool { !self.iter().any(|bit| bit) }
+
+    /// Returns the index of the first set element in the column or `None` if no element is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("10 01 01").unwrap();
+    /// assert_eq!(m.col_view(1).first_set(), Some(1));
+    /// assert_eq!(BitMatrix::<usize>::zeros(3, 3).col_view(1).first_set(), None);
+    /// ```
+    #[must_use]
+    pub fn first_set(&self) -> Option<usize> { self.iter().position(|bit| bit) }
+
+    /// Returns the dot product of the column with any bit-store of the same length.
+    ///
+    /// # Panics
+    /// Panics if the lengths do not match.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let m: BitMatrix = BitMatrix::from_string("110 011 111").unwrap();
+    /// assert!(m.col_view(0).dot(&m.col(2)));
+    /// assert!(!m.col_view(1).dot(&m.col(2)));
+    /// ```
+    #[must_use]
+    pub fn dot<Src: BitStore<Word>>(&self, rhs: &Src) -> bool {
+        assert_eq!(self.len(), rhs.len(), "Length mismatch {} != {}", self.len(), rhs.len());
+        self.iter().enumerate().filter(|&(i, bit)| bit && rhs.get(i)).count() % 2 == 1
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// The `BitColumnMut` type.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// A mutable non-owning view of one column of a [`BitMatrix`].
+///
+/// The view gathers the strided bits of the column into a contiguous bit-vector when it is created and scatters them
+/// back into the rows of the bit-matrix when it is dropped. That gives it real words underneath so it is a full
+/// [`BitStore`] and works anywhere a bit-store is expected. The view holds the mutable borrow of the bit-matrix for
+/// its whole lifetime so nothing else can see the rows before the changes are written back.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let mut m: BitMatrix = BitMatrix::identity(4);
+/// m.col_view_mut(3).flip_all();
+/// assert_eq!(m.to_compact_binary_string(), "1001 0101 0011 0000");
+/// m.col_view_mut(0).slice_mut(1..3).set_all(true);
+/// assert_eq!(m.to_compact_binary_string(), "1001 1101 1011 0000");
+/// ```
+pub struct BitColumnMut<'a, Word: Unsigned = usize> {
+    // The rows that the column runs down.
+    m_rows: &'a mut [BitVector<Word>],
+
+    // The index of the column in each of those rows.
+    m_col: usize,
+
+    // A contiguous copy of the column that is written back to the rows on drop.
+    m_bits: BitVector<Word>,
+}
+
+/// Constructors for mutable column views.
+impl<'a, Word: Unsigned> BitColumnMut<'a, Word> {
+    /// Crate-only constructor for a mutable view of column `col` of the given rows.
+    pub(crate) fn new(rows: &'a mut [BitVector<Word>], col: usize) -> Self {
+        let bits = gather(rows, col);
+        Self { m_rows: rows, m_col: col, m_bits: bits }
+    }
+}
+
+/// Writes the contiguous copy of the column back into the rows of the bit-matrix.
+impl<Word: Unsigned> Drop for BitColumnMut<'_, Word> {
+    fn drop(&mut self) {
+        for (i, row) in self.m_rows.iter_mut().enumerate() {
+            row.set(self.m_col, self.m_bits.get(i));
+        }
+    }
+}
+
+/// A mutable column view is a bit-store backed by its contiguous copy of the column.
+impl<Word: Unsigned> BitStore<Word> for BitColumnMut<'_, Word> {
+    #[inline]
+    fn len(&self) -> usize { self.m_bits.len() }
+
+    #[inline]
+    fn store(&self) -> &[Word] { self.m_bits.store() }
+
+    #[inline]
+    fn store_mut(&mut self) -> &mut [Word] { self.m_bits.store_mut() }
+
+    #[inline]
+    fn offset(&self) -> u32 { 0 }
+
+    #[inline]
+    fn words(&self) -> usize { self.m_bits.words() }
+
+    #[inline]
+    fn word(&self, i: usize) -> Word { self.m_bits.word(i) }
+
+    #[inline]
+    fn set_word(&mut self, i: usize, value: Word) { self.m_bits.set_word(i, value); }
+}
+
+/// Returns a copy of column `col` of the given rows as a bit-vector.
+fn gather<Word: Unsigned>(rows: &[BitVector<Word>], col: usize) -> BitVector<Word> {
+    BitVector::from_fn(rows.len(), |i| rows[i].get(col))
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// Conversions and `Display`-like trait implementations.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// A whole bit-matrix can be used anywhere a read-only view is expected.
+impl<'a, Word: Unsigned> From<&'a BitMatrix<Word>> for BitMatrixView<'a, Word> {
+    fn from(src: &'a BitMatrix<Word>) -> Self { src.view(.., ..) }
+}
+
+/// The `Display` trait implementation for a view which matches the one for a [`BitMatrix`].
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let m: BitMatrix = BitMatrix::identity(4);
+/// assert_eq!(format!("{:#}", m.view(0..2, 0..3)), "100 010");
+/// assert_eq!(format!("{}", m.view(0..2, 0..3)), format!("{}", m.sub_matrix(0..2, 0..3)));
+/// ```
+impl<Word: Unsigned> fmt::Display for BitMatrixView<'_, Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_compact_binary_string())
+        }
+        else {
+            const BAR: &str = "\u{2502}";
+            write!(f, "{}", self.to_custom_binary_string("\n", " ", BAR, BAR))
+        }
+    }
+}
+
+/// The `Debug` trait implementation for a view is the compact binary string.
+impl<Word: Unsigned> fmt::Debug for BitMatrixView<'_, Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}", self.to_compact_binary_string()) }
+}
+
+/// The `Display` trait implementation for a mutable view is the same as for a read-only one.
+impl<Word: Unsigned> fmt::Display for BitMatrixViewMut<'_, Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Display::fmt(&self.as_view(), f) }
+}
+
+/// The `Display` trait implementation for a column view prints the bits as a string of 0's and 1's.
+impl<Word: Unsigned> fmt::Display for BitColumn<'_, Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bits: String = self.iter().map(|bit| if bit { '1' } else { '0' }).collect();
+        write!(f, "{bits}")
+    }
+}
+
+/// The `Display` trait implementation for a mutable column view prints the bits as a string of 0's and 1's.
+impl<Word: Unsigned> fmt::Display for BitColumnMut<'_, Word> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}", self.to_binary_string()) }
+}
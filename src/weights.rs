@@ -0,0 +1,179 @@
+//! [`WeightDistribution`] counts the codewords of each weight in a code and implements the MacWilliams transform.
+
+// Standard library imports.
+use std::fmt;
+
+#[doc = include_str!("../docs/weights.md")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct WeightDistribution {
+    // The number of words of weight `w` is `counts[w]` for `w` in `0..=n`.
+    counts: Vec<u128>,
+}
+
+/// Constructors for weight distributions.
+impl WeightDistribution {
+    /// Returns the weight distribution of words of length `n = counts.len() - 1` with `counts[w]` words of weight `w`.
+    ///
+    /// # Panics
+    /// Panics if `counts` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let w = WeightDistribution::from_counts(vec![1, 0, 0, 7, 7, 0, 0, 1]);
+    /// assert_eq!((w.n(), w.total()), (7, 16));
+    /// ```
+    #[must_use]
+    pub fn from_counts(counts: Vec<u128>) -> Self {
+        assert!(!counts.is_empty(), "A weight distribution needs a count for weight zero");
+        Self { counts }
+    }
+}
+
+/// Methods to query a weight distribution.
+impl WeightDistribution {
+    /// Returns the length `n` of the words.
+    #[must_use]
+    pub fn n(&self) -> usize { self.counts.len() - 1 }
+
+    /// Returns the number of words of each weight from zero to `n`.
+    #[must_use]
+    pub fn counts(&self) -> &[u128] { &self.counts }
+
+    /// Returns the number of words of weight `w`, which is zero if `w > n`.
+    #[must_use]
+    pub fn count(&self, w: usize) -> u128 { self.counts.get(w).copied().unwrap_or(0) }
+
+    /// Returns the total number of words, which is `2^k` for a linear code of dimension `k`.
+    #[must_use]
+    pub fn total(&self) -> u128 { self.counts.iter().sum() }
+
+    /// Returns the smallest non-zero weight of a word or `None` if every word has weight zero.
+    ///
+    /// For a linear code this is the minimum distance.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let w = WeightDistribution::from_counts(vec![1, 0, 0, 7, 7, 0, 0, 1]);
+    /// assert_eq!(w.min_weight(), Some(3));
+    /// ```
+    #[must_use]
+    pub fn min_weight(&self) -> Option<usize> { (1..self.counts.len()).find(|&w| self.counts[w] > 0) }
+
+    /// Returns the value of the weight enumerator `W(x, y)`, the sum of `A_w x^(n - w) y^w` over the weights `w`.
+    ///
+    /// For example, if a codeword is sent over a binary symmetric channel with error probability `p` then the
+    /// probability of an undetected error is `W(1 - p, p) - (1 - p)^n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// let w = WeightDistribution::from_counts(vec![1, 0, 0, 7, 7, 0, 0, 1]);
+    /// assert_eq!(w.evaluate(1.0, 1.0), 16.0);
+    /// assert_eq!(w.evaluate(1.0, 0.0), 1.0);
+    /// ```
+    #[must_use]
+    pub fn evaluate(&self, x: f64, y: f64) -> f64 {
+        let n = self.n();
+        self.counts.iter().enumerate().map(|(w, &a)| a as f64 * x.powi((n - w) as i32) * y.powi(w as i32)).sum()
+    }
+
+    /// Returns the weight distribution of the dual of a linear code with this weight distribution.
+    ///
+    /// This is the MacWilliams identity `B_j = (A_0 K_j(0) + ... + A_n K_j(n)) / 2^k` where `K_j` is the Krawtchouk
+    /// polynomial `K_j(i) = sum_s (-1)^s C(i, s) C(n - i, j - s)`. The calculation is exact with 128-bit integers.
+    ///
+    /// # Panics
+    /// Panics if the total is not a power of two, if the counts are not those of a linear code, or if the code is too
+    /// long for the sums to fit in 128 bits, which can happen for codes of length over 100 or so.
+    ///
+    /// # Examples
+    /// ```
+    /// use gf2::*;
+    /// // The [7,4,3] Hamming code and its dual, the [7,3,4] simplex code.
+    /// let hamming = WeightDistribution::from_counts(vec![1, 0, 0, 7, 7, 0, 0, 1]);
+    /// let simplex = hamming.macwilliams();
+    /// assert_eq!(simplex.counts(), &[1, 0, 0, 0, 7, 0, 0, 0]);
+    /// assert_eq!(simplex.macwilliams(), hamming);
+    /// ```
+    #[must_use]
+    pub fn macwilliams(&self) -> Self {
+        let total = self.total();
+        assert!(total.is_power_of_two(), "The total {total} is not the size of a linear code");
+        let k = total.trailing_zeros();
+        let n = self.n();
+        let overflow = || panic!("A code of length {n} is too long for the MacWilliams transform in 128 bits");
+
+        // Row `i` holds the coefficients of `(1 - z)^i (1 + z)^(n - i)`, which are the values `K_j(i)`.
+        let mut sums = vec![0_i128; n + 1];
+        for (i, &a) in self.counts.iter().enumerate().filter(|&(_, &a)| a > 0) {
+            let mut krawtchouk = vec![0_i128; n + 1];
+            krawtchouk[0] = 1;
+            for t in 0..n {
+                let sign = if t < i { -1 } else { 1 };
+                for j in (1..=t + 1).rev() {
+                    krawtchouk[j] = krawtchouk[j].checked_add(sign * krawtchouk[j - 1]).unwrap_or_else(overflow);
+                }
+            }
+            let a = i128::try_from(a).unwrap_or_else(|_| overflow());
+            for j in 0..=n {
+                let term = a.checked_mul(krawtchouk[j]).unwrap_or_else(overflow);
+                sums[j] = sums[j].checked_add(term).unwrap_or_else(overflow);
+            }
+        }
+        let counts = sums
+            .into_iter()
+            .map(|s| {
+                assert!(s >= 0 && s % (1 << k) == 0, "The counts are not the weight distribution of a linear code");
+                (s >> k) as u128
+            })
+            .collect();
+        Self { counts }
+    }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// Trait implementations for weight distributions.
+// --------------------------------------------------------------------------------------------------------------------
+
+/// The `Display` trait implementation for a weight distribution shows the homogeneous weight enumerator `W(x, y)`.
+///
+/// # Examples
+/// ```
+/// use gf2::*;
+/// let w = WeightDistribution::from_counts(vec![1, 0, 0, 7, 7, 0, 0, 1]);
+/// assert_eq!(w.to_string(), "x^7 + 7x^4y^3 + 7x^3y^4 + y^7");
+/// ```
+impl fmt::Display for WeightDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let power = |v: &str, e: usize| match e {
+            0 => String::new(),
+            1 => v.to_string(),
+            _ => format!("{v}^{e}"),
+        };
+        let n = self.n();
+        let terms: Vec<String> = (0..=n)
+            .filter(|&w| self.counts[w] > 0)
+            .map(|w| {
+                let a = self.counts[w];
+                let monomial = format!("{}{}", power("x", n - w), power("y", w));
+                if monomial.is_empty() {
+                    a.to_string()
+                }
+                else if a == 1 {
+                    monomial
+                }
+                else {
+                    format!("{a}{monomial}")
+                }
+            })
+            .collect();
+        if terms.is_empty() { write!(f, "0") } else { write!(f, "{}", terms.join(" + ")) }
+    }
+}
+
+/// The `Debug` trait implementation for a weight distribution shows the counts.
+impl fmt::Debug for WeightDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "WeightDistribution {:?}", self.counts) }
+}
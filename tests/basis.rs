@@ -0,0 +1,91 @@
+use gf2::*;
+
+// The types of basis, bit-vector, and bit-matrix we are testing.
+type BB = BitBasis<u8>;
+type BV = BitVector<u8>;
+type BM = BitMatrix<u8>;
+
+#[test]
+fn test_insert_and_reduce() {
+    // 6 = 12 ^ 10 is rejected and then 243 = 12 ^ 255 is a sum of the first and third accepted vectors.
+    let mut basis = BB::new(8);
+    assert!(basis.insert(&BV::from_unsigned(12_u8)));
+    assert!(basis.insert(&BV::from_unsigned(10_u8)));
+    assert!(!basis.insert(&BV::from_unsigned(6_u8)));
+    assert!(basis.insert(&BV::from_unsigned(255_u8)));
+    assert!(!basis.insert(&BV::zeros(8)));
+    assert_eq!(basis.rank(), 3);
+
+    let (r, c) = basis.reduce(&BV::from_unsigned(6_u8));
+    assert!(r.none());
+    assert_eq!(c.to_string(), "110");
+    let (r, c) = basis.reduce(&BV::from_unsigned(243_u8));
+    assert!(r.none());
+    assert_eq!(c.to_string(), "101");
+    let (r, _) = basis.reduce(&BV::from_unsigned(1_u8));
+    assert_eq!(r, BV::from_unsigned(1_u8));
+}
+
+#[test]
+fn test_from_matrix() {
+    let m = BM::from_string("1100 0110 1010").unwrap();
+    let basis = BB::from_matrix(&m);
+    assert_eq!(basis.rank(), 2);
+    assert_eq!(basis.to_matrix().rank(), 2);
+    assert!((0..m.rows()).all(|i| basis.contains(m.row(i))));
+    assert!(!basis.contains(&BV::from_string("1000").unwrap()));
+    assert_eq!(basis, BB::from_matrix(&BM::from_string("1010 0110").unwrap()));
+
+    let basis = BB::from_matrix(&BM::identity(5));
+    assert_eq!(basis.rank(), 5);
+    assert_eq!(basis.pivots(), &[4, 3, 2, 1, 0]);
+
+    let basis = BB::from_matrix(&BM::zeros(3, 4));
+    assert!(basis.is_empty());
+    assert_eq!(basis.vector_len(), 4);
+}
+
+#[test]
+fn test_xor_queries() {
+    let from = |xs: &[u8]| {
+        let mut basis = BB::new(8);
+        for &x in xs {
+            basis.insert(&BV::from_unsigned(x));
+        }
+        basis
+    };
+
+    // The span of 3, 10, 14 is {0, 3, 4, 7, 9, 10, 13, 14}.
+    let basis = from(&[3, 10, 14]);
+    assert_eq!(basis.max_xor(), BV::from_unsigned(14_u8));
+    assert_eq!(basis.min_xor(), Some(BV::from_unsigned(3_u8)));
+    assert_eq!(basis.max_xor_with(&BV::from_unsigned(16_u8)), BV::from_unsigned(30_u8));
+    assert_eq!(basis.min_xor_with(&BV::from_unsigned(15_u8)), BV::from_unsigned(1_u8));
+
+    // The span of 12, 10, 6, 255 is {0, 6, 10, 12, 243, 245, 249, 255}.
+    let basis = from(&[12, 10, 6, 255]);
+    assert_eq!(basis.max_xor(), BV::from_unsigned(255_u8));
+    assert_eq!(basis.min_xor(), Some(BV::from_unsigned(6_u8)));
+    assert_eq!(basis.max_xor_with(&BV::from_unsigned(1_u8)), BV::from_unsigned(254_u8));
+    assert_eq!(basis.min_xor_with(&BV::from_unsigned(250_u8)), BV::from_unsigned(3_u8));
+
+    // The unit vectors span everything below 16.
+    let basis = from(&[1, 2, 4, 8]);
+    assert_eq!(basis.max_xor(), BV::from_unsigned(15_u8));
+    assert_eq!(basis.min_xor(), Some(BV::from_unsigned(1_u8)));
+    assert_eq!(basis.min_xor_with(&BV::from_unsigned(31_u8)), BV::from_unsigned(16_u8));
+
+    let basis = BB::new(8);
+    assert_eq!(basis.max_xor(), BV::zeros(8));
+    assert_eq!(basis.min_xor(), None);
+}
+
+#[test]
+fn test_merge() {
+    let mut a = BB::from_matrix(&BM::from_string("1100 0110").unwrap());
+    let b = BB::from_matrix(&BM::from_string("1010 0001").unwrap());
+    assert_eq!(a.merge(&b), 1);
+    assert_eq!(a.rank(), 3);
+    assert_eq!(a, BB::from_matrix(&BM::from_string("1100 0110 0001").unwrap()));
+    assert_eq!(a.merge(&b), 0);
+}
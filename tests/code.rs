@@ -0,0 +1,256 @@
+use gf2::BitStore;
+
+#[test]
+fn test_generator_and_parity_check() {
+    // The [7,4] Hamming code in systematic form G = [I | P] has parity-check matrix H = [P^T | I].
+    let g: gf2::BitMatrix<u8> = gf2::BitMatrix::from_string("1000110 0100101 0010011 0001111").unwrap();
+    let h: gf2::BitMatrix<u8> = gf2::BitMatrix::from_string("1101100 1011010 0111001").unwrap();
+    let code = gf2::LinearCode::from_generator(&g);
+    assert_eq!((code.n(), code.k(), code.redundancy()), (7, 4, 3));
+    assert!((code.generator() * &code.parity_check().transposed()).none());
+    assert_eq!(code, gf2::LinearCode::from_parity_check(&h));
+    assert_eq!(code.dual(), gf2::LinearCode::from_generator(&h));
+    assert_eq!(code.dual().dual(), code);
+
+    // A dependent row in the generator matrix is dropped.
+    let g: gf2::BitMatrix<u8> = gf2::BitMatrix::from_string("1100 0110 1010").unwrap();
+    let code = gf2::LinearCode::from_generator(&g);
+    assert_eq!((code.n(), code.k()), (4, 2));
+    assert_eq!(code.parity_check().rank(), 2);
+    assert!(code.contains(&gf2::BitVector::<u8>::from_string("1010").unwrap()));
+    assert!(!code.contains(&gf2::BitVector::<u8>::from_string("1000").unwrap()));
+
+    for seed in 1..20_u64 {
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_with_rank_seeded(7, 10, 6, seed);
+        let code = gf2::LinearCode::from_generator(&m);
+        assert_eq!((code.generator().rank(), code.parity_check().rank()), (6, 4));
+        assert!((code.generator() * &code.parity_check().transposed()).none());
+        assert_eq!(code, gf2::LinearCode::from_parity_check(code.parity_check()));
+    }
+}
+
+#[test]
+fn test_rank_zero() {
+    let full: gf2::LinearCode<u8> = gf2::LinearCode::from_subspace(&gf2::BitSubspace::full(5));
+    assert_eq!((full.k(), full.d()), (5, Some(1)));
+
+    // Rank zero generator and parity-check matrices still know the length of the codewords.
+    let all: gf2::LinearCode<u8> = gf2::LinearCode::from_parity_check(&gf2::BitMatrix::zeros(1, 5));
+    assert_eq!((all.n(), all.k(), all.redundancy(), all.d()), (5, 5, 0, Some(1)));
+    assert!(all.contains(&gf2::BitVector::<u8>::ones(5)));
+    let zero: gf2::LinearCode<u8> = gf2::LinearCode::from_generator(&gf2::BitMatrix::zeros(2, 5));
+    assert_eq!((zero.n(), zero.k(), zero.redundancy(), zero.d()), (5, 0, 5, None));
+    assert_eq!(zero.parity_check().rows(), 5);
+    assert!(zero.contains(&gf2::BitVector::<u8>::zeros(5)));
+    assert!(!zero.contains(&gf2::BitVector::<u8>::unit(2, 5)));
+    assert_eq!(zero.syndrome(&gf2::BitVector::<u8>::ones(5)).count_ones(), 5);
+}
+
+#[test]
+#[should_panic(expected = "at least one row")]
+fn test_generator_with_no_rows() {
+    let g: gf2::BitMatrix<u8> = gf2::BitMatrix::zeros(0, 5);
+    let _ = gf2::LinearCode::from_generator(&g);
+}
+
+#[test]
+fn test_systematic_form() {
+    // The Hamming code is already systematic so there is nothing to permute.
+    let hamming: gf2::LinearCode<u8> = gf2::LinearCode::hamming(3);
+    let (s, p) = hamming.systematic_form();
+    assert_eq!(s.sub_matrix(0..4, 0..4), gf2::BitMatrix::identity(4));
+    assert!(s.row_iter().all(|row| hamming.contains(&p.inverse().apply(row))));
+
+    // The span of 0110 and 0011 needs the information positions 1 and 2 moved to the front.
+    let g: gf2::BitMatrix<u8> = gf2::BitMatrix::from_string("0110 0011").unwrap();
+    let code = gf2::LinearCode::from_generator(&g);
+    let (s, p) = code.systematic_form();
+    assert_eq!(s.sub_matrix(0..2, 0..2), gf2::BitMatrix::identity(2));
+    assert_ne!(p, gf2::BitPermutation::identity(4));
+    assert!(s.row_iter().all(|row| code.contains(&p.inverse().apply(row))));
+}
+
+#[test]
+fn test_minimum_distance() {
+    assert_eq!(gf2::LinearCode::<u8>::hamming(3).d(), Some(3));
+    assert_eq!(gf2::LinearCode::<u8>::simplex(3).d(), Some(4));
+    assert_eq!(gf2::LinearCode::<u8>::repetition(5).d(), Some(5));
+    assert_eq!(gf2::LinearCode::<u8>::single_parity_check(6).d(), Some(2));
+    let g: gf2::BitMatrix<u8> = gf2::BitMatrix::from_string("11000 00111").unwrap();
+    let code = gf2::LinearCode::from_generator(&g);
+    assert_eq!((code.d(), code.dual().d()), (Some(2), Some(2)));
+
+    for seed in 1..20_u64 {
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_with_rank_seeded(4, 9, 4, seed);
+        let code = gf2::LinearCode::from_generator(&m);
+        let min = code.to_subspace().elements().skip(1).map(|c| c.count_ones()).min();
+        assert_eq!(code.d(), min);
+    }
+}
+
+#[test]
+fn test_hull() {
+    // The simplex code is inside its dual, the Hamming code, so it is the hull of both.
+    let hamming: gf2::LinearCode<u8> = gf2::LinearCode::hamming(3);
+    let simplex = gf2::LinearCode::simplex(3);
+    assert_eq!(hamming.hull(), simplex);
+    assert_eq!(simplex.hull(), simplex);
+    assert!(simplex.is_self_orthogonal() && !hamming.is_self_orthogonal());
+
+    // 111 has odd weight so it is not in its own dual but 1111 is.
+    assert_eq!(gf2::LinearCode::<u8>::repetition(3).hull().k(), 0);
+    assert!(gf2::LinearCode::<u8>::repetition(4).is_self_orthogonal());
+    assert!(gf2::LinearCode::<u8>::extended_hamming(3).is_self_dual());
+}
+
+#[test]
+fn test_extended_and_augmented() {
+    // Extending appends an overall parity bit and augmenting adds the all-ones word.
+    let extended: gf2::LinearCode<u8> = gf2::LinearCode::hamming(3).extended();
+    assert_eq!((extended.n(), extended.k(), extended.d()), (8, 4, Some(4)));
+    assert_eq!(gf2::LinearCode::<u8>::repetition(3).extended(), gf2::LinearCode::repetition(4));
+    let extended: gf2::LinearCode<u8> = gf2::LinearCode::single_parity_check(4).extended();
+    assert_eq!((extended.n(), extended.k(), extended.d()), (5, 3, Some(2)));
+    assert!(!extended.contains(&gf2::BitVector::<u8>::unit(4, 5)));
+
+    assert_eq!(gf2::LinearCode::<u8>::simplex(3).augmented(), gf2::LinearCode::hamming(3));
+    let parity: gf2::LinearCode<u8> = gf2::LinearCode::single_parity_check(4);
+    assert_eq!(parity.augmented(), parity);
+}
+
+#[test]
+fn test_punctured_and_shortened() {
+    // Dropping the parity bit of the extended Hamming code gets back to the Hamming code and keeping only the
+    // codewords with a zero there leaves the even weight Hamming codewords, which form the simplex code.
+    let extended: gf2::LinearCode<u8> = gf2::LinearCode::extended_hamming(3);
+    assert_eq!(extended.punctured(&[7]), gf2::LinearCode::hamming(3));
+    assert_eq!(extended.shortened(&[7]), gf2::LinearCode::simplex(3));
+
+    let hamming: gf2::LinearCode<u8> = gf2::LinearCode::hamming(3);
+    let punctured = hamming.punctured(&[0]);
+    assert_eq!((punctured.n(), punctured.k(), punctured.d()), (6, 4, Some(2)));
+    let shortened = hamming.shortened(&[0]);
+    assert_eq!((shortened.n(), shortened.k(), shortened.d()), (6, 3, Some(3)));
+}
+
+/// Checks the length, dimension and minimum distance of a code and that its two matrices are orthogonal.
+fn check(code: &gf2::LinearCode<u8>, n: usize, k: usize, d: usize) {
+    assert_eq!((code.n(), code.k(), code.d()), (n, k, Some(d)));
+    assert!((code.generator() * &code.parity_check().transposed()).none());
+}
+
+#[test]
+fn test_trivial_codes() {
+    for n in 1..6 {
+        // The code that is just the zero codeword corrects nothing and has a full rank parity-check matrix.
+        let zero: gf2::LinearCode<u8> = gf2::LinearCode::from_subspace(&gf2::BitSubspace::zero(n));
+        assert_eq!((zero.n(), zero.k(), zero.d()), (n, 0, None));
+        let w: gf2::BitVector<u8> = gf2::BitVector::random_seeded(n, n as u64);
+        assert_eq!(zero.syndrome(&w), w);
+        let (s, p) = zero.systematic_form();
+        assert_eq!((s.rows(), p.len()), (0, n));
+        let decoder = zero.decoder();
+        assert_eq!((decoder.len(), decoder.max_weight()), (1, 0));
+        let z: gf2::BitVector<u8> = gf2::BitVector::zeros(n);
+        assert_eq!(decoder.decode(&z), Some((z.clone(), z.clone())));
+        assert_eq!(decoder.decode(&gf2::BitVector::<u8>::ones(n)), None);
+
+        // The code of every bit-vector has no parity checks so every word decodes to itself.
+        let full: gf2::LinearCode<u8> = gf2::LinearCode::from_subspace(&gf2::BitSubspace::full(n));
+        assert_eq!((full.n(), full.k(), full.d()), (n, n, Some(1)));
+        assert!(full.syndrome(&w).is_empty());
+        let (s, p) = full.systematic_form();
+        assert_eq!(s, gf2::BitMatrix::identity(n));
+        assert_eq!(p, gf2::BitPermutation::identity(n));
+        let decoder = full.decoder();
+        assert_eq!((decoder.len(), decoder.max_weight()), (1, 0));
+        assert!(decoder.syndrome(&w).is_empty());
+        assert_eq!(decoder.decode(&w), Some((w.clone(), gf2::BitVector::zeros(n))));
+    }
+}
+
+#[test]
+fn test_hamming() {
+    for m in 2..=5 {
+        // Hamming codes are perfect and the syndrome of a single error is its position in binary.
+        let n = (1 << m) - 1;
+        let hamming = gf2::LinearCode::hamming(m);
+        check(&hamming, n, n - m, 3);
+        assert_eq!((1 + n) << (n - m), 1 << n);
+        let decoder = hamming.decoder();
+        assert_eq!((decoder.len(), decoder.max_weight()), (1 << m, 1));
+        for j in 0..n {
+            let s = hamming.syndrome(&gf2::BitVector::<u8>::unit(j, n));
+            assert_eq!(s, gf2::BitVector::from_fn(m, |i| (j + 1) >> i & 1 == 1));
+        }
+
+        // The extensions detect double errors.
+        let extended = gf2::LinearCode::extended_hamming(m);
+        check(&extended, n + 1, n - m, 4);
+        let decoder = extended.decoder();
+        assert_eq!(decoder.len(), n + 2);
+        for e in gf2::FixedWeight::<u8>::new(n + 1, 2) {
+            assert_eq!(decoder.decode(&e), None);
+        }
+    }
+}
+
+#[test]
+fn test_simplex() {
+    for m in 2..=5 {
+        // Every non-zero simplex codeword has the same weight.
+        let n = (1 << m) - 1;
+        let simplex = gf2::LinearCode::simplex(m);
+        check(&simplex, n, m, 1 << (m - 1));
+        assert_eq!(simplex, gf2::LinearCode::hamming(m).dual());
+        assert!(simplex.to_subspace().elements().skip(1).all(|c| c.count_ones() == 1 << (m - 1)));
+    }
+}
+
+#[test]
+fn test_secded() {
+    // SECDED codes are systematic with the fewest check bits and tell single errors from double ones.
+    for (k, n) in [(1, 4), (4, 8), (11, 16), (26, 32), (57, 64), (64, 72)] {
+        let code = gf2::LinearCode::<u8>::secded(k);
+        assert_eq!((code.n(), code.k()), (n, k));
+        assert_eq!(code.generator().sub_matrix(0..k, 0..k), gf2::BitMatrix::identity(k));
+        assert!((code.generator() * &code.parity_check().transposed()).none());
+        let decoder = gf2::SyndromeDecoder::bounded(code.parity_check(), 1);
+        let c = code.encode(&gf2::BitVector::<u8>::random_seeded(k, k as u64));
+        for j in 0..n {
+            let e = gf2::BitVector::unit(j, n);
+            assert_eq!(decoder.decode(&c.xor(&e)), Some((c.clone(), e)));
+        }
+        for e in gf2::FixedWeight::<u8>::new(n, 2) {
+            assert_eq!(decoder.decode(&c.xor(&e)), None);
+        }
+    }
+}
+
+#[test]
+fn test_repetition_and_parity_check() {
+    // Repetition and single parity-check codes are duals.
+    for n in 2..10 {
+        let repetition = gf2::LinearCode::repetition(n);
+        check(&repetition, n, 1, n);
+        let parity = gf2::LinearCode::single_parity_check(n);
+        check(&parity, n, n - 1, 2);
+        assert_eq!(parity, repetition.dual());
+        assert_eq!(repetition.decoder().max_weight(), (n - 1) / 2);
+    }
+}
+
+#[test]
+fn test_golay() {
+    // The Golay code is perfect and the extended Golay code is self-dual with weights divisible by four.
+    let binomial = |n: usize, k: usize| (0..k).fold(1, |b, i| b * (n - i) / (i + 1));
+    let golay = gf2::LinearCode::golay();
+    check(&golay, 23, 12, 7);
+    assert_eq!((0..=3).map(|i| binomial(23, i)).sum::<usize>() << 12, 1 << 23);
+    let decoder = golay.decoder();
+    assert_eq!((decoder.len(), decoder.max_weight()), (1 << 11, 3));
+    let extended = gf2::LinearCode::extended_golay();
+    check(&extended, 24, 12, 8);
+    assert!(extended.is_self_dual());
+    assert!(extended.to_subspace().elements().all(|c| c.count_ones() % 4 == 0));
+}
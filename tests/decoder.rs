@@ -0,0 +1,72 @@
+use gf2::BitStore;
+
+#[test]
+fn test_complete() {
+    // The repetition code of length 3 has one coset for each single error.
+    let h: gf2::BitMatrix<u8> = gf2::BitMatrix::from_string("110 011").unwrap();
+    let complete = gf2::SyndromeDecoder::complete(&h);
+    assert_eq!((complete.len(), complete.max_weight()), (4, 1));
+    let leader = |s: &str| complete.coset_leader(&gf2::BitVector::<u8>::from_string(s).unwrap()).unwrap().to_string();
+    assert_eq!(leader("00"), "000");
+    assert_eq!(leader("10"), "100");
+    assert_eq!(leader("11"), "010");
+    assert_eq!(leader("01"), "001");
+
+    // The syndrome of a single error in the Hamming code is the position of the error in binary.
+    let hamming: gf2::LinearCode<u8> = gf2::LinearCode::hamming(3);
+    let complete = gf2::SyndromeDecoder::complete(hamming.parity_check());
+    assert_eq!((complete.len(), complete.max_weight()), (8, 1));
+    for j in 0..7 {
+        let e = gf2::BitVector::unit(j, 7);
+        assert_eq!(complete.coset_leader(&complete.syndrome(&e)), Some(e));
+    }
+
+    // The [8,4,4] SECDED code has 8 cosets led by single errors and 7 led by pairs of errors.
+    let secded: gf2::LinearCode<u8> = gf2::LinearCode::secded(4);
+    let complete = gf2::SyndromeDecoder::complete(secded.parity_check());
+    assert_eq!((complete.len(), complete.max_weight()), (16, 2));
+    for e in gf2::FixedWeight::<u8>::new(8, 2) {
+        let s = complete.syndrome(&e);
+        let leader = complete.coset_leader(&s).unwrap();
+        assert_eq!((complete.syndrome(&leader), leader.count_ones()), (s, 2));
+    }
+}
+
+#[test]
+fn test_bounded() {
+    // With `t = 1` the SECDED decoder corrects single errors and reports every double error.
+    let secded: gf2::LinearCode<u8> = gf2::LinearCode::secded(4);
+    let bounded = gf2::SyndromeDecoder::bounded(secded.parity_check(), 1);
+    assert_eq!((bounded.len(), bounded.max_weight()), (9, 1));
+    let c = secded.encode(&gf2::BitVector::<u8>::from_string("1011").unwrap());
+    for j in 0..8 {
+        let e = gf2::BitVector::unit(j, 8);
+        assert_eq!(bounded.coset_leader(&bounded.syndrome(&e)), Some(e.clone()));
+        assert_eq!(bounded.decode(&c.xor(&e)), Some((c.clone(), e)));
+    }
+    let e = gf2::BitVector::<u8>::from_string("11000000").unwrap();
+    assert_eq!(bounded.coset_leader(&bounded.syndrome(&e)), None);
+    assert_eq!(bounded.decode(&c.xor(&e)), None);
+    assert_eq!(bounded.decode(&c), Some((c.clone(), gf2::BitVector::zeros(8))));
+
+    // With `t = 0` only the codewords themselves decode.
+    let bounded = gf2::SyndromeDecoder::bounded(secded.parity_check(), 0);
+    assert_eq!((bounded.len(), bounded.max_weight()), (1, 0));
+    assert_eq!(bounded.decode(&c.xor(&gf2::BitVector::unit(3, 8))), None);
+}
+
+#[test]
+fn test_sparse_table() {
+    // Codes with more than 24 parity checks always use a hashed table and correct the light patterns within half the
+    // distance.
+    for seed in 1..4_u64 {
+        let g: gf2::BitMatrix = gf2::BitMatrix::random_with_rank_seeded(8, 40, 8, seed);
+        let code = gf2::LinearCode::from_generator(&g);
+        let t = ((code.d().unwrap() - 1) / 2).min(2);
+        let decoder = gf2::SyndromeDecoder::bounded(code.parity_check(), t);
+        let c = code.encode(&gf2::BitVector::ones(8));
+        for e in gf2::BoundedWeight::new(40, t) {
+            assert_eq!(decoder.decode(&c.xor(&e)), Some((c.clone(), e)));
+        }
+    }
+}
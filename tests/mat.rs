@@ -16,3 +16,615 @@ fn test_inverse() {
         }
     }
 }
+
+#[test]
+fn test_fundamental_subspaces() {
+    for (r, c) in [(1, 1), (7, 13), (40, 25), (64, 64), (70, 130)] {
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random(r, c);
+        let rank = m.rank();
+        let n = m.null_space();
+        let l = m.left_null_space();
+        assert_eq!(n.rows(), c - rank);
+        assert_eq!(l.rows(), r - rank);
+        assert_eq!(m.row_space().rows(), rank);
+        assert_eq!(m.column_space().rows(), rank);
+        if n.rows() > 0 {
+            assert_eq!(n.rank(), n.rows());
+            assert!((&m * &n.transposed()).none());
+        }
+        if l.rows() > 0 {
+            assert_eq!(l.rank(), l.rows());
+            assert!((&l * &m).none());
+        }
+    }
+}
+
+#[test]
+fn test_generalised_inverse() {
+    for (r, c) in [(1, 1), (7, 13), (40, 25), (64, 64), (70, 130)] {
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random(r, c);
+        let g = m.generalised_inverse();
+        assert_eq!((g.rows(), g.cols()), (c, r));
+        assert_eq!(&(&m * &g) * &m, m);
+        assert_eq!(&(&g * &m) * &g, g);
+        let rank = m.rank();
+        assert_eq!(m.left_inverse().is_some(), rank == c);
+        assert_eq!(m.right_inverse().is_some(), rank == r);
+    }
+    let z: gf2::BitMatrix<u8> = gf2::BitMatrix::zeros(3, 5);
+    assert!(z.generalised_inverse().none());
+}
+
+#[test]
+fn test_order_and_cycle_structure() {
+    use gf2::BitStore;
+    // Compare against brute force iteration over all the vectors for some small random matrices.
+    for n in 1..=8 {
+        for seed in 1..=20 {
+            let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(n, n, seed);
+            let mut lengths = std::collections::BTreeMap::new();
+            let mut seen = vec![false; 1 << n];
+            let mut singular = false;
+            for start in 0..1usize << n {
+                if seen[start] {
+                    continue;
+                }
+                let v0: gf2::BitVector<u8> = gf2::BitVector::from_fn(n, |i| (start >> i) & 1 == 1);
+                let (mut v, mut len) = (v0.clone(), 0u128);
+                loop {
+                    let index = v.set_bits().map(|i| 1 << i).sum::<usize>();
+                    if seen[index] && v != v0 {
+                        singular = true;
+                        break;
+                    }
+                    seen[index] = true;
+                    v = m.dot(&v);
+                    len += 1;
+                    if v == v0 {
+                        break;
+                    }
+                }
+                if singular {
+                    break;
+                }
+                *lengths.entry(len).or_insert(0u128) += 1;
+            }
+            if singular {
+                assert_eq!(m.order(), None);
+                assert_eq!(m.cycle_structure(), None);
+            }
+            else {
+                let expected: Vec<(u128, u128)> = lengths.into_iter().collect();
+                assert_eq!(m.order(), expected.iter().map(|&(l, _)| l).max());
+                assert_eq!(m.cycle_structure(), Some(expected));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_frobenius_form() {
+    // Block diagonal matrices with repeated blocks have several invariant factors.
+    for seed in 1..=10 {
+        let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(4, 4, seed);
+        let b: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(3, 3, seed + 100);
+        let mut m: gf2::BitMatrix<u8> = gf2::BitMatrix::zeros(15, 15);
+        m.replace_sub_matrix(0, 0, &a);
+        m.replace_sub_matrix(4, 4, &a);
+        m.replace_sub_matrix(8, 8, &b);
+        m.replace_sub_matrix(11, 11, &a);
+        for mat in [m, gf2::BitMatrix::random_seeded(40, 40, seed)] {
+            let (top_rows, p) = mat.frobenius_form_with_transform();
+            let f = gf2::BitMatrix::frobenius(&top_rows);
+            assert_eq!(&p * &f, &mat * &p);
+            assert!(p.inverse().is_some());
+
+            // Each invariant factor divides the next and the last one annihilates the matrix.
+            let factors: Vec<gf2::BitPolynomial<u8>> =
+                top_rows.iter().map(gf2::BitMatrix::characteristic_polynomial_companion_matrix).collect();
+            for pair in factors.windows(2) {
+                assert!(pair[1].div_rem(&pair[0]).1.is_zero());
+            }
+            assert!(factors.last().unwrap().eval_matrix(&mat).none());
+
+            // A random conjugate is similar and we can recover a transform.
+            let t: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(mat.rows(), mat.rows(), seed + 200);
+            if let Some(t_inv) = t.inverse() {
+                let other = &t_inv * &(&mat * &t);
+                assert!(mat.is_similar_to(&other));
+                let s = mat.similarity_transform_to(&other).unwrap();
+                assert_eq!(&mat * &s, &s * &other);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_primary_decomposition() {
+    use gf2::BitStore;
+    for seed in 1..=10 {
+        let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(4, 4, seed);
+        let mut m: gf2::BitMatrix<u8> = gf2::BitMatrix::zeros(12, 12);
+        m.replace_sub_matrix(0, 0, &a);
+        m.replace_sub_matrix(4, 4, &a);
+        m.replace_sub_matrix(8, 8, &a);
+        for mat in [m, gf2::BitMatrix::random_seeded(40, 40, seed)] {
+            // The component bases together form a basis for the whole space.
+            let components = mat.primary_decomposition();
+            let mut basis = components[0].basis().clone();
+            for c in &components[1..] {
+                basis.append_rows(c.basis().clone());
+            }
+            assert_eq!(basis.rows(), mat.rows());
+            assert!(basis.inverse().is_some());
+
+            for c in &components {
+                // The matrix acts on each component as the companion matrices of its elementary divisors.
+                let p = c.basis().transposed();
+                let f = gf2::BitMatrix::frobenius(&c.top_rows());
+                assert_eq!(&mat * &p, &p * &f);
+
+                // Each cyclic vector generates a piece of the right dimension that is killed by its elementary divisor.
+                let divisors = c.elementary_divisors();
+                for ((e, u), divisor) in c.cyclic_vectors().iter().zip(&divisors) {
+                    assert_eq!(divisor.degree(), e * c.factor().degree());
+                    assert!(divisor.eval_matrix(&mat).dot(u).none());
+                    let mut krylov: gf2::BitMatrix<u8> = gf2::BitMatrix::zeros(divisor.degree(), mat.rows());
+                    let mut v = u.clone();
+                    for i in 0..divisor.degree() {
+                        let next = mat.dot(&v);
+                        krylov[i] = v;
+                        v = next;
+                    }
+                    assert_eq!(krylov.rank(), divisor.degree());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_minimal_polynomial() {
+    use gf2::BitStore;
+    for seed in 1..=10 {
+        let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(4, 4, seed);
+        let b: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(3, 3, seed + 100);
+        let mut m: gf2::BitMatrix<u8> = gf2::BitMatrix::zeros(14, 14);
+        m.replace_sub_matrix(0, 0, &a);
+        m.replace_sub_matrix(4, 4, &a);
+        m.replace_sub_matrix(8, 8, &b);
+        m.replace_sub_matrix(11, 11, &b);
+        for mat in [m, gf2::BitMatrix::random_seeded(40, 40, seed)] {
+            // The minimal polynomial is the last invariant factor in the Frobenius normal form.
+            let f = mat.minimal_polynomial();
+            let top_rows = mat.frobenius_form();
+            let last = gf2::BitMatrix::characteristic_polynomial_companion_matrix(top_rows.last().unwrap());
+            assert_eq!(f, last);
+
+            // The minimal polynomial of a vector kills it but none of its proper factors do.
+            let v: gf2::BitVector<u8> = gf2::BitVector::random_seeded(mat.rows(), seed);
+            let g = mat.minimal_polynomial_of(&v);
+            assert!(g.eval_matrix(&mat).dot(&v).none());
+            for (p, _) in g.factors() {
+                let h = g.div_rem(&p).0;
+                assert!(h.eval_matrix(&mat).dot(&v).any());
+            }
+            assert!(f.div_rem(&g).1.is_zero());
+        }
+    }
+}
+
+#[test]
+fn test_blocks() {
+    for seed in 1..=10 {
+        let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(3, 5, seed);
+        let b: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(4, 7, seed + 100);
+        let c: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(5, 2, seed + 200);
+        let d: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(7, 6, seed + 300);
+
+        // The mixed-product property of the Kronecker product.
+        assert_eq!(&a.kronecker(&b) * &c.kronecker(&d), (&a * &c).kronecker(&(&b * &d)));
+
+        // The direct sum is the block diagonal matrix and splitting it gives back the blocks.
+        let z01 = gf2::BitMatrix::zeros(3, 7);
+        let z10 = gf2::BitMatrix::zeros(4, 5);
+        let m = a.direct_sum(&b);
+        assert_eq!(m, gf2::BitMatrix::from_blocks(&[&[&a, &z01], &[&z10, &b]]));
+        let blocks = m.to_blocks(&[3, 4], &[5, 7]);
+        assert_eq!(blocks[0][0], a);
+        assert_eq!(blocks[0][1], z01);
+        assert_eq!(blocks[1][0], z10);
+        assert_eq!(blocks[1][1], b);
+
+        // Stacking agrees with appending.
+        let mut h = a.clone();
+        h.append_cols(&z01);
+        assert_eq!(gf2::BitMatrix::hstack(&[&a, &z01]), h);
+        let mut v = b.clone();
+        v.append_rows(z01.clone());
+        assert_eq!(gf2::BitMatrix::vstack(&[&b, &z01]), v);
+    }
+}
+
+#[test]
+fn test_views() {
+    use gf2::BitStore;
+    for seed in 1..=10 {
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(37, 29, seed);
+
+        // Read-only views agree with sub-matrix copies for windows that are not word aligned.
+        for (rows, cols) in [(0..37, 0..29), (3..30, 5..22), (10..11, 7..8)] {
+            let view = m.view(rows.clone(), cols.clone());
+            let sub = m.sub_matrix(rows, cols);
+            assert_eq!(view.to_bit_matrix(), sub);
+            assert_eq!(view.count_ones(), sub.count_ones());
+            let v: gf2::BitVector<u8> = gf2::BitVector::random_seeded(sub.cols(), seed);
+            assert_eq!(view.dot(&v), sub.dot(&v));
+            for (j, col) in view.col_iter().enumerate() {
+                assert_eq!(col.to_vector(), sub.col(j));
+            }
+        }
+
+        // Column views read the strided bits in place.
+        let v: gf2::BitVector<u8> = gf2::BitVector::random_seeded(37, seed + 300);
+        for (j, col) in m.col_iter().enumerate() {
+            assert_eq!(col.len(), 37);
+            assert_eq!(col.count_ones(), m.col(j).count_ones());
+            assert_eq!(col.first_set(), m.col(j).first_set());
+            assert_eq!(col.none(), m.col(j).none());
+            assert_eq!(col.dot(&v), m.col(j).dot(&v));
+            assert!(col.iter().enumerate().all(|(i, bit)| bit == m[i][j]));
+        }
+
+        // Writing through a mutable view only touches the window.
+        let src: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(11, 13, seed + 100);
+        let mut expected = m.clone();
+        let mut window = expected.sub_matrix(9..20, 3..16);
+        window ^= &src;
+        expected.replace_sub_matrix(9, 3, &window);
+        let mut n = m.clone();
+        n.view_mut(9..20, 3..16).xor_eq(&src);
+        assert_eq!(n, expected);
+        n.view_mut(9..20, 3..16).copy_from(m.view(9..20, 3..16));
+        assert_eq!(n, m);
+
+        // Writing through a mutable column view.
+        let c: gf2::BitVector<u8> = gf2::BitVector::random_seeded(37, seed + 200);
+        n.col_view_mut(17).copy_store(&c);
+        assert_eq!(n.col(17), c);
+        assert_eq!(n.sub_matrix(0..37, 0..17), m.sub_matrix(0..37, 0..17));
+        n.col_view_mut(17).xor_eq(&c.slice(..));
+        assert!(n.col_view(17).none());
+        n.col_view_mut(17).flip_all().flip(3);
+        assert_eq!(n.col_view(17).count_ones(), 36);
+
+        // A mutable column view works in generic bit-store code.
+        fn fill_prefix<Store: BitStore<u8>>(store: &mut Store, k: usize) { store.copy_fn(|i| i < k); }
+        fn weight<Store: BitStore<u8>>(store: &Store) -> usize { store.count_ones() }
+        assert_eq!(weight(&n.col_view_mut(5)), m.col(5).count_ones());
+        fill_prefix(&mut n.col_view_mut(5), 20);
+        assert_eq!(n.col(5), gf2::BitVector::from_fn(37, |i| i < 20));
+        fill_prefix(&mut n.col_view_mut(5).slice_mut(15..25), 8);
+        assert_eq!(n.col(5), gf2::BitVector::from_fn(37, |i| i < 23));
+        let col = n.col_view_mut(6);
+        assert_eq!(col.to_binary_string(), m.col(6).to_binary_string());
+        assert_eq!(gf2::BitVector::from_store(&col), m.col(6));
+    }
+}
+
+#[test]
+fn test_views_without_rows() {
+    use gf2::BitStore;
+    // A window with no rows is fine and so is a view of a bit-matrix with no rows.
+    let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(5, 7, 1);
+    let view = m.view(2..2, 1..4);
+    assert_eq!((view.rows(), view.cols(), view.row_iter().len()), (0, 3, 0));
+    assert!(view.is_empty() && view.none());
+    assert_eq!(view.dot(&gf2::BitVector::<u8>::ones(3)).len(), 0);
+    let empty: gf2::BitMatrix<u8> = gf2::BitMatrix::new();
+    let view = gf2::BitMatrixView::from(&empty);
+    assert_eq!((view.rows(), view.cols(), view.count_ones()), (0, 0, 0));
+    assert_eq!(view.to_bit_matrix(), empty);
+    let mut empty = empty;
+    empty.view_mut(.., ..).set_all(true).copy_from(&gf2::BitMatrix::new());
+}
+
+#[test]
+#[should_panic(expected = "at least one column")]
+fn test_view_with_no_columns() {
+    let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(5, 7, 1);
+    let _view = m.view(0..3, 4..4);
+}
+
+#[test]
+fn test_permutations() {
+    for seed in 1..=20_u64 {
+        let n = 5 + seed as usize * 3;
+        let p = gf2::BitPermutation::random_seeded(n, seed);
+        let q = gf2::BitPermutation::random_seeded(n, seed + 100);
+        let p_mat: gf2::BitMatrix<u8> = p.to_matrix();
+        let q_mat: gf2::BitMatrix<u8> = q.to_matrix();
+
+        // Conversions round trip.
+        assert_eq!(gf2::BitPermutation::from_matrix(&p_mat), Some(p.clone()));
+        assert_eq!(gf2::BitPermutation::from_swaps(&p.to_swaps()), p);
+        assert_eq!(gf2::BitPermutation::from_cycles(n, &p.cycles()), Some(p.clone()));
+        assert_eq!(gf2::BitPermutation::from_vec(p.to_vec()), Some(p.clone()));
+
+        // Composition and inverses match products and transposes of permutation matrices.
+        assert_eq!(p.compose(&q).to_matrix::<u8>(), &p_mat * &q_mat);
+        assert_eq!(p.inverse().to_matrix::<u8>(), p_mat.transposed());
+        assert!(p.compose(&p.inverse()).is_identity());
+
+        // The sign is multiplicative and matches a count of transpositions.
+        assert_eq!(p.compose(&q).sign(), p.sign() * q.sign());
+        let swaps = p.to_swaps().iter().enumerate().filter(|&(i, &j)| i != j).count();
+        assert_eq!(p.sign(), if swaps.is_multiple_of(2) { 1 } else { -1 });
+
+        // The order is the first power that is the identity.
+        let order = p.order().unwrap() as usize;
+        let mut power = p.clone();
+        for _ in 1..order {
+            assert!(!power.is_identity());
+            power = power.compose(&p);
+        }
+        assert!(power.is_identity());
+
+        // Acting on bit-stores, rows and columns.
+        let v: gf2::BitVector<u8> = gf2::BitVector::random_seeded(n, seed + 200);
+        assert_eq!(p.apply(&v), p_mat.dot(&v));
+        let mut w = v.clone();
+        p.apply_in_place(&mut w);
+        assert_eq!(w, p.apply(&v));
+        let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(n, n + 3, seed + 300);
+        assert_eq!(p.permute_rows(&a), &p_mat * &a);
+        let b: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(n + 3, n, seed + 400);
+        let mut c = b.clone();
+        p.permute_cols_in_place(&mut c);
+        assert_eq!(c, &b * &p_mat.transposed());
+        assert_eq!(p.permute_cols(&b), c);
+
+        // The LU row permutation matches the permutation matrix.
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(n, n, seed + 500);
+        let lu = gf2::BitLU::new(&m);
+        assert_eq!(lu.permutation().to_matrix::<u8>(), lu.P());
+        assert_eq!(lu.permutation_vector(), lu.permutation().to_vec());
+    }
+}
+
+#[test]
+fn test_random_structured() {
+    for seed in 1..=20_u64 {
+        let n = 10 + seed as usize;
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_invertible_seeded(n, seed);
+        assert_eq!(m.rank(), n);
+        for k in [0, 1, n / 2, n] {
+            let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_with_rank_seeded(n, n + 7, k, seed);
+            assert_eq!(m.rank(), k);
+            assert_eq!((m.rows(), m.cols()), (n, n + 7));
+        }
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_alternating_seeded(n, seed);
+        assert!(m.is_symmetric());
+        assert_eq!(m.count_ones_on_diagonal(), 0);
+        let u: gf2::BitMatrix<u8> = gf2::BitMatrix::random_unit_upper_seeded(n, seed);
+        let l: gf2::BitMatrix<u8> = gf2::BitMatrix::random_unit_lower_seeded(n, seed);
+        assert_eq!(u.unit_upper(), u);
+        assert_eq!(l.unit_lower(), l);
+        let p = gf2::BitPolynomial::<u8>::random_seeded(n, seed);
+        let m = gf2::BitMatrix::random_with_characteristic_polynomial_seeded(&p, seed);
+        assert_eq!(m.characteristic_polynomial(), p);
+    }
+
+    // The invertible generator is uniform on the 168 elements of GL(3,2) and the rank 1 generator is uniform on the
+    // 49 bit-matrices of rank 1 in the 3 x 3 bit-matrices.
+    for (k, size) in [(3, 168), (1, 49)] {
+        let trials = 200 * size;
+        let mut counts = std::collections::HashMap::new();
+        for trial in 0..trials {
+            let m: gf2::BitMatrix<u8> = if k == 3 {
+                gf2::BitMatrix::random_invertible_seeded(3, trial as u64 + 1)
+            }
+            else {
+                gf2::BitMatrix::random_with_rank_seeded(3, 3, k, trial as u64 + 1)
+            };
+            *counts.entry(m.to_compact_binary_string()).or_insert(0_usize) += 1;
+        }
+        assert_eq!(counts.len(), size);
+        assert!(counts.values().all(|&count| (120..=280).contains(&count)));
+    }
+}
+
+#[test]
+fn test_symplectic() {
+    use gf2::BitStore;
+    for seed in 1..=20_u64 {
+        let n = seed as usize;
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_symplectic_seeded(n, seed);
+        assert!(m.is_symplectic());
+        assert!(m.transposed().is_symplectic());
+        let j = gf2::BitMatrix::symplectic_form(n);
+        assert_eq!(&(&m.transposed() * &j) * &m, j);
+
+        // The transvection decomposition multiplies back to the bit-matrix.
+        let hs = m.transvection_decomposition().unwrap();
+        assert!(hs.len() <= 4 * n);
+        let mut product = gf2::BitMatrix::identity(2 * n);
+        for h in &hs {
+            product = &product * &gf2::BitMatrix::transvection(h);
+        }
+        assert_eq!(product, m);
+
+        // Symplectic Gram-Schmidt on a random span and on an isotropic span.
+        let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(n + 3, 2 * n + 2, seed);
+        let b: gf2::BitMatrix<u8> = gf2::BitMatrix::from_fn(n + 1, 2 * n + 2, |i, j| i == j);
+        for a in [a, b] {
+            let (pairs, isotropic) = a.symplectic_gram_schmidt();
+            let mut rows = Vec::new();
+            for (e, f) in &pairs {
+                assert!(e.symplectic_dot(f));
+                rows.push(e.clone());
+                rows.push(f.clone());
+            }
+            rows.extend(isotropic.iter().cloned());
+            let all = gf2::BitMatrix::from_fn(rows.len(), 2 * n + 2, |i, j| rows[i][j]);
+            assert_eq!(all.rank(), a.rank());
+            assert_eq!(all.row_space(), a.row_space());
+            for i in 0..all.rows() {
+                for k in i + 1..all.rows() {
+                    let partners = i < 2 * pairs.len() && i % 2 == 0 && k == i + 1;
+                    assert_eq!(all[i].symplectic_dot(&all[k]), partners);
+                }
+            }
+        }
+    }
+
+    // The random generator is uniform on the 720 elements of Sp(4,2).
+    let trials = 100 * 720;
+    let mut counts = std::collections::HashMap::new();
+    for trial in 0..trials {
+        let m: gf2::BitMatrix<u8> = gf2::BitMatrix::random_symplectic_seeded(2, trial as u64 + 1);
+        *counts.entry(m.to_compact_binary_string()).or_insert(0_usize) += 1;
+    }
+    assert_eq!(counts.len(), 720);
+    assert!(counts.values().all(|&count| (50..=150).contains(&count)));
+}
+
+#[test]
+fn test_boolean_semiring() {
+    for seed in 1..20_u64 {
+        let n = 5 + seed as usize;
+        let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_biased_seeded(n, n, 0.15, seed);
+        let b: gf2::BitMatrix<u8> = gf2::BitMatrix::random_biased_seeded(n, n, 0.15, seed + 100);
+
+        // Compare the word-parallel product with the naive OR of ANDs.
+        let ab = a.boolean_dot_matrix(&b);
+        for i in 0..n {
+            for j in 0..n {
+                assert_eq!(ab[i][j], (0..n).any(|k| a[i][k] && b[k][j]));
+            }
+        }
+        let v = b.row(0).clone();
+        let av = a.boolean_dot(&v);
+        for i in 0..n {
+            assert_eq!(av[i], (0..n).any(|k| a[i][k] && v[k]));
+        }
+
+        // Boolean powers are repeated products.
+        let mut power = gf2::BitMatrix::identity(n);
+        for e in 0..5 {
+            assert_eq!(a.boolean_to_the(e), power);
+            power = power.boolean_dot_matrix(&a);
+        }
+
+        // The transitive closure is the OR of the first n powers and agrees with the reachability queries.
+        let closure = a.transitive_closure();
+        let mut expected: gf2::BitMatrix<u8> = gf2::BitMatrix::zeros(n, n);
+        let mut power = a.clone();
+        for _ in 0..n {
+            expected |= &power;
+            power = power.boolean_dot_matrix(&a);
+        }
+        assert_eq!(closure, expected);
+        let reflexive = a.reflexive_transitive_closure();
+        assert_eq!(reflexive, &closure | &gf2::BitMatrix::identity(n));
+        for i in 0..n {
+            assert_eq!(a.reachable_from(i), reflexive.row(i).clone());
+        }
+    }
+}
+
+#[test]
+fn test_dot_matrix() {
+    // Compare the word-level product with the element-wise definition for shapes that are not word aligned.
+    for seed in 1..=10_u64 {
+        let (r, k, c) = (3 + seed as usize * 5, 7 + seed as usize * 3, 1 + seed as usize * 7);
+        let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(r, k, seed);
+        let b: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(k, c, seed + 100);
+        let expected = gf2::BitMatrix::from_fn(r, c, |i, j| (0..k).filter(|&l| a[i][l] && b[l][j]).count() % 2 == 1);
+        assert_eq!(a.dot_matrix(&b), expected);
+    }
+}
+
+#[test]
+fn test_lu_solve_matrix() {
+    // The decomposition of a random invertible bit-matrix solves for many right-hand sides at once.
+    for seed in 1..=10_u64 {
+        let n = 5 + seed as usize * 7;
+        let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_invertible_seeded(n, seed);
+        let b: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(n, 3 + seed as usize * 5, seed + 100);
+        let lu = gf2::BitLU::new(&a);
+        assert_eq!(lu.permutation().permute_rows(&a), &lu.L() * &lu.U());
+        let x = lu.X(&b).unwrap();
+        assert_eq!(&a * &x, b);
+        for j in 0..b.cols() {
+            assert_eq!(lu.x(&b.col(j)).unwrap(), x.col(j));
+        }
+    }
+}
+
+#[test]
+fn test_characteristic_polynomial() {
+    // Similar bit-matrices share a characteristic polynomial which also annihilates them (Cayley-Hamilton).
+    for seed in 1..=10_u64 {
+        let n = 3 + seed as usize * 4;
+        let top_row: gf2::BitVector<u8> = gf2::BitVector::random_seeded(n, seed);
+        let c = gf2::BitMatrix::companion(&top_row);
+        let p = gf2::BitMatrix::characteristic_polynomial_companion_matrix(&top_row);
+        let s: gf2::BitMatrix<u8> = gf2::BitMatrix::random_invertible_seeded(n, seed + 100);
+        let a = &(&s.inverse().unwrap() * &c) * &s;
+        assert_eq!(a.characteristic_polynomial(), p);
+        assert!(p.eval_matrix(&a).is_zero());
+
+        // The characteristic polynomial of a direct sum is the product of those of its parts.
+        let b: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(n / 2, n / 2, seed + 200);
+        assert_eq!(a.direct_sum(&b).characteristic_polynomial(), &p * &b.characteristic_polynomial());
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel() {
+    use gf2::BitStore;
+
+    // The results must not depend on the number of threads, so compute everything with one thread and then with four.
+    // A low threshold means even these small problems get shared across the threads.
+    let n = 200;
+    let a: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(n, n, 1);
+    let b: gf2::BitMatrix<u8> = gf2::BitMatrix::random_seeded(n, n, 2);
+    let g: gf2::BitMatrix<u8> = gf2::BitMatrix::random_invertible_seeded(n, 3);
+    let v: gf2::BitVector<u8> = gf2::BitVector::random_seeded(1 << 16, 4);
+    let w: gf2::BitVector<u8> = gf2::BitVector::random_seeded(1 << 16, 5);
+    let run = |threads: usize| {
+        gf2::set_num_threads(threads);
+        gf2::set_parallel_threshold(1 << 10);
+        let product = a.dot_matrix(&b);
+        let mut echelon = a.clone();
+        let echelon_pivots = echelon.to_echelon_form();
+        let mut reduced = a.clone();
+        let reduced_pivots = reduced.to_reduced_echelon_form();
+        let lu = gf2::BitLU::new(&a);
+        let lu_parts = (lu.L(), lu.U(), lu.swaps().to_vec(), lu.rank());
+        let inverses = (gf2::BitLU::new(&g).inverse(), g.inverse());
+        let characteristic_polynomial = a.characteristic_polynomial();
+        let mut stores = vec![&v ^ &w, &v & &w, &v | &w, v.flipped()];
+        let mut slice_xor = v.clone();
+        slice_xor.slice_mut(3..(1 << 15)).xor_eq(&w.slice(5..(1 << 15) + 2));
+        stores.push(slice_xor);
+        let counts = (v.count_ones(), v.slice(7..(1 << 16) - 1).count_ones());
+        gf2::set_num_threads(0);
+        gf2::set_parallel_threshold(0);
+        let echelon_forms = (echelon, echelon_pivots, reduced, reduced_pivots);
+        (product, echelon_forms, lu_parts, inverses, characteristic_polynomial, stores, counts)
+    };
+    let serial = run(1);
+    let parallel = run(4);
+    assert!(serial == parallel);
+
+    // Spot check the parallel results against the definitions.
+    let (product, (_, _, reduced, _), (l, u, _, rank), (lu_inverse, inverse), ..) = parallel;
+    assert_eq!(product.row(5).clone(), b.left_dot(a.row(5)));
+    assert_eq!(reduced.rank(), rank);
+    assert_eq!(gf2::BitLU::new(&a).permutation().permute_rows(&a), &l * &u);
+    assert_eq!(lu_inverse, inverse);
+    assert!((&g * &inverse.unwrap()).is_identity());
+}
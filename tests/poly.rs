@@ -0,0 +1,14 @@
+#[test]
+fn test_factors() {
+    for n in 1..=60 {
+        let p: gf2::BitPolynomial<u8> = gf2::BitPolynomial::random(n);
+        let mut product: gf2::BitPolynomial<u8> = gf2::BitPolynomial::one();
+        for (f, m) in p.factors() {
+            assert!(f.is_irreducible());
+            for _ in 0..m {
+                product *= &f;
+            }
+        }
+        assert_eq!(product, p);
+    }
+}
@@ -0,0 +1,92 @@
+use gf2::*;
+
+// The types of quadratic form and bit-matrix we are testing.
+type QF = QuadraticForm<u8>;
+type BM = BitMatrix<u8>;
+
+#[test]
+fn test_arf_invariant() {
+    // x0 x1
+    let q = QF::new(&BM::from_string("01 00").unwrap());
+    assert_eq!((q.rank(), q.arf_invariant()), (2, Some(false)));
+
+    // x0 x1 + x0 + x1
+    let q = QF::new(&BM::from_string("11 01").unwrap());
+    assert_eq!((q.rank(), q.arf_invariant()), (2, Some(true)));
+
+    // x0 x1 + x2 does not vanish on the radical spanned by x2.
+    let q = QF::new(&BM::from_string("010 000 001").unwrap());
+    assert_eq!((q.rank(), q.arf_invariant()), (3, None));
+
+    // x0 x1 in three variables vanishes on its radical.
+    let q = QF::new(&BM::from_string("010 000 000").unwrap());
+    assert_eq!((q.rank(), q.arf_invariant()), (2, Some(false)));
+
+    // x0 x1 + x2 x3 + x2 + x3 is a hyperbolic plane plus an anisotropic one.
+    let q = QF::new(&BM::from_string("0100 0000 0011 0001").unwrap());
+    assert_eq!((q.rank(), q.arf_invariant()), (4, Some(true)));
+}
+
+#[test]
+fn test_canonical_form() {
+    let q = QF::new(&BM::from_string("11 01").unwrap());
+    let (c, p) = q.canonical_form();
+    assert_eq!(c.to_string(), "x0^2 + x0 x1 + x1^2");
+    assert_eq!(q.transformed(&p), c);
+
+    let q = QF::new(&BM::from_string("000 001 000").unwrap());
+    let (c, p) = q.canonical_form();
+    assert_eq!(c.to_string(), "x0 x1");
+    assert_eq!(q.transformed(&p), c);
+
+    for seed in 1..=20_u64 {
+        let q = QF::random_seeded(7, seed);
+        let (c, p) = q.canonical_form();
+        assert_eq!(q.transformed(&p), c);
+        assert_eq!(p.rank(), 7);
+        assert_eq!((c.rank(), c.arf_invariant()), (q.rank(), q.arf_invariant()));
+    }
+}
+
+#[test]
+fn test_equivalence() {
+    let q1 = QF::new(&BM::from_string("0100 0000 0001 0000").unwrap());
+    let q2 = QF::new(&BM::from_string("0010 0001 0000 0000").unwrap());
+    let q3 = QF::new(&BM::from_string("0100 0000 0011 0001").unwrap());
+    assert!(q1.is_equivalent_to(&q2));
+    assert!(!q1.is_equivalent_to(&q3));
+
+    for seed in 1..=20_u64 {
+        let q = QF::random_seeded(6, seed);
+        let s = BM::random_invertible_seeded(6, seed + 100);
+        assert!(q.is_equivalent_to(&q.transformed(&s)));
+    }
+}
+
+#[test]
+fn test_number_of_zeros() {
+    let zeros = |q: &QF| {
+        let n = q.dim();
+        (0..1_usize << n).filter(|&k| !q.eval(&BitVector::<u8>::from_fn(n, |i| (k >> i) & 1 == 1))).count()
+    };
+    assert_eq!(zeros(&QF::new(&BM::from_string("01 00").unwrap())), 3);
+    assert_eq!(zeros(&QF::new(&BM::from_string("11 01").unwrap())), 1);
+    assert_eq!(zeros(&QF::new(&BM::from_string("010 000 001").unwrap())), 4);
+    assert_eq!(zeros(&QF::new(&BM::from_string("010 000 000").unwrap())), 6);
+    assert_eq!(zeros(&QF::zero(3)), 8);
+}
+
+#[test]
+fn test_bilinear_form() {
+    let q = QF::new(&BM::from_string("11 01").unwrap());
+    let b = q.bilinear_form();
+    assert_eq!(b.to_compact_binary_string(), "01 10");
+    assert_eq!(b.pfaffian(), Some(true));
+    assert_eq!(q.radical().rows(), 0);
+
+    let q = QF::new(&BM::from_string("010 000 001").unwrap());
+    let b = q.bilinear_form();
+    assert!(b.is_alternating());
+    assert_eq!(b.pfaffian(), Some(false));
+    assert_eq!(q.radical().to_compact_binary_string(), "001");
+}
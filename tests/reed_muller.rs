@@ -0,0 +1,69 @@
+use gf2::BitStore;
+
+#[test]
+fn test_parameters() {
+    let binomial = |n: usize, k: usize| (0..k).fold(1, |b, i| b * (n - i) / (i + 1));
+    for m in 1..=5 {
+        for r in 0..=m {
+            // The dimension is the number of monomials of degree at most `r` in `m` variables.
+            let rm = gf2::ReedMuller::<u8>::new(r, m);
+            let k = (0..=r).map(|i| binomial(m, i)).sum::<usize>();
+            assert_eq!((rm.n(), rm.k(), rm.generator().rank()), (1 << m, k, k));
+            assert_eq!(rm.code().d(), Some(rm.d()));
+        }
+    }
+}
+
+#[test]
+fn test_duality() {
+    for m in 1..=5 {
+        for r in 0..m {
+            let rm = gf2::ReedMuller::<u8>::new(r, m);
+            assert_eq!(&rm.code().dual(), gf2::ReedMuller::new(m - r - 1, m).code());
+        }
+    }
+}
+
+#[test]
+fn test_majority_decode() {
+    // Reed's decoder corrects every error pattern of weight less than `d / 2`.
+    for m in 3..=7 {
+        for r in 0..m - 1 {
+            let rm = gf2::ReedMuller::<u8>::new(r, m);
+            let u = gf2::BitVector::from_fn(rm.k(), |i| i % 3 != 1);
+            let mut received = rm.encode(&u);
+            for j in (1..rm.n()).step_by(5).take((rm.d() - 1) / 2) {
+                received.flip(j);
+            }
+            assert_eq!(rm.majority_decode(&received), u);
+        }
+    }
+}
+
+#[test]
+fn test_hadamard_decode_nearest() {
+    // The Hadamard decoder finds a nearest codeword to any received word.
+    let rm = gf2::ReedMuller::<u8>::new(1, 4);
+    let codewords: Vec<_> = rm.code().to_subspace().elements().collect();
+    for seed in 1..100_u64 {
+        let received = gf2::BitVector::random_seeded(16, seed);
+        let decoded = rm.encode(&rm.hadamard_decode(&received));
+        let nearest = codewords.iter().map(|c| c.xor(&received).count_ones()).min().unwrap();
+        assert_eq!(decoded.xor(&received).count_ones(), nearest);
+    }
+}
+
+#[test]
+fn test_first_order_decoders_agree() {
+    // Both decoders correct the same errors for the first order codes.
+    for m in 2..=10 {
+        let rm = gf2::ReedMuller::<u8>::new(1, m);
+        let u = gf2::BitVector::random_seeded(m + 1, m as u64);
+        let mut received = rm.encode(&u);
+        for j in (0..rm.n()).step_by(3).take((rm.d() - 1) / 2) {
+            received.flip(j);
+        }
+        assert_eq!(rm.hadamard_decode(&received), u);
+        assert_eq!(rm.majority_decode(&received), u);
+    }
+}
@@ -0,0 +1,111 @@
+#[test]
+fn test_products() {
+    for seed in 1..=10 {
+        let m: gf2::SparseBitMatrix<u8> = gf2::SparseBitMatrix::random_seeded(70, 90, 6, seed);
+        let dense = m.to_bit_matrix();
+        assert!(gf2::SparseBitMatrix::from_bit_matrix(&dense) == m);
+        let v: gf2::BitVector<u8> = gf2::BitVector::random_seeded(90, seed);
+        let u: gf2::BitVector<u8> = gf2::BitVector::random_seeded(70, seed);
+        assert_eq!(m.dot(&v), dense.dot(&v));
+        assert_eq!(m.transpose_dot(&u), dense.transposed().dot(&u));
+        assert_eq!(m.transposed().dot(&u), m.transpose_dot(&u));
+    }
+}
+
+#[test]
+fn test_null_vectors() {
+    use gf2::BitStore;
+    for seed in 1..=5 {
+        for (r, c, k) in [(100, 120, 4), (600, 600, 8), (800, 900, 10), (900, 800, 6)] {
+            let m: gf2::SparseBitMatrix<u32> = gf2::SparseBitMatrix::random_seeded(r, c, k, seed);
+            let null = m.null_vectors();
+            assert_eq!(null.rank(), null.rows());
+            for i in 0..null.rows() {
+                assert!(m.dot(&null[i]).none());
+            }
+
+            // Small matrices get the whole null space and large ones get some of it.
+            let nullity = m.to_bit_matrix().nullity();
+            if c <= 256 {
+                assert_eq!(null.rows(), nullity);
+            }
+            else {
+                assert!(null.rows() <= nullity);
+                assert_eq!(null.rows() == 0, nullity == 0);
+            }
+        }
+    }
+
+    // With no equations every vector is a null vector but we only get a bounded number of them.
+    for (c, k) in [(0, 0), (5, 5), (300, 64), (1_000_000, 64)] {
+        let m: gf2::SparseBitMatrix<u32> = gf2::SparseBitMatrix::zeros(0, c);
+        let null = m.null_vectors();
+        assert_eq!((null.rows(), null.cols()), (k, if k > 0 { c } else { 0 }));
+        assert!((0..k).all(|i| null[i].count_ones() == 1 && null[i][i]));
+    }
+}
+
+#[test]
+fn test_solve() {
+    use gf2::BitStore;
+    for seed in 1..=5 {
+        for (r, c, k) in [(100, 100, 4), (700, 700, 8), (700, 650, 8), (650, 700, 8)] {
+            let m: gf2::SparseBitMatrix = gf2::SparseBitMatrix::random_seeded(r, c, k, seed);
+            let x0: gf2::BitVector = gf2::BitVector::random_seeded(c, seed);
+            let b = m.dot(&x0);
+            let x = m.solve(&b).unwrap();
+            assert_eq!(m.dot(&x), b);
+
+            // A random right-hand side is solvable exactly when the dense solver says the system is consistent.
+            let b: gf2::BitVector = gf2::BitVector::random_seeded(r, seed + 100);
+            let consistent = m.to_bit_matrix().solver_for(&b).is_consistent();
+            assert_eq!(m.solve(&b).is_some(), consistent);
+        }
+    }
+
+    // A system with no equations is solved by anything.
+    for c in [5, 1000] {
+        let m: gf2::SparseBitMatrix = gf2::SparseBitMatrix::zeros(0, c);
+        let x = m.solve(&gf2::BitVector::zeros(0)).unwrap();
+        assert_eq!(x.len(), c);
+    }
+}
+
+#[test]
+fn test_structured_gauss() {
+    use gf2::BitStore;
+    for seed in 1..=5 {
+        for (r, c, k) in [(1, 1, 1), (50, 60, 1), (300, 300, 3), (400, 380, 4), (380, 400, 5)] {
+            let m: gf2::SparseBitMatrix<u16> = gf2::SparseBitMatrix::random_seeded(r, c, k, seed);
+            let dense = m.to_bit_matrix();
+            for max_cost in [0, 4, 1000] {
+                // The kernel of the full matrix is the lift of the kernel of the core.
+                let zero: gf2::BitVector<u16> = gf2::BitVector::zeros(r);
+                let solver = gf2::StructuredGauss::with_max_cost(&m, &zero, max_cost);
+                assert_eq!(solver.eliminated_count() + solver.core_cols().len(), c);
+                if solver.core().rows() > 0 {
+                    assert_eq!(solver.core_cols().len(), solver.core().cols());
+                }
+                let kernel = solver.kernel_basis();
+                assert_eq!(kernel.rows(), dense.nullity());
+                assert_eq!(kernel.rank(), kernel.rows());
+                for i in 0..kernel.rows() {
+                    assert!(m.dot(&kernel[i]).none());
+                }
+
+                // Consistent and random right-hand sides.
+                let b = m.dot(&gf2::BitVector::random_seeded(c, seed));
+                let x = gf2::StructuredGauss::with_max_cost(&m, &b, max_cost).x().unwrap();
+                assert_eq!(m.dot(&x), b);
+                let x = m.structured_solver_for(&b.slice(..)).x().unwrap();
+                assert_eq!(m.dot(&x), b);
+                let b: gf2::BitVector<u16> = gf2::BitVector::random_seeded(r, seed + 100);
+                let x = gf2::StructuredGauss::with_max_cost(&m, &b, max_cost).x();
+                assert_eq!(x.is_some(), dense.solver_for(&b).is_consistent());
+                if let Some(x) = x {
+                    assert_eq!(m.dot(&x), b);
+                }
+            }
+        }
+    }
+}
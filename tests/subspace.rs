@@ -0,0 +1,107 @@
+use gf2::*;
+
+// The types of subspace, bit-vector, and bit-matrix we are testing.
+type BS = BitSubspace<u8>;
+type BV = BitVector<u8>;
+type BM = BitMatrix<u8>;
+
+#[test]
+fn test_from_matrix() {
+    // The canonical basis is in reduced echelon form with the pivots in increasing order.
+    let u = BS::from_matrix(&BM::from_string("1100 0110 1010").unwrap());
+    assert_eq!((u.vector_len(), u.dim(), u.codim()), (4, 2, 2));
+    assert_eq!(u.to_matrix(), BM::from_string("1010 0110").unwrap());
+    assert_eq!(u.pivots(), &[0, 1]);
+    assert_eq!(u, BS::from_vectors(4, &[BV::from_string("0110").unwrap(), BV::from_string("1010").unwrap()]));
+
+    let u = BS::from_matrix(&BM::from_string("00110 10011").unwrap());
+    assert_eq!(u.to_matrix(), BM::from_string("10011 00110").unwrap());
+    assert_eq!(u.pivots(), &[0, 2]);
+    assert!(BS::full(3).is_full());
+    assert_eq!(BS::from_matrix(&BM::identity(3)), BS::full(3));
+}
+
+#[test]
+fn test_no_vectors() {
+    // Without any rows the length has to be given explicitly.
+    let zero: BS = BS::from_vectors(5, &[]);
+    assert_eq!(zero, BS::zero(5));
+    assert_eq!(zero.vector_len(), 5);
+    assert_eq!(zero.orthogonal_complement(), BS::full(5));
+    assert_eq!(zero.elements().count(), 1);
+    assert_eq!(zero.coset_representatives().count(), 32);
+
+    // Rows that are all zero still fix the length.
+    let m: BM = BM::zeros(2, 5);
+    assert_eq!(BS::from_matrix(&m), zero);
+    assert_eq!(BS::kernel_of(&m), BS::full(5));
+}
+
+#[test]
+#[should_panic(expected = "at least one row")]
+fn test_from_matrix_with_no_rows() {
+    let m: BM = BM::zeros(0, 5);
+    let _ = BS::from_matrix(&m);
+}
+
+#[test]
+fn test_sum_and_intersection() {
+    // U is {0000, 1100, 0110, 1010} and W is {0000, 0011, 0110, 0101} so they share 0110 and add up to the even
+    // weight vectors.
+    let u = BS::from_matrix(&BM::from_string("1100 0110").unwrap());
+    let w = BS::from_matrix(&BM::from_string("0011 0110").unwrap());
+    let sum = u.sum(&w);
+    let intersection = u.intersection(&w);
+    assert_eq!(sum.dim(), 3);
+    assert_eq!(sum, BS::kernel_of(&BM::ones(1, 4)));
+    assert_eq!(intersection, BS::from_matrix(&BM::from_string("0110").unwrap()));
+    assert!(intersection.is_subspace_of(&u) && intersection.is_subspace_of(&w));
+    assert!(u.is_subspace_of(&sum) && !sum.is_subspace_of(&u));
+
+    // Subspaces that only meet in zero.
+    let x = BS::from_matrix(&BM::from_string("0001").unwrap());
+    assert!(u.intersection(&x).is_zero());
+    assert_eq!(u.sum(&x).dim(), 3);
+    assert!(sum.sum(&x).is_full());
+}
+
+#[test]
+fn test_orthogonal_complement() {
+    // x0 + x1 = x1 + x2 = 0 leaves x0 = x1 = x2 with x3 free.
+    let u = BS::from_matrix(&BM::from_string("1100 0110").unwrap());
+    let perp = u.orthogonal_complement();
+    assert_eq!(perp, BS::from_matrix(&BM::from_string("1110 0001").unwrap()));
+    assert_eq!(perp.orthogonal_complement(), u);
+
+    // The even weight vectors are orthogonal to the all ones vector.
+    let even = BS::kernel_of(&BM::ones(1, 4));
+    assert_eq!(even.orthogonal_complement(), BS::from_matrix(&BM::ones(1, 4)));
+}
+
+#[test]
+fn test_coordinates() {
+    let u = BS::from_matrix(&BM::from_string("1100 0110").unwrap());
+    assert_eq!(u.coordinates(&BV::from_string("1100").unwrap()).unwrap().to_string(), "11");
+    assert_eq!(u.coordinates(&BV::from_string("1010").unwrap()).unwrap().to_string(), "10");
+    assert_eq!(u.coordinates(&BV::zeros(4)).unwrap().to_string(), "00");
+    assert!(u.coordinates(&BV::from_string("0001").unwrap()).is_none());
+    assert_eq!(u.element(&BV::from_string("01").unwrap()).to_string(), "0110");
+}
+
+#[test]
+fn test_cosets() {
+    // The quotient by a plane in GF(2)^4 has four cosets that between them cover all sixteen vectors.
+    let u = BS::from_matrix(&BM::from_string("1100 0110").unwrap());
+    let v = BV::from_string("1011").unwrap();
+    assert_eq!(u.coset_representative(&v).to_string(), "0001");
+    assert_eq!(u.quotient_coordinates(&v).to_string(), "01");
+    assert_eq!(u.quotient_element(&BV::from_string("11").unwrap()).to_string(), "0011");
+    let coset: Vec<String> = u.coset(&v).map(|x| x.to_string()).collect();
+    assert_eq!(coset, ["1011", "0001", "0111", "1101"]);
+
+    let mut seen = std::collections::HashSet::new();
+    for r in u.coset_representatives() {
+        assert!(u.coset(&r).all(|x| u.coset_representative(&x) == r && seen.insert(x)));
+    }
+    assert_eq!(seen.len(), 16);
+}
@@ -29,6 +29,19 @@ fn test_trailing_zeros() {
     }
 }
 
+#[test]
+fn test_trailing_zeros_full_words() {
+    // When the length is a multiple of the word size the last word has no unused bits to subtract.
+    for n in [8, 16, 64] {
+        let mut v = BV::zeros(n);
+        assert_eq!(v.trailing_zeros(), n);
+        for i in 0..n {
+            v.set(i, true);
+            assert_eq!(v.trailing_zeros(), n - i - 1, "v = {v} so expected trailing zeros to be {}", n - i - 1);
+        }
+    }
+}
+
 #[test]
 fn test_all() {
     let mut v: BV = BV::zeros(3);
@@ -188,3 +201,48 @@ fn assign_from_store_handles_slices() {
 
     assert_eq!(dst.to_string(), slice.to_string());
 }
+
+#[test]
+fn test_gray_code() {
+    // Lengths that span several words, compared with the integer Gray code.
+    for n in [0, 1, 7, 8, 9, 17] {
+        let mut count = 0_u32;
+        let mut previous: Option<BV> = None;
+        for (v, flipped) in GrayCode::<u8>::new(n) {
+            assert_eq!(v, BV::from_fn(n, |i| ((count ^ (count >> 1)) >> i) & 1 == 1));
+            assert_eq!(v.from_gray(), BV::from_fn(n, |i| (count >> i) & 1 == 1));
+            assert_eq!(v.from_gray().to_gray(), v);
+            if let Some(p) = previous {
+                let i = flipped.unwrap();
+                assert_eq!((&p ^ &v).set_bits().collect::<Vec<_>>(), [i]);
+            }
+            previous = Some(v);
+            count += 1;
+        }
+        assert_eq!(count, 1 << n);
+    }
+}
+
+#[test]
+fn test_weight_enumeration() {
+    let n = 18;
+    for k in [0, 1, 2, 5, 17, 18, 19] {
+        let all: Vec<BV> = FixedWeight::new(n, k).collect();
+        let expected: Vec<BV> = (0..1_u32 << n)
+            .filter(|x| x.count_ones() as usize == k)
+            .map(|x| BV::from_fn(n, |i| (x >> i) & 1 == 1))
+            .collect();
+        assert_eq!(all, expected);
+        for (r, v) in all.iter().enumerate().step_by(97) {
+            assert_eq!(FixedWeight::rank(v), r);
+            assert_eq!(&FixedWeight::unrank(n, k, r), v);
+        }
+    }
+    let bounded: Vec<BV> = BoundedWeight::new(n, 3).collect();
+    assert_eq!(bounded.len(), 1 + 18 + 153 + 816);
+    assert!(bounded.windows(2).all(|w| w[0].count_ones() <= w[1].count_ones()));
+    for (r, v) in bounded.iter().enumerate() {
+        assert_eq!(BoundedWeight::rank(v), r);
+        assert_eq!(&BoundedWeight::unrank(n, r), v);
+    }
+}
@@ -0,0 +1,71 @@
+use gf2::*;
+
+#[test]
+fn test_counts() {
+    // The [7,4,3] Hamming code, its dual the [7,3,4] simplex code, and the repetition code of length 5.
+    let hamming = LinearCode::<u8>::hamming(3).weight_distribution();
+    assert_eq!(hamming.counts(), &[1, 0, 0, 7, 7, 0, 0, 1]);
+    assert_eq!((hamming.n(), hamming.total(), hamming.min_weight()), (7, 16, Some(3)));
+    let simplex = LinearCode::<u8>::simplex(3).weight_distribution();
+    assert_eq!(simplex.counts(), &[1, 0, 0, 0, 7, 0, 0, 0]);
+    let repetition = LinearCode::<u8>::repetition(5).weight_distribution();
+    assert_eq!(repetition.counts(), &[1, 0, 0, 0, 0, 1]);
+
+    // The first order Reed-Muller code of length 16 has every non-constant codeword of weight 8.
+    let rm = ReedMuller::<u8>::new(1, 4).code().weight_distribution();
+    assert_eq!(rm.counts(), &[1, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 1]);
+
+    // The Golay code has the well known weight enumerator 1 + 253 y^7 + 506 y^8 + 1288 y^11 + ... + y^23.
+    let golay = LinearCode::<u8>::golay().weight_distribution();
+    let mut counts = vec![0; 24];
+    for (w, a) in [(0, 1), (7, 253), (8, 506), (11, 1288), (12, 1288), (15, 506), (16, 253), (23, 1)] {
+        counts[w] = a;
+    }
+    assert_eq!(golay.counts(), &counts[..]);
+    assert_eq!(golay.min_weight(), Some(7));
+
+    let extended = LinearCode::<u8>::extended_golay().weight_distribution();
+    assert_eq!((extended.count(8), extended.count(12), extended.count(16), extended.count(24)), (759, 2576, 759, 1));
+}
+
+#[test]
+fn test_macwilliams() {
+    // The Hamming and simplex codes are duals.
+    let hamming = LinearCode::<u8>::hamming(3).weight_distribution();
+    let simplex = LinearCode::<u8>::simplex(3).weight_distribution();
+    assert_eq!(hamming.macwilliams(), simplex);
+    assert_eq!(simplex.macwilliams(), hamming);
+
+    // The repetition code of length 5 and the even weight vectors of length 5.
+    let repetition = WeightDistribution::from_counts(vec![1, 0, 0, 0, 0, 1]);
+    assert_eq!(repetition.macwilliams().counts(), &[1, 0, 10, 0, 5, 0]);
+    assert_eq!(repetition.macwilliams(), LinearCode::<u8>::single_parity_check(5).weight_distribution());
+
+    // The dual of the Golay code is its even weight subcode.
+    let golay = LinearCode::<u8>::golay();
+    let dual = golay.weight_distribution().macwilliams();
+    assert_eq!((dual.total(), dual.count(8), dual.count(12), dual.count(16)), (1 << 11, 506, 1288, 253));
+    assert_eq!(dual, golay.dual().weight_distribution());
+
+    // The extended Golay code is self-dual so it is a fixed point of the transform.
+    let extended = LinearCode::<u8>::extended_golay().weight_distribution();
+    assert_eq!(extended.macwilliams(), extended);
+
+    // RM(1, 4) and RM(2, 4) are duals.
+    let rm = ReedMuller::<u8>::new(1, 4).code().weight_distribution();
+    assert_eq!(rm.macwilliams(), ReedMuller::<u8>::new(2, 4).code().weight_distribution());
+}
+
+#[test]
+fn test_brouwer_zimmermann() {
+    // Codes of larger dimension use the Brouwer–Zimmermann algorithm for the minimum distance.
+    let extended = LinearCode::<u8>::extended_hamming(5);
+    assert_eq!(extended.d(), Some(4));
+    assert_eq!(extended.d(), extended.weight_distribution().min_weight());
+    let rm = ReedMuller::<u8>::new(3, 5);
+    assert_eq!(rm.code().d(), Some(4));
+    assert_eq!(rm.code().d(), rm.code().weight_distribution().min_weight());
+    assert_eq!(LinearCode::<u8>::secded(26).d(), Some(4));
+    assert_eq!(LinearCode::<u8>::hamming(7).d(), Some(3));
+    assert_eq!(ReedMuller::<u8>::new(2, 6).code().d(), Some(16));
+}